@@ -0,0 +1,427 @@
+use std::{fs, path::Path, time::Duration};
+
+use common::Params;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cli::{BenchArgs, Selection},
+    days::Day,
+    generate, run,
+    table::format_table,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut samples = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as u64)
+            .collect::<Vec<_>>();
+        samples.sort();
+        let median_ns = match samples.len() {
+            0 => return None,
+            n if n % 2 == 0 => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+            n => samples[n / 2],
+        };
+        Some(Self {
+            min_ns: samples[0],
+            median_ns,
+            max_ns: samples[samples.len() - 1],
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct StageReport {
+    pub stage: String,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DayReport {
+    pub day: u8,
+    /// The size of the generated input, or `None` for the real one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<usize>,
+    pub stages: Vec<StageReport>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub year: u16,
+    pub iterations: usize,
+    pub days: Vec<DayReport>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("cannot read report `{}`: {e}", path.display()))?;
+        serde_json::from_str(&text).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(path, json + "\n")
+            .map_err(|e| format!("cannot write report `{}`: {e}", path.display()))
+    }
+
+    fn stats(&self, day: u8, size: Option<usize>, stage: &str) -> Option<Stats> {
+        self.days
+            .iter()
+            .find(|report| report.day == day && report.size == size)?
+            .stages
+            .iter()
+            .find(|report| report.stage == stage)
+            .map(|report| report.stats)
+    }
+}
+
+pub fn bench_day(
+    day: &'static Day,
+    input: &str,
+    params: &Params,
+    iterations: usize,
+) -> Result<DayReport, String> {
+    let runs = (0..iterations)
+        .map(|_| run::solve(day, input, params, &[1, 2]).map(|result| result.run))
+        .collect::<Result<Vec<_>, String>>()?;
+    let parse = runs.iter().map(|run| run.parse).collect::<Vec<_>>();
+    let stages = std::iter::once(("parse".to_string(), parse))
+        .chain([1, 2].map(|part| {
+            let samples = runs
+                .iter()
+                .flat_map(|run| run.parts.iter().filter(|result| result.part == part))
+                .map(|result| result.elapsed)
+                .collect();
+            (format!("part{part}"), samples)
+        }))
+        .filter_map(|(stage, samples)| {
+            Stats::from_samples(&samples).map(|stats| StageReport { stage, stats })
+        })
+        .collect();
+    Ok(DayReport {
+        day: day.number,
+        size: None,
+        stages,
+    })
+}
+
+/// Benchmarks `day` on a generated input of each of `sizes`.
+pub fn bench_sizes(
+    day: &'static Day,
+    sizes: &[usize],
+    seed: u64,
+    iterations: usize,
+) -> Result<Vec<DayReport>, String> {
+    sizes
+        .iter()
+        .map(|&size| {
+            let generated = generate::generate(day, size, seed)?;
+            let report = bench_day(day, &generated.input, &generated.params, iterations)?;
+            Ok(DayReport {
+                size: Some(size),
+                ..report
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub size: Option<usize>,
+    pub stage: String,
+    pub change: f64,
+}
+
+fn change(current: &Stats, baseline: &Stats) -> f64 {
+    (current.median_ns as f64 / baseline.median_ns.max(1) as f64 - 1.0) * 100.0
+}
+
+pub fn regressions(report: &Report, baseline: &Report, threshold: f64) -> Vec<Regression> {
+    report
+        .days
+        .iter()
+        .flat_map(|day| {
+            day.stages.iter().filter_map(|stage| {
+                let previous = baseline.stats(day.day, day.size, &stage.stage)?;
+                let change = change(&stage.stats, &previous);
+                (change > threshold).then(|| Regression {
+                    day: day.day,
+                    size: day.size,
+                    stage: stage.stage.clone(),
+                    change,
+                })
+            })
+        })
+        .collect()
+}
+
+fn format_ns(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+/// The exponent `k` of `size^k` that the median time of `stage` follows since the
+/// previous size of `day`.
+fn growth(report: &Report, index: usize, stage: &StageReport) -> Option<f64> {
+    let day = &report.days[index];
+    let previous = report.days[..index]
+        .iter()
+        .rev()
+        .find(|previous| previous.day == day.day)?;
+    let stats = previous
+        .stages
+        .iter()
+        .find(|previous| previous.stage == stage.stage)?
+        .stats;
+    let sizes = day.size? as f64 / previous.size? as f64;
+    let times = stage.stats.median_ns.max(1) as f64 / stats.median_ns.max(1) as f64;
+    (sizes > 1.0).then(|| times.ln() / sizes.ln())
+}
+
+fn scaling_table(report: &Report, baseline: Option<&Report>) -> String {
+    let rows = report
+        .days
+        .iter()
+        .enumerate()
+        .flat_map(|(index, day)| {
+            day.stages.iter().map(move |stage| {
+                let previous =
+                    baseline.and_then(|baseline| baseline.stats(day.day, day.size, &stage.stage));
+                [
+                    day.day.to_string(),
+                    day.size.map_or("-".to_string(), |size| size.to_string()),
+                    stage.stage.clone(),
+                    format_ns(stage.stats.median_ns),
+                    growth(report, index, stage)
+                        .map_or("-".to_string(), |growth| format!("n^{growth:.2}")),
+                    previous.map_or("-".to_string(), |previous| {
+                        format!("{:+.1}%", change(&stage.stats, &previous))
+                    }),
+                ]
+            })
+        })
+        .collect::<Vec<_>>();
+    format_table(
+        ["Day", "Size", "Stage", "Median", "Growth", "vs baseline"],
+        &rows,
+    )
+}
+
+fn report_table(report: &Report, baseline: Option<&Report>) -> String {
+    let rows = report
+        .days
+        .iter()
+        .flat_map(|day| {
+            day.stages.iter().map(|stage| {
+                let previous =
+                    baseline.and_then(|baseline| baseline.stats(day.day, day.size, &stage.stage));
+                [
+                    day.day.to_string(),
+                    stage.stage.clone(),
+                    format_ns(stage.stats.min_ns),
+                    format_ns(stage.stats.median_ns),
+                    format_ns(stage.stats.max_ns),
+                    previous.map_or("-".to_string(), |previous| {
+                        format!("{:+.1}%", change(&stage.stats, &previous))
+                    }),
+                ]
+            })
+        })
+        .collect::<Vec<_>>();
+    format_table(
+        ["Day", "Stage", "Min", "Median", "Max", "vs baseline"],
+        &rows,
+    )
+}
+
+pub fn execute(args: &BenchArgs) -> Result<(), String> {
+    let selected = run::select(args.year, &args.selection)?;
+    let days = match args.sizes.is_empty() {
+        true => selected
+            .into_iter()
+            .map(|day| {
+                let input = run::load_input(day, None, args.input_dir.as_deref())?;
+                bench_day(day, &input, &Params::default(), args.iterations)
+            })
+            .collect::<Result<Vec<_>, String>>()?,
+        false => selected
+            .into_iter()
+            // Running every day skips those without a generator.
+            .filter(|day| {
+                args.selection != Selection::All || generate::generate(day, 1, args.seed).is_ok()
+            })
+            .map(|day| bench_sizes(day, &args.sizes, args.seed, args.iterations))
+            .collect::<Result<Vec<_>, String>>()?
+            .into_iter()
+            .flatten()
+            .collect(),
+    };
+    let report = Report {
+        year: args.year,
+        iterations: args.iterations,
+        days,
+    };
+    let baseline = args.baseline.as_deref().map(Report::load).transpose()?;
+    if let Some(baseline) = baseline.as_ref().filter(|b| b.year != report.year) {
+        return Err(format!(
+            "the baseline is for {}, not {}",
+            baseline.year, report.year
+        ));
+    }
+    match args.sizes.is_empty() {
+        true => println!("{}", report_table(&report, baseline.as_ref())),
+        false => println!("{}", scaling_table(&report, baseline.as_ref())),
+    }
+    if let Some(output) = &args.output {
+        report.save(output)?;
+    }
+    let regressions = baseline
+        .map(|baseline| regressions(&report, &baseline, args.threshold))
+        .unwrap_or_default();
+    if regressions.is_empty() {
+        return Ok(());
+    }
+    let regressions = regressions
+        .iter()
+        .map(|r| match r.size {
+            Some(size) => format!(
+                "day {} {} of size {size} is {:.1}% slower",
+                r.day, r.stage, r.change
+            ),
+            None => format!("day {} {} is {:.1}% slower", r.day, r.stage, r.change),
+        })
+        .collect::<Vec<_>>()
+        .join("\n  ");
+    Err(format!(
+        "regressions beyond {}%:\n  {regressions}",
+        args.threshold
+    ))
+}
+
+#[cfg(test)]
+mod bench_tests {
+    use super::*;
+    use crate::days;
+
+    fn stats(median_ns: u64) -> Stats {
+        Stats {
+            min_ns: median_ns,
+            median_ns,
+            max_ns: median_ns,
+        }
+    }
+
+    fn report(day: u8, stage: &str, median_ns: u64) -> Report {
+        Report {
+            year: 2024,
+            iterations: 1,
+            days: vec![DayReport {
+                day,
+                size: None,
+                stages: vec![StageReport {
+                    stage: stage.to_string(),
+                    stats: stats(median_ns),
+                }],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = [5, 1, 3].map(Duration::from_nanos);
+        assert_eq!(
+            Stats::from_samples(&samples),
+            Some(Stats {
+                min_ns: 1,
+                median_ns: 3,
+                max_ns: 5
+            })
+        );
+        let samples = [4, 1, 2, 8].map(Duration::from_nanos);
+        assert_eq!(Stats::from_samples(&samples).unwrap().median_ns, 3);
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let day = days::find(2024, 1).unwrap();
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+        let report = bench_day(day, input, &Params::default(), 3).unwrap();
+        assert_eq!(report.day, 1);
+        assert_eq!(
+            report
+                .stages
+                .iter()
+                .map(|stage| stage.stage.as_str())
+                .collect::<Vec<_>>(),
+            vec!["parse", "part1", "part2"]
+        );
+    }
+
+    #[test]
+    fn test_bench_sizes() {
+        let day = days::find(2024, 9).unwrap();
+        let reports = bench_sizes(day, &[100, 1000], 1, 2).unwrap();
+        assert_eq!(
+            reports.iter().map(|report| report.size).collect::<Vec<_>>(),
+            vec![Some(100), Some(1000)]
+        );
+        let report = Report {
+            year: 2024,
+            iterations: 2,
+            days: reports,
+        };
+        let table = scaling_table(&report, None);
+        assert!(table
+            .lines()
+            .nth(2)
+            .unwrap()
+            .starts_with("9   | 100  | parse"));
+        assert!(table.lines().nth(5).unwrap().contains("| n^"));
+    }
+
+    #[test]
+    fn test_growth() {
+        let larger = report(9, "part1", 100_000).days.remove(0);
+        let mut report = report(9, "part1", 1000);
+        report.days[0].size = Some(10);
+        report.days.push(DayReport {
+            size: Some(100),
+            ..larger
+        });
+        let growth_since = |index: usize| growth(&report, index, &report.days[index].stages[0]);
+        assert!((growth_since(1).unwrap() - 2.0).abs() < 1e-9);
+        assert_eq!(growth_since(0), None);
+    }
+
+    #[test]
+    fn test_report_round_trip() {
+        let report = report(7, "part2", 1500);
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(
+            json,
+            r#"{"year":2024,"iterations":1,"days":[{"day":7,"stages":[{"stage":"part2","min_ns":1500,"median_ns":1500,"max_ns":1500}]}]}"#
+        );
+        assert_eq!(serde_json::from_str::<Report>(&json).unwrap(), report);
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = report(7, "part2", 1000);
+        assert!(regressions(&report(7, "part2", 1050), &baseline, 10.0).is_empty());
+        let found = regressions(&report(7, "part2", 1200), &baseline, 10.0);
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].day, found[0].stage.as_str()), (7, "part2"));
+        assert!((found[0].change - 20.0).abs() < 1e-9);
+        assert!(regressions(&report(8, "part2", 5000), &baseline, 10.0).is_empty());
+    }
+}
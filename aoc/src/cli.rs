@@ -0,0 +1,1076 @@
+use std::path::PathBuf;
+
+use common::{log::Filter, Params};
+
+use crate::{days, generate, serve};
+
+pub const USAGE: &str = "Usage:
+    aoc run <day> [--part <1|2>] [--input <path|->] [--input-dir <dir>] [--example <n>]
+            [--param <name>=<value>]... [--config <path>] [--frames <dir>]
+            [--render <dir> [--scale <pixels>]] [--step]
+    aoc run --all [--input-dir <dir>] [--config <path>]
+    aoc params [<day>] [--config <path>]
+    aoc verify [<day>] [--answers <path>] [--input-dir <dir>]
+    aoc bench [<day>] [--iterations <n>] [--output <path>] [--baseline <path>]
+              [--threshold <percent>] [--input-dir <dir>] [--sizes <n>,... [--seed <n>]]
+    aoc generate <day> --size <n> [--seed <n>] [--output <path>]
+    aoc fetch <day> [--input-dir <dir>] [--base-url <url>]
+    aoc submit <day> <1|2> [--input <path|->] [--input-dir <dir>] [--base-url <url>]
+               [--record <path>]
+    aoc new <day> [--input-dir <dir>]
+    aoc serve [--port <port>] [--config <path>]
+    aoc help
+
+Every command but help also takes --year <year>, by default the latest solved one.
+Every command takes --verbose (-v), which logs info, debug and then trace events
+the more it is repeated; AOC_LOG filters them per target, such as
+`info,y2024_day18=trace`.";
+
+const FIRST_YEAR: u16 = 2015;
+
+#[derive(Debug, PartialEq)]
+pub enum Selection {
+    One(u8),
+    All,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunArgs {
+    pub year: u16,
+    pub selection: Selection,
+    pub part: Option<u8>,
+    pub input: Option<PathBuf>,
+    pub input_dir: Option<PathBuf>,
+    pub example: Option<usize>,
+    pub params: Params,
+    pub config: Option<PathBuf>,
+    pub frames: Option<PathBuf>,
+    pub render: Option<PathBuf>,
+    pub scale: Option<usize>,
+    pub step: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParamsArgs {
+    pub year: u16,
+    pub selection: Selection,
+    pub config: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct VerifyArgs {
+    pub year: u16,
+    pub selection: Selection,
+    pub answers: Option<PathBuf>,
+    pub input_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchArgs {
+    pub year: u16,
+    pub selection: Selection,
+    pub iterations: usize,
+    pub output: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+    pub threshold: f64,
+    pub input_dir: Option<PathBuf>,
+    pub sizes: Vec<usize>,
+    pub seed: u64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GenerateArgs {
+    pub year: u16,
+    pub day: u8,
+    pub size: usize,
+    pub seed: u64,
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct FetchArgs {
+    pub year: u16,
+    pub day: u8,
+    pub input_dir: Option<PathBuf>,
+    pub base_url: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SubmitArgs {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input: Option<PathBuf>,
+    pub input_dir: Option<PathBuf>,
+    pub base_url: Option<String>,
+    pub record: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct NewArgs {
+    pub year: u16,
+    pub day: u8,
+    pub input_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ServeArgs {
+    pub year: u16,
+    pub port: u16,
+    pub config: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunArgs),
+    Params(ParamsArgs),
+    Verify(VerifyArgs),
+    Bench(BenchArgs),
+    Generate(GenerateArgs),
+    Fetch(FetchArgs),
+    Submit(SubmitArgs),
+    New(NewArgs),
+    Serve(ServeArgs),
+    Help,
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "invalid day `{value}`, expected a number from 1 to 25"
+        )),
+    }
+}
+
+fn parse_year(value: &str) -> Result<u16, String> {
+    match value.parse::<u16>() {
+        Ok(year) if year >= FIRST_YEAR => Ok(year),
+        _ => Err(format!(
+            "invalid year `{value}`, expected {FIRST_YEAR} or later"
+        )),
+    }
+}
+
+fn parse_part(value: &str) -> Result<u8, String> {
+    match value {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part `{value}`, expected 1 or 2")),
+    }
+}
+
+fn parse_example(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(example) if example > 0 => Ok(example),
+        _ => Err(format!(
+            "invalid example `{value}`, expected a number from 1"
+        )),
+    }
+}
+
+fn parse_size(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(size) if size > 0 => Ok(size),
+        _ => Err(format!("invalid size `{value}`, expected a number from 1")),
+    }
+}
+
+fn parse_seed(value: &str) -> Result<u64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid seed `{value}`, expected a number"))
+}
+
+fn parse_scale(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(scale) if scale > 0 => Ok(scale),
+        _ => Err(format!(
+            "invalid scale `{value}`, expected a number of pixels from 1"
+        )),
+    }
+}
+
+fn parse_run(args: &[String]) -> Result<RunArgs, String> {
+    let mut day = None;
+    let mut all = false;
+    let mut part = None;
+    let mut input = None;
+    let mut input_dir = None;
+    let mut example = None;
+    let mut params = Params::default();
+    let mut config = None;
+    let mut frames = None;
+    let mut render = None;
+    let mut scale = None;
+    let mut step = false;
+    let mut year = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--step" => step = true,
+            "--part" => {
+                let value = args.next().ok_or("missing value for --part")?;
+                part = Some(parse_part(value)?);
+            }
+            "--input" => {
+                let value = args.next().ok_or("missing value for --input")?;
+                input = Some(PathBuf::from(value));
+            }
+            "--input-dir" => {
+                let value = args.next().ok_or("missing value for --input-dir")?;
+                input_dir = Some(PathBuf::from(value));
+            }
+            "--example" => {
+                let value = args.next().ok_or("missing value for --example")?;
+                example = Some(parse_example(value)?);
+            }
+            "--param" => {
+                let value = args.next().ok_or("missing value for --param")?;
+                params.merge(&value.parse()?);
+            }
+            "--config" => {
+                let value = args.next().ok_or("missing value for --config")?;
+                config = Some(PathBuf::from(value));
+            }
+            "--frames" => {
+                let value = args.next().ok_or("missing value for --frames")?;
+                frames = Some(PathBuf::from(value));
+            }
+            "--render" => {
+                let value = args.next().ok_or("missing value for --render")?;
+                render = Some(PathBuf::from(value));
+            }
+            "--scale" => {
+                let value = args.next().ok_or("missing value for --scale")?;
+                scale = Some(parse_scale(value)?);
+            }
+            "--year" => {
+                let value = args.next().ok_or("missing value for --year")?;
+                year = Some(parse_year(value)?);
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag `{flag}`")),
+            value if day.is_none() => day = Some(parse_day(value)?),
+            value => return Err(format!("unexpected argument `{value}`")),
+        }
+    }
+    let selection = match (day, all) {
+        (Some(day), false) => Selection::One(day),
+        (None, true) => Selection::All,
+        (Some(_), true) => return Err("cannot combine a day with --all".to_string()),
+        (None, false) => return Err("expected a day or --all".to_string()),
+    };
+    if selection == Selection::All && input.is_some() {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    if selection == Selection::All && example.is_some() {
+        return Err("--example can only be used with a single day".to_string());
+    }
+    if selection == Selection::All && params != Params::default() {
+        return Err("--param can only be used with a single day".to_string());
+    }
+    if selection == Selection::All && frames.is_some() {
+        return Err("--frames can only be used with a single day".to_string());
+    }
+    if selection == Selection::All && render.is_some() {
+        return Err("--render can only be used with a single day".to_string());
+    }
+    if scale.is_some() && render.is_none() {
+        return Err("--scale can only be used with --render".to_string());
+    }
+    if selection == Selection::All && step {
+        return Err("--step can only be used with a single day".to_string());
+    }
+    if [frames.is_some(), render.is_some(), step]
+        .iter()
+        .filter(|&&mode| mode)
+        .count()
+        > 1
+    {
+        return Err("choose one of --frames, --render and --step".to_string());
+    }
+    if input.is_some() && example.is_some() {
+        return Err("cannot combine --input with --example".to_string());
+    }
+    Ok(RunArgs {
+        year: year.unwrap_or_else(days::latest_year),
+        selection,
+        part,
+        input,
+        input_dir,
+        example,
+        params,
+        config,
+        frames,
+        render,
+        scale,
+        step,
+    })
+}
+
+fn parse_params(args: &[String]) -> Result<ParamsArgs, String> {
+    let mut day = None;
+    let mut config = None;
+    let mut year = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => {
+                let value = args.next().ok_or("missing value for --config")?;
+                config = Some(PathBuf::from(value));
+            }
+            "--year" => {
+                let value = args.next().ok_or("missing value for --year")?;
+                year = Some(parse_year(value)?);
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag `{flag}`")),
+            value if day.is_none() => day = Some(parse_day(value)?),
+            value => return Err(format!("unexpected argument `{value}`")),
+        }
+    }
+    Ok(ParamsArgs {
+        year: year.unwrap_or_else(days::latest_year),
+        selection: day.map_or(Selection::All, Selection::One),
+        config,
+    })
+}
+
+fn parse_verify(args: &[String]) -> Result<VerifyArgs, String> {
+    let mut day = None;
+    let mut answers = None;
+    let mut input_dir = None;
+    let mut year = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => {
+                let value = args.next().ok_or("missing value for --answers")?;
+                answers = Some(PathBuf::from(value));
+            }
+            "--input-dir" => {
+                let value = args.next().ok_or("missing value for --input-dir")?;
+                input_dir = Some(PathBuf::from(value));
+            }
+            "--year" => {
+                let value = args.next().ok_or("missing value for --year")?;
+                year = Some(parse_year(value)?);
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag `{flag}`")),
+            value if day.is_none() => day = Some(parse_day(value)?),
+            value => return Err(format!("unexpected argument `{value}`")),
+        }
+    }
+    Ok(VerifyArgs {
+        year: year.unwrap_or_else(days::latest_year),
+        selection: day.map_or(Selection::All, Selection::One),
+        answers,
+        input_dir,
+    })
+}
+
+fn parse_bench(args: &[String]) -> Result<BenchArgs, String> {
+    let mut day = None;
+    let mut bench = BenchArgs {
+        year: days::latest_year(),
+        selection: Selection::All,
+        iterations: 10,
+        output: None,
+        baseline: None,
+        threshold: 10.0,
+        input_dir: None,
+        sizes: vec![],
+        seed: generate::DEFAULT_SEED,
+    };
+    let mut seed = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" => {
+                let value = args.next().ok_or("missing value for --iterations")?;
+                bench.iterations = value
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or(format!("invalid iterations `{value}`"))?;
+            }
+            "--output" => {
+                let value = args.next().ok_or("missing value for --output")?;
+                bench.output = Some(PathBuf::from(value));
+            }
+            "--baseline" => {
+                let value = args.next().ok_or("missing value for --baseline")?;
+                bench.baseline = Some(PathBuf::from(value));
+            }
+            "--threshold" => {
+                let value = args.next().ok_or("missing value for --threshold")?;
+                bench.threshold = value
+                    .parse()
+                    .ok()
+                    .filter(|&t: &f64| t >= 0.0)
+                    .ok_or(format!("invalid threshold `{value}`"))?;
+            }
+            "--input-dir" => {
+                let value = args.next().ok_or("missing value for --input-dir")?;
+                bench.input_dir = Some(PathBuf::from(value));
+            }
+            "--sizes" => {
+                let value = args.next().ok_or("missing value for --sizes")?;
+                bench.sizes = value.split(',').map(parse_size).collect::<Result<_, _>>()?;
+            }
+            "--seed" => {
+                let value = args.next().ok_or("missing value for --seed")?;
+                seed = Some(parse_seed(value)?);
+            }
+            "--year" => {
+                let value = args.next().ok_or("missing value for --year")?;
+                bench.year = parse_year(value)?;
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag `{flag}`")),
+            value if day.is_none() => day = Some(parse_day(value)?),
+            value => return Err(format!("unexpected argument `{value}`")),
+        }
+    }
+    bench.selection = day.map_or(Selection::All, Selection::One);
+    if let Some(seed) = seed {
+        if bench.sizes.is_empty() {
+            return Err("--seed can only be used with --sizes".to_string());
+        }
+        bench.seed = seed;
+    }
+    if !bench.sizes.is_empty() && bench.input_dir.is_some() {
+        return Err("cannot combine --sizes with --input-dir".to_string());
+    }
+    Ok(bench)
+}
+
+fn parse_generate(args: &[String]) -> Result<GenerateArgs, String> {
+    let mut day = None;
+    let mut size = None;
+    let mut seed = generate::DEFAULT_SEED;
+    let mut output = None;
+    let mut year = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => {
+                let value = args.next().ok_or("missing value for --size")?;
+                size = Some(parse_size(value)?);
+            }
+            "--seed" => {
+                let value = args.next().ok_or("missing value for --seed")?;
+                seed = parse_seed(value)?;
+            }
+            "--output" => {
+                let value = args.next().ok_or("missing value for --output")?;
+                output = Some(PathBuf::from(value));
+            }
+            "--year" => {
+                let value = args.next().ok_or("missing value for --year")?;
+                year = Some(parse_year(value)?);
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag `{flag}`")),
+            value if day.is_none() => day = Some(parse_day(value)?),
+            value => return Err(format!("unexpected argument `{value}`")),
+        }
+    }
+    Ok(GenerateArgs {
+        year: year.unwrap_or_else(days::latest_year),
+        day: day.ok_or("expected a day")?,
+        size: size.ok_or("expected a --size")?,
+        seed,
+        output,
+    })
+}
+
+fn parse_fetch(args: &[String]) -> Result<FetchArgs, String> {
+    let mut day = None;
+    let mut input_dir = None;
+    let mut base_url = None;
+    let mut year = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input-dir" => {
+                let value = args.next().ok_or("missing value for --input-dir")?;
+                input_dir = Some(PathBuf::from(value));
+            }
+            "--base-url" => {
+                let value = args.next().ok_or("missing value for --base-url")?;
+                base_url = Some(value.clone());
+            }
+            "--year" => {
+                let value = args.next().ok_or("missing value for --year")?;
+                year = Some(parse_year(value)?);
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag `{flag}`")),
+            value if day.is_none() => day = Some(parse_day(value)?),
+            value => return Err(format!("unexpected argument `{value}`")),
+        }
+    }
+    Ok(FetchArgs {
+        year: year.unwrap_or_else(days::latest_year),
+        day: day.ok_or("expected a day")?,
+        input_dir,
+        base_url,
+    })
+}
+
+fn parse_submit(args: &[String]) -> Result<SubmitArgs, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut input_dir = None;
+    let mut base_url = None;
+    let mut record = None;
+    let mut year = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let value = args.next().ok_or("missing value for --input")?;
+                input = Some(PathBuf::from(value));
+            }
+            "--input-dir" => {
+                let value = args.next().ok_or("missing value for --input-dir")?;
+                input_dir = Some(PathBuf::from(value));
+            }
+            "--base-url" => {
+                let value = args.next().ok_or("missing value for --base-url")?;
+                base_url = Some(value.clone());
+            }
+            "--record" => {
+                let value = args.next().ok_or("missing value for --record")?;
+                record = Some(PathBuf::from(value));
+            }
+            "--year" => {
+                let value = args.next().ok_or("missing value for --year")?;
+                year = Some(parse_year(value)?);
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag `{flag}`")),
+            value if day.is_none() => day = Some(parse_day(value)?),
+            value if part.is_none() => part = Some(parse_part(value)?),
+            value => return Err(format!("unexpected argument `{value}`")),
+        }
+    }
+    Ok(SubmitArgs {
+        year: year.unwrap_or_else(days::latest_year),
+        day: day.ok_or("expected a day")?,
+        part: part.ok_or("expected a part")?,
+        input,
+        input_dir,
+        base_url,
+        record,
+    })
+}
+
+fn parse_new(args: &[String]) -> Result<NewArgs, String> {
+    let mut day = None;
+    let mut input_dir = None;
+    let mut year = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input-dir" => {
+                let value = args.next().ok_or("missing value for --input-dir")?;
+                input_dir = Some(PathBuf::from(value));
+            }
+            "--year" => {
+                let value = args.next().ok_or("missing value for --year")?;
+                year = Some(parse_year(value)?);
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag `{flag}`")),
+            value if day.is_none() => day = Some(parse_day(value)?),
+            value => return Err(format!("unexpected argument `{value}`")),
+        }
+    }
+    Ok(NewArgs {
+        year: year.unwrap_or_else(days::latest_year),
+        day: day.ok_or("expected a day")?,
+        input_dir,
+    })
+}
+
+/// Takes the `--verbose` flags out of `args` and counts them.
+pub fn split_verbose(args: &[String]) -> (usize, Vec<String>) {
+    let mut count = 0;
+    let rest = args
+        .iter()
+        .filter(|arg| {
+            let steps = match arg.as_str() {
+                "--verbose" => 1,
+                flag => flag
+                    .strip_prefix('-')
+                    .filter(|vs| !vs.is_empty() && vs.chars().all(|c| c == 'v'))
+                    .map_or(0, str::len),
+            };
+            count += steps;
+            steps == 0
+        })
+        .cloned()
+        .collect();
+    (count, rest)
+}
+
+/// The log filter of `AOC_LOG`, if set, made `verbose` steps louder.
+pub fn log_filter(env: Option<&str>, verbose: usize) -> Result<Filter, String> {
+    let filter = match env {
+        Some(env) => env.parse().map_err(|e| format!("invalid AOC_LOG: {e}"))?,
+        None => Filter::default(),
+    };
+    Ok(filter.louder(verbose))
+}
+
+fn parse_serve(args: &[String]) -> Result<ServeArgs, String> {
+    let mut port = serve::DEFAULT_PORT;
+    let mut config = None;
+    let mut year = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--port" => {
+                let value = args.next().ok_or("missing value for --port")?;
+                port = value
+                    .parse()
+                    .map_err(|_| format!("invalid port `{value}`"))?;
+            }
+            "--config" => {
+                let value = args.next().ok_or("missing value for --config")?;
+                config = Some(PathBuf::from(value));
+            }
+            "--year" => {
+                let value = args.next().ok_or("missing value for --year")?;
+                year = Some(parse_year(value)?);
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown flag `{flag}`")),
+            value => return Err(format!("unexpected argument `{value}`")),
+        }
+    }
+    Ok(ServeArgs {
+        year: year.unwrap_or_else(days::latest_year),
+        port,
+        config,
+    })
+}
+
+pub fn parse(args: &[String]) -> Result<Command, String> {
+    match args.split_first() {
+        Some((command, rest)) if command == "run" => parse_run(rest).map(Command::Run),
+        Some((command, rest)) if command == "params" => parse_params(rest).map(Command::Params),
+        Some((command, rest)) if command == "verify" => parse_verify(rest).map(Command::Verify),
+        Some((command, rest)) if command == "bench" => parse_bench(rest).map(Command::Bench),
+        Some((command, rest)) if command == "generate" => {
+            parse_generate(rest).map(Command::Generate)
+        }
+        Some((command, rest)) if command == "fetch" => parse_fetch(rest).map(Command::Fetch),
+        Some((command, rest)) if command == "submit" => parse_submit(rest).map(Command::Submit),
+        Some((command, rest)) if command == "new" => parse_new(rest).map(Command::New),
+        Some((command, rest)) if command == "serve" => parse_serve(rest).map(Command::Serve),
+        Some((command, _)) if command == "help" || command == "--help" => Ok(Command::Help),
+        Some((command, _)) => Err(format!("unknown command `{command}`")),
+        None => Ok(Command::Help),
+    }
+}
+
+#[cfg(test)]
+mod cli_tests {
+    use common::log::Level;
+
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_run_day() {
+        assert_eq!(
+            parse(&args("run 17 --part 2 --input path/to/file.txt")),
+            Ok(Command::Run(RunArgs {
+                year: 2024,
+                selection: Selection::One(17),
+                part: Some(2),
+                input: Some(PathBuf::from("path/to/file.txt")),
+                input_dir: None,
+                example: None,
+                params: Params::default(),
+                config: None,
+                frames: None,
+                render: None,
+                scale: None,
+                step: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_run_all() {
+        assert_eq!(
+            parse(&args("run --all --input-dir /puzzles")),
+            Ok(Command::Run(RunArgs {
+                year: 2024,
+                selection: Selection::All,
+                part: None,
+                input: None,
+                input_dir: Some(PathBuf::from("/puzzles")),
+                example: None,
+                params: Params::default(),
+                config: None,
+                frames: None,
+                render: None,
+                scale: None,
+                step: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_run_example() {
+        assert_eq!(
+            parse(&args("run 18 --example 1 --part 2")),
+            Ok(Command::Run(RunArgs {
+                year: 2024,
+                selection: Selection::One(18),
+                part: Some(2),
+                input: None,
+                input_dir: None,
+                example: Some(1),
+                params: Params::default(),
+                config: None,
+                frames: None,
+                render: None,
+                scale: None,
+                step: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_run_params() {
+        assert_eq!(
+            parse(&args(
+                "run 18 --param size=7 --param bytes=12,size=9 --config variant.toml"
+            )),
+            Ok(Command::Run(RunArgs {
+                year: 2024,
+                selection: Selection::One(18),
+                part: None,
+                input: None,
+                input_dir: None,
+                example: None,
+                params: Params::from_pairs(&[("size", 9), ("bytes", 12)]),
+                config: Some(PathBuf::from("variant.toml")),
+                frames: None,
+                render: None,
+                scale: None,
+                step: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_run_frames() {
+        assert_eq!(
+            parse(&args("run 15 --example 2 --part 2 --frames out")),
+            Ok(Command::Run(RunArgs {
+                year: 2024,
+                selection: Selection::One(15),
+                part: Some(2),
+                input: None,
+                input_dir: None,
+                example: Some(2),
+                params: Params::default(),
+                config: None,
+                frames: Some(PathBuf::from("out")),
+                render: None,
+                scale: None,
+                step: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_run_render_and_step() {
+        assert_eq!(
+            parse(&args("run 14 --example 1 --render out --scale 2")),
+            Ok(Command::Run(RunArgs {
+                year: 2024,
+                selection: Selection::One(14),
+                part: None,
+                input: None,
+                input_dir: None,
+                example: Some(1),
+                params: Params::default(),
+                config: None,
+                frames: None,
+                render: Some(PathBuf::from("out")),
+                scale: Some(2),
+                step: false,
+            }))
+        );
+        assert_eq!(
+            parse(&args("run 15 --example 1 --step")),
+            Ok(Command::Run(RunArgs {
+                year: 2024,
+                selection: Selection::One(15),
+                part: None,
+                input: None,
+                input_dir: None,
+                example: Some(1),
+                params: Params::default(),
+                config: None,
+                frames: None,
+                render: None,
+                scale: None,
+                step: true,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_params() {
+        assert_eq!(
+            parse(&args("params 14 --config variant.toml")),
+            Ok(Command::Params(ParamsArgs {
+                year: 2024,
+                selection: Selection::One(14),
+                config: Some(PathBuf::from("variant.toml")),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(
+            parse(&args("verify")),
+            Ok(Command::Verify(VerifyArgs {
+                year: 2024,
+                selection: Selection::All,
+                answers: None,
+                input_dir: None,
+            }))
+        );
+        assert_eq!(
+            parse(&args("verify 9 --answers my.toml")),
+            Ok(Command::Verify(VerifyArgs {
+                year: 2024,
+                selection: Selection::One(9),
+                answers: Some(PathBuf::from("my.toml")),
+                input_dir: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(
+            parse(&args(
+                "bench 10 --iterations 5 --output new.json --baseline old.json --threshold 2.5"
+            )),
+            Ok(Command::Bench(BenchArgs {
+                year: 2024,
+                selection: Selection::One(10),
+                iterations: 5,
+                output: Some(PathBuf::from("new.json")),
+                baseline: Some(PathBuf::from("old.json")),
+                threshold: 2.5,
+                input_dir: None,
+                sizes: vec![],
+                seed: 2024,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_bench_sizes() {
+        assert_eq!(
+            parse(&args("bench 20 --sizes 101,301,901 --seed 7")),
+            Ok(Command::Bench(BenchArgs {
+                year: 2024,
+                selection: Selection::One(20),
+                iterations: 10,
+                output: None,
+                baseline: None,
+                threshold: 10.0,
+                input_dir: None,
+                sizes: vec![101, 301, 901],
+                seed: 7,
+            }))
+        );
+        assert_eq!(
+            parse(&args("bench --sizes 10,0")),
+            Err("invalid size `0`, expected a number from 1".to_string())
+        );
+        assert_eq!(
+            parse(&args("bench --seed 7")),
+            Err("--seed can only be used with --sizes".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_generate() {
+        assert_eq!(
+            parse(&args("generate 9 --size 1000000 --output disk.txt")),
+            Ok(Command::Generate(GenerateArgs {
+                year: 2024,
+                day: 9,
+                size: 1000000,
+                seed: 2024,
+                output: Some(PathBuf::from("disk.txt")),
+            }))
+        );
+        assert_eq!(
+            parse(&args("generate 9 --seed x")),
+            Err("invalid seed `x`, expected a number".to_string())
+        );
+        assert_eq!(
+            parse(&args("generate 9")),
+            Err("expected a --size".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        assert_eq!(
+            parse(&args("fetch 5 --base-url http://localhost:8080")),
+            Ok(Command::Fetch(FetchArgs {
+                year: 2024,
+                day: 5,
+                input_dir: None,
+                base_url: Some("http://localhost:8080".to_string()),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_submit() {
+        assert_eq!(
+            parse(&args("submit 17 2 --record my.toml")),
+            Ok(Command::Submit(SubmitArgs {
+                year: 2024,
+                day: 17,
+                part: 2,
+                input: None,
+                input_dir: None,
+                base_url: None,
+                record: Some(PathBuf::from("my.toml")),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_new() {
+        assert_eq!(
+            parse(&args("new 6")),
+            Ok(Command::New(NewArgs {
+                year: 2024,
+                day: 6,
+                input_dir: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_serve() {
+        assert_eq!(
+            parse(&args("serve")),
+            Ok(Command::Serve(ServeArgs {
+                year: 2024,
+                port: serve::DEFAULT_PORT,
+                config: None,
+            }))
+        );
+        assert_eq!(
+            parse(&args("serve --port 8080 --config variant.toml")),
+            Ok(Command::Serve(ServeArgs {
+                year: 2024,
+                port: 8080,
+                config: Some(PathBuf::from("variant.toml")),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_year() {
+        assert_eq!(
+            parse(&args("new 1 --year 2025")),
+            Ok(Command::New(NewArgs {
+                year: 2025,
+                day: 1,
+                input_dir: None,
+            }))
+        );
+        assert_eq!(
+            parse(&args("run --year 2015 --all")),
+            Ok(Command::Run(RunArgs {
+                year: 2015,
+                selection: Selection::All,
+                part: None,
+                input: None,
+                input_dir: None,
+                example: None,
+                params: Params::default(),
+                config: None,
+                frames: None,
+                render: None,
+                scale: None,
+                step: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_verbose() {
+        assert_eq!(
+            split_verbose(&args("run 18 -v --part 2 --verbose")),
+            (2, args("run 18 --part 2"))
+        );
+        assert_eq!(split_verbose(&args("-vvv verify")), (3, args("verify")));
+        assert_eq!(split_verbose(&args("verify")), (0, args("verify")));
+        assert_eq!(
+            split_verbose(&args("verify -vx --vv -verbose")),
+            (0, args("verify -vx --vv -verbose"))
+        );
+        assert_eq!(log_filter(None, 2), Ok(Filter::new(Level::Debug)));
+        assert_eq!(
+            log_filter(Some("y2024_day19=trace"), 0),
+            Ok(Filter::default().with_target("y2024_day19", Level::Trace))
+        );
+        assert!(log_filter(Some("y2024_day19=loud"), 0).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse(&args("run")).is_err());
+        assert!(parse(&args("run 26")).is_err());
+        assert!(parse(&args("run 3 --part 3")).is_err());
+        assert!(parse(&args("run 3 --all")).is_err());
+        assert!(parse(&args("run --all --input foo.txt")).is_err());
+        assert!(parse(&args("run --all --example 1")).is_err());
+        assert!(parse(&args("run 3 --example 0")).is_err());
+        assert!(parse(&args("run 3 --example 1 --input foo.txt")).is_err());
+        assert!(parse(&args("run --all --param size=7")).is_err());
+        assert!(parse(&args("run --all --frames out")).is_err());
+        assert!(parse(&args("run --all --render out")).is_err());
+        assert!(parse(&args("run 14 --scale 2")).is_err());
+        assert!(parse(&args("run --all --step")).is_err());
+        assert!(parse(&args("run 15 --step --frames out")).is_err());
+        assert!(parse(&args("run 14 --render out --scale 0")).is_err());
+        assert!(parse(&args("run 18 --param size")).is_err());
+        assert!(parse(&args("run 18 --param size=big")).is_err());
+        assert!(parse(&args("params 18 19")).is_err());
+        assert!(parse(&args("verify 3 4")).is_err());
+        assert!(parse(&args("bench --iterations 0")).is_err());
+        assert!(parse(&args("bench --threshold -1")).is_err());
+        assert!(parse(&args("fetch")).is_err());
+        assert!(parse(&args("submit 3")).is_err());
+        assert!(parse(&args("submit 3 1 2")).is_err());
+        assert!(parse(&args("new 0")).is_err());
+        assert!(parse(&args("serve --port 70000")).is_err());
+        assert!(parse(&args("serve 3")).is_err());
+        assert!(parse(&args("run 3 --year 2014")).is_err());
+        assert!(parse(&args("run 3 --year twenty")).is_err());
+        assert!(parse(&args("run 3 --year")).is_err());
+        assert!(parse(&args("launch 3")).is_err());
+    }
+}
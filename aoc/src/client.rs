@@ -0,0 +1,312 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io::Read,
+    time::Duration,
+};
+
+use ureq::{Agent, AgentBuilder, Request, Response};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/throttle2k/aoc2024_rust";
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, PartialEq)]
+pub enum ClientError {
+    MissingSession,
+    NotUnlocked { year: u16, day: u8 },
+    Unauthorized { status: u16 },
+    RateLimited { retry_after: Option<u64> },
+    Status { status: u16, body: String },
+    Transport(String),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => {
+                write!(
+                    f,
+                    "no session token, set {SESSION_VAR} to your session cookie"
+                )
+            }
+            ClientError::NotUnlocked { year, day } => {
+                write!(f, "the puzzle of day {day} of {year} is not unlocked yet")
+            }
+            ClientError::Unauthorized { status } => write!(
+                f,
+                "the server rejected the session token (HTTP {status}), refresh {SESSION_VAR}"
+            ),
+            ClientError::RateLimited {
+                retry_after: Some(seconds),
+            } => write!(f, "rate limited by the server, retry in {seconds}s"),
+            ClientError::RateLimited { retry_after: None } => {
+                write!(f, "rate limited by the server, retry later")
+            }
+            ClientError::Status { status, body } => {
+                write!(
+                    f,
+                    "unexpected HTTP {status} from the server: {}",
+                    body.trim()
+                )
+            }
+            ClientError::Transport(e) => write!(f, "cannot reach the server: {e}"),
+        }
+    }
+}
+
+impl Error for ClientError {}
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: Agent,
+}
+
+fn read_body(response: Response) -> Result<String, ClientError> {
+    let mut body = String::new();
+    response
+        .into_reader()
+        .read_to_string(&mut body)
+        .map_err(|e| ClientError::Transport(e.to_string()))?;
+    Ok(body)
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(TIMEOUT)
+                .build(),
+        }
+    }
+
+    /// A client for `base_url`, or else `AOC_BASE_URL` or the real site, with the
+    /// session token from `AOC_SESSION`.
+    pub fn from_env(base_url: Option<&str>) -> Result<Self, ClientError> {
+        let session = env::var(SESSION_VAR)
+            .ok()
+            .filter(|session| !session.trim().is_empty())
+            .ok_or(ClientError::MissingSession)?;
+        let base_url = base_url
+            .map(String::from)
+            .or_else(|| env::var(BASE_URL_VAR).ok())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        Ok(Self::new(&base_url, &session))
+    }
+
+    pub fn day_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}", self.base_url)
+    }
+
+    fn check(
+        &self,
+        year: u16,
+        day: u8,
+        result: Result<Response, ureq::Error>,
+    ) -> Result<String, ClientError> {
+        match result {
+            Ok(response) => read_body(response),
+            Err(ureq::Error::Status(status, response)) => Err(match status {
+                404 => ClientError::NotUnlocked { year, day },
+                400 | 401 | 403 => ClientError::Unauthorized { status },
+                429 => ClientError::RateLimited {
+                    retry_after: response
+                        .header("Retry-After")
+                        .and_then(|seconds| seconds.trim().parse().ok()),
+                },
+                _ => ClientError::Status {
+                    status,
+                    body: read_body(response)?,
+                },
+            }),
+            Err(e) => Err(ClientError::Transport(e.to_string())),
+        }
+    }
+
+    fn request(&self, method: &str, year: u16, day: u8, path: &str) -> Request {
+        self.agent
+            .request(method, &format!("{}{path}", self.day_url(year, day)))
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    pub fn get(&self, year: u16, day: u8, path: &str) -> Result<String, ClientError> {
+        self.check(year, day, self.request("GET", year, day, path).call())
+    }
+
+    pub fn post(
+        &self,
+        year: u16,
+        day: u8,
+        path: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, ClientError> {
+        self.check(
+            year,
+            day,
+            self.request("POST", year, day, path).send_form(form),
+        )
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_server {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    pub struct Reply {
+        pub status: u16,
+        pub headers: Vec<(&'static str, &'static str)>,
+        pub body: &'static str,
+    }
+
+    impl Reply {
+        pub fn new(status: u16, body: &'static str) -> Self {
+            Self {
+                status,
+                headers: vec![],
+                body,
+            }
+        }
+
+        pub fn with_header(mut self, name: &'static str, value: &'static str) -> Self {
+            self.headers.push((name, value));
+            self
+        }
+    }
+
+    fn read_request(reader: &mut impl BufRead) -> String {
+        let mut request = String::new();
+        let mut length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    length = value.trim().parse().unwrap();
+                }
+            }
+            request.push_str(&line);
+            if line.trim().is_empty() {
+                break;
+            }
+        }
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+        request.push_str(&String::from_utf8(body).unwrap());
+        request
+    }
+
+    /// Answers one connection with each of `replies` in turn, on a local port.
+    /// Returns the base URL to point a client at and a handle to the raw requests.
+    pub fn serve(replies: Vec<Reply>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            replies
+                .into_iter()
+                .map(|reply| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let request = read_request(&mut BufReader::new(&mut stream));
+                    let mut response = format!(
+                        "HTTP/1.1 {} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n",
+                        reply.status,
+                        reply.body.len()
+                    );
+                    for (name, value) in reply.headers {
+                        response.push_str(&format!("{name}: {value}\r\n"));
+                    }
+                    response.push_str("\r\n");
+                    response.push_str(reply.body);
+                    stream.write_all(response.as_bytes()).unwrap();
+                    request
+                })
+                .collect()
+        });
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod client_tests {
+    use super::{test_server::*, *};
+
+    #[test]
+    fn test_get_sends_session() {
+        let (base_url, server) = serve(vec![Reply::new(200, "3   4\n")]);
+        let client = Client::new(&format!("{base_url}/"), " secret\n");
+        assert_eq!(client.get(2024, 1, "/input"), Ok("3   4\n".to_string()));
+        let request = &server.join().unwrap()[0];
+        assert!(request.starts_with("GET /2024/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=secret\r\n"));
+        assert!(request.contains(&format!("User-Agent: {USER_AGENT}\r\n")));
+    }
+
+    #[test]
+    fn test_post_sends_form() {
+        let (base_url, server) = serve(vec![Reply::new(200, "<article></article>")]);
+        let client = Client::new(&base_url, "secret");
+        let form = [("level", "2"), ("answer", "4,6,3,5,6,3,5,2,1,0")];
+        assert!(client.post(2024, 17, "/answer", &form).is_ok());
+        let request = &server.join().unwrap()[0];
+        assert!(request.starts_with("POST /2024/day/17/answer HTTP/1.1\r\n"));
+        assert!(request.contains("Content-Type: application/x-www-form-urlencoded\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=4%2C6%2C3%2C5%2C6%2C3%2C5%2C2%2C1%2C0"));
+    }
+
+    #[test]
+    fn test_get_errors() {
+        let (base_url, server) = serve(vec![
+            Reply::new(404, "Please don't repeatedly request this endpoint"),
+            Reply::new(
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            Reply::new(429, "Slow down").with_header("Retry-After", "60"),
+            Reply::new(500, "Internal Server Error\n"),
+        ]);
+        let client = Client::new(&base_url, "secret");
+        assert_eq!(
+            client.get(2024, 25, "/input"),
+            Err(ClientError::NotUnlocked {
+                year: 2024,
+                day: 25
+            })
+        );
+        assert_eq!(
+            client.get(2024, 1, "/input"),
+            Err(ClientError::Unauthorized { status: 400 })
+        );
+        assert_eq!(
+            client.get(2024, 1, "/input"),
+            Err(ClientError::RateLimited {
+                retry_after: Some(60)
+            })
+        );
+        assert_eq!(
+            client.get(2024, 1, "/input").unwrap_err().to_string(),
+            "unexpected HTTP 500 from the server: Internal Server Error"
+        );
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_unreachable_server() {
+        let client = Client::new("http://127.0.0.1:1", "secret");
+        assert!(matches!(
+            client.get(2024, 1, "/input"),
+            Err(ClientError::Transport(_))
+        ));
+    }
+}
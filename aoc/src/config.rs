@@ -0,0 +1,137 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use common::{workspace_root, Params};
+use toml::{Table, Value};
+
+use crate::{cli::ParamsArgs, days::Day, run::select, table::format_table, verify::parse_day_key};
+
+pub const CONFIG_DIR: &str = "params";
+
+/// The parameters of `year`, relative to the workspace root.
+pub fn config_file(year: u16) -> PathBuf {
+    Path::new(CONFIG_DIR).join(format!("{year}.toml"))
+}
+
+/// Parameters that replace the defaults of the days, by day.
+#[derive(Debug, Default)]
+pub struct Config {
+    days: HashMap<u8, Params>,
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table = text.parse::<Table>().map_err(|e| e.to_string())?;
+        let mut days = HashMap::new();
+        for (day_key, values) in &table {
+            let day = parse_day_key(day_key)?;
+            let values = values
+                .as_table()
+                .ok_or(format!("`{day_key}` must be a table"))?;
+            let mut params = Params::default();
+            for (name, value) in values {
+                match value {
+                    Value::Integer(n) if *n >= 0 => params.set(name, *n as u64),
+                    _ => return Err(format!("`{day_key}.{name}` must be a non-negative integer")),
+                }
+            }
+            days.insert(day, params);
+        }
+        Ok(Self { days })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("cannot read parameters `{}`: {e}", path.display()))?;
+        Self::parse(&text).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    /// The config at `path`, or else the one of `year` in the workspace if there is one.
+    pub fn find(path: Option<&Path>, year: u16) -> Result<Self, String> {
+        if let Some(path) = path {
+            return Self::load(path);
+        }
+        let path = workspace_root()
+            .map_err(|e| e.to_string())?
+            .join(config_file(year));
+        match path.exists() {
+            true => Self::load(&path),
+            false => Ok(Self::default()),
+        }
+    }
+
+    /// The parameters of `day`, with `overrides` taking precedence over the config.
+    pub fn params(&self, day: &Day, overrides: &Params) -> Result<Params, String> {
+        let mut params = self.days.get(&day.number).cloned().unwrap_or_default();
+        params.merge(overrides);
+        params
+            .check(day.params)
+            .map_err(|e| format!("day {} of {}: {e}", day.number, day.year))?;
+        Ok(params)
+    }
+}
+
+pub fn execute(args: &ParamsArgs) -> Result<(), String> {
+    let config = Config::find(args.config.as_deref(), args.year)?;
+    let mut rows = vec![];
+    for day in select(args.year, &args.selection)? {
+        let params = config.params(day, &Params::default())?;
+        for param in day.params {
+            rows.push([
+                day.number.to_string(),
+                param.name.to_string(),
+                param.default.to_string(),
+                params.value(param).to_string(),
+                param.help.to_string(),
+            ]);
+        }
+    }
+    println!(
+        "{}",
+        format_table(["Day", "Name", "Default", "Value", "Description"], &rows)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod config_tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_params() {
+        let config =
+            Config::parse("[day18]\nsize = 7\nbytes = 12\n\n[day20]\nthreshold = 50").unwrap();
+        let day18 = days::find(2024, 18).unwrap();
+        assert_eq!(
+            config.params(day18, &Params::default()),
+            Ok(Params::from_pairs(&[("size", 7), ("bytes", 12)]))
+        );
+        assert_eq!(
+            config.params(day18, &Params::from_pairs(&[("bytes", 20)])),
+            Ok(Params::from_pairs(&[("size", 7), ("bytes", 20)]))
+        );
+        assert_eq!(
+            config.params(days::find(2024, 1).unwrap(), &Params::default()),
+            Ok(Params::default())
+        );
+        assert_eq!(
+            config.params(day18, &Params::from_pairs(&[("threshold", 50)])),
+            Err(
+                "day 18 of 2024: unknown parameter `threshold`, expected one of size, bytes"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Config::parse("[eighteen]\nsize = 7").is_err());
+        assert!(Config::parse("[day18]\nsize = -7").is_err());
+        assert!(Config::parse("[day18]\nsize = \"7\"").is_err());
+        assert!(Config::parse("day18 = 7").is_err());
+    }
+}
@@ -0,0 +1,90 @@
+use common::{
+    generate::Generated, observe::Observer, random::Rng, Answer, Example, Param, Params,
+    ParseError, Solution,
+};
+
+use crate::run::{self, Run};
+
+pub type Solver = fn(&str, &Params, &[u8]) -> Result<Run, ParseError>;
+pub type Simulator = fn(&str, &Params, u8, &mut dyn Observer) -> Result<Answer, ParseError>;
+pub type Generator = fn(usize, &mut Rng) -> Option<Generated>;
+
+#[derive(Debug)]
+pub struct Day {
+    pub year: u16,
+    pub number: u8,
+    pub title: &'static str,
+    pub solve: Solver,
+    pub simulate: Simulator,
+    pub generate: Generator,
+    pub examples: &'static [Example],
+    pub params: &'static [Param],
+}
+
+macro_rules! day {
+    ($year:literal, $number:literal, $solution:path, $title:literal) => {
+        Day {
+            year: $year,
+            number: $number,
+            title: $title,
+            solve: run::solve_parts::<$solution>,
+            simulate: run::simulate_part::<$solution>,
+            generate: <$solution as Solution>::generate,
+            examples: <$solution as Solution>::EXAMPLES,
+            params: <$solution as Solution>::PARAMS,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(2024, 1, y2024_day01::Day01, "Historian Hysteria"),
+    day!(2024, 2, y2024_day02::Day02, "Red-Nosed Reports"),
+    day!(2024, 3, y2024_day03::Day03, "Mull It Over"),
+    day!(2024, 4, y2024_day04::Day04, "Ceres Search"),
+    day!(2024, 5, y2024_day05::Day05, "Print Queue"),
+    day!(2024, 6, y2024_day06::Day06, "Guard Gallivant"),
+    day!(2024, 7, y2024_day07::Day07, "Bridge Repair"),
+    day!(2024, 8, y2024_day08::Day08, "Resonant Collinearity"),
+    day!(2024, 9, y2024_day09::Day09, "Disk Fragmenter"),
+    day!(2024, 10, y2024_day10::Day10, "Hoof It"),
+    day!(2024, 11, y2024_day11::Day11, "Plutonian Pebbles"),
+    day!(2024, 12, y2024_day12::Day12, "Garden Groups"),
+    day!(2024, 13, y2024_day13::Day13, "Claw Contraption"),
+    day!(2024, 14, y2024_day14::Day14, "Restroom Redoubt"),
+    day!(2024, 15, y2024_day15::Day15, "Warehouse Woes"),
+    day!(2024, 16, y2024_day16::Day16, "Reindeer Maze"),
+    day!(2024, 17, y2024_day17::Day17, "Chronospatial Computer"),
+    day!(2024, 18, y2024_day18::Day18, "RAM Run"),
+    day!(2024, 19, y2024_day19::Day19, "Linen Layout"),
+    day!(2024, 20, y2024_day20::Day20, "Race Condition"),
+    day!(2024, 21, y2024_day21::Day21, "Keypad Conundrum"),
+    day!(2024, 22, y2024_day22::Day22, "Monkey Market"),
+    day!(2024, 23, y2024_day23::Day23, "LAN Party"),
+    day!(2024, 24, y2024_day24::Day24, "Crossed Wires"),
+    day!(2024, 25, y2024_day25::Day25, "Code Chronicle"),
+];
+
+pub fn find(year: u16, number: u8) -> Option<&'static Day> {
+    DAYS.iter()
+        .find(|day| day.year == year && day.number == number)
+}
+
+pub fn of_year(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |day| day.year == year)
+}
+
+/// Every year with at least one solved day, oldest first.
+pub fn years() -> Vec<u16> {
+    let mut years = DAYS.iter().map(|day| day.year).collect::<Vec<_>>();
+    years.sort();
+    years.dedup();
+    years
+}
+
+/// The year commands use when none is given: the most recent one.
+pub fn latest_year() -> u16 {
+    DAYS.iter()
+        .map(|day| day.year)
+        .max()
+        .expect("at least one day is registered")
+}
@@ -0,0 +1,115 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use common::{input_dir, input_file};
+
+use crate::{
+    cli::FetchArgs,
+    client::{Client, ClientError},
+};
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+pub fn input_path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(input_file(year, day))
+}
+
+/// Downloads the input of `day` of `year` into `dir`, unless it is already there. The client is
+/// only created when a download is needed, so cached days work without a session.
+pub fn fetch(
+    year: u16,
+    day: u8,
+    dir: &Path,
+    client: impl FnOnce() -> Result<Client, ClientError>,
+) -> Result<Fetched, String> {
+    let path = input_path(dir, year, day);
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+    let input = client()
+        .and_then(|client| client.get(year, day, "/input"))
+        .map_err(|e| e.to_string())?;
+    let parent = path.parent().unwrap_or(dir);
+    fs::create_dir_all(parent).map_err(|e| format!("cannot create `{}`: {e}", parent.display()))?;
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, input)
+        .and_then(|_| fs::rename(&partial, &path))
+        .map_err(|e| format!("cannot write `{}`: {e}", path.display()))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+pub fn execute(args: &FetchArgs) -> Result<(), String> {
+    let dir = input_dir(args.input_dir.as_deref()).map_err(|e| e.to_string())?;
+    match fetch(args.year, args.day, &dir, || {
+        Client::from_env(args.base_url.as_deref())
+    })? {
+        Fetched::Cached(path) => println!(
+            "day {} of {}: already cached at {}",
+            args.day,
+            args.year,
+            path.display()
+        ),
+        Fetched::Downloaded(path) => println!(
+            "day {} of {}: saved to {}",
+            args.day,
+            args.year,
+            path.display()
+        ),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod fetch_tests {
+    use std::env;
+
+    use super::*;
+    use crate::client::test_server::{serve, Reply};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()))
+    }
+
+    #[test]
+    fn test_fetch_downloads_then_caches() {
+        let dir = temp_dir("cache");
+        let (base_url, server) = serve(vec![Reply::new(200, "1\n10\n100\n2024\n")]);
+        let client = || Ok(Client::new(&base_url, "secret"));
+        let path = input_path(&dir, 2024, 22);
+        assert_eq!(
+            fetch(2024, 22, &dir, client),
+            Ok(Fetched::Downloaded(path.clone()))
+        );
+        assert!(path.ends_with("2024/day22.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n10\n100\n2024\n");
+        assert_eq!(server.join().unwrap().len(), 1);
+        assert_eq!(
+            fetch(2024, 22, &dir, || Err(ClientError::MissingSession)),
+            Ok(Fetched::Cached(path))
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_leaves_no_file_on_error() {
+        let dir = temp_dir("error");
+        let (base_url, server) = serve(vec![Reply::new(404, "Not Found")]);
+        let client = || Ok(Client::new(&base_url, "secret"));
+        assert_eq!(
+            fetch(2024, 25, &dir, client),
+            Err("the puzzle of day 25 of 2024 is not unlocked yet".to_string())
+        );
+        server.join().unwrap();
+        assert!(!input_path(&dir, 2024, 25).exists());
+        assert_eq!(
+            fetch(2024, 25, &dir, || Err(ClientError::MissingSession)),
+            Err(ClientError::MissingSession.to_string())
+        );
+    }
+}
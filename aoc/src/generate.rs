@@ -0,0 +1,62 @@
+use std::fs;
+
+use common::{generate::Generated, random::Rng};
+
+use crate::{
+    cli::{GenerateArgs, Selection},
+    days::Day,
+    run,
+};
+
+pub const DEFAULT_SEED: u64 = 2024;
+
+/// A synthetic input of `day` of the given `size`, the same for the same `seed`.
+pub fn generate(day: &Day, size: usize, seed: u64) -> Result<Generated, String> {
+    (day.generate)(size, &mut Rng::new(seed)).ok_or(format!(
+        "day {} of {} has no input generator",
+        day.number, day.year
+    ))
+}
+
+pub fn execute(args: &GenerateArgs) -> Result<(), String> {
+    let day = run::select(args.year, &Selection::One(args.day))?[0];
+    let generated = generate(day, args.size, args.seed)?;
+    match &args.output {
+        Some(path) => fs::write(path, &generated.input)
+            .map_err(|e| format!("cannot write input `{}`: {e}", path.display()))?,
+        None => print!("{}", generated.input),
+    }
+    if generated.params != Default::default() {
+        eprintln!("solve it with --param {}", generated.params);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod generate_tests {
+    use common::Answer;
+
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_generate() {
+        let day = days::find(2024, 18).unwrap();
+        let generated = generate(day, 9, 1).unwrap();
+        assert_eq!(generated, generate(day, 9, 1).unwrap());
+        assert_ne!(generated.input, generate(day, 9, 2).unwrap().input);
+        assert_eq!(generated.params.get("size"), Some(9));
+        let result = run::solve(day, &generated.input, &generated.params, &[1, 2]).unwrap();
+        assert!(matches!(result.answer(1), Answer::Number(_)));
+        assert!(matches!(result.answer(2), Answer::Coord(_, _)));
+    }
+
+    #[test]
+    fn test_every_day_generates() {
+        for day in days::of_year(2024) {
+            let generated = generate(day, 10, DEFAULT_SEED).unwrap();
+            let result = run::solve(day, &generated.input, &generated.params, &[1, 2]);
+            assert!(result.is_ok(), "day {}: {:?}", day.number, result.err());
+        }
+    }
+}
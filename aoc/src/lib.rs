@@ -0,0 +1,14 @@
+pub mod bench;
+pub mod cli;
+pub mod client;
+pub mod config;
+pub mod days;
+pub mod fetch;
+pub mod generate;
+pub mod run;
+pub mod scaffold;
+pub mod serve;
+pub mod step;
+pub mod submit;
+pub mod table;
+pub mod verify;
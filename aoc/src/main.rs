@@ -0,0 +1,45 @@
+use std::{env, process::ExitCode};
+
+use aoc::cli::{self, Command};
+use common::log::{self, LOG_VAR};
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let (verbose, args) = cli::split_verbose(&args);
+    match cli::log_filter(env::var(LOG_VAR).ok().as_deref(), verbose) {
+        Ok(filter) => log::init(filter),
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    }
+    let command = match cli::parse(&args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {e}\n\n{}", cli::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    let result = match command {
+        Command::Run(args) => aoc::run::execute(&args),
+        Command::Params(args) => aoc::config::execute(&args),
+        Command::Verify(args) => aoc::verify::execute(&args),
+        Command::Bench(args) => aoc::bench::execute(&args),
+        Command::Generate(args) => aoc::generate::execute(&args),
+        Command::Fetch(args) => aoc::fetch::execute(&args),
+        Command::Submit(args) => aoc::submit::execute(&args),
+        Command::New(args) => aoc::scaffold::execute(&args),
+        Command::Serve(args) => aoc::serve::execute(&args),
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,492 @@
+use std::{
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use common::{
+    observe::{FrameWriter, Observer},
+    read_path,
+    render::{GifWriter, ImageFormat, ImageWriter},
+    try_read_input, Answer, Example, Params, ParseError, Solution,
+};
+
+use crate::{
+    cli::{RunArgs, Selection},
+    config::Config,
+    days::{self, Day},
+    step,
+    table::format_table,
+};
+
+#[derive(Debug)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug)]
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+#[derive(Debug)]
+pub struct DayResult {
+    pub day: &'static Day,
+    pub run: Run,
+}
+
+impl DayResult {
+    pub fn answer(&self, part: u8) -> &Answer {
+        self.run
+            .parts
+            .iter()
+            .find(|result| result.part == part)
+            .map(|result| &result.answer)
+            .unwrap_or(&Answer::Unsolved)
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.run.parse
+            + self
+                .run
+                .parts
+                .iter()
+                .map(|result| result.elapsed)
+                .sum::<Duration>()
+    }
+}
+
+pub fn solve_parts<S: Solution>(
+    input: &str,
+    params: &Params,
+    parts: &[u8],
+) -> Result<Run, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse_with(input, params)?;
+    let parse = start.elapsed();
+    let parts = parts
+        .iter()
+        .filter_map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&parsed),
+                2 => S::part2(&parsed),
+                _ => Answer::Unsolved,
+            };
+            let elapsed = start.elapsed();
+            (answer != Answer::Unsolved).then_some(PartResult {
+                part,
+                answer,
+                elapsed,
+            })
+        })
+        .collect();
+    Ok(Run { parse, parts })
+}
+
+pub fn simulate_part<S: Solution>(
+    input: &str,
+    params: &Params,
+    part: u8,
+    observer: &mut dyn Observer,
+) -> Result<Answer, ParseError> {
+    let parsed = S::parse_with(input, params)?;
+    Ok(S::simulate(&parsed, part, observer))
+}
+
+/// Solves `part` of `day` while `observer` watches its simulation.
+pub fn simulate(
+    day: &'static Day,
+    input: &str,
+    params: &Params,
+    part: u8,
+    observer: &mut dyn Observer,
+) -> Result<Answer, String> {
+    match (day.simulate)(input, params, part, observer)
+        .map_err(|e| format!("cannot parse the input of day {}: {e}", day.number))?
+    {
+        Answer::Unsolved => Err(format!(
+            "part {part} of day {} of {} is not a simulation",
+            day.number, day.year
+        )),
+        answer => Ok(answer),
+    }
+}
+
+pub fn solve(
+    day: &'static Day,
+    input: &str,
+    params: &Params,
+    parts: &[u8],
+) -> Result<DayResult, String> {
+    let run = (day.solve)(input, params, parts)
+        .map_err(|e| format!("cannot parse the input of day {}: {e}", day.number))?;
+    Ok(DayResult { day, run })
+}
+
+pub fn results_table(results: &[DayResult]) -> String {
+    let rows = results
+        .iter()
+        .map(|result| {
+            [
+                result.day.number.to_string(),
+                result.day.title.to_string(),
+                result.answer(1).to_string(),
+                result.answer(2).to_string(),
+                format!("{:.2?}", result.elapsed()),
+            ]
+        })
+        .collect::<Vec<_>>();
+    format_table(["Day", "Title", "Part 1", "Part 2", "Time"], &rows)
+}
+
+pub fn select(year: u16, selection: &Selection) -> Result<Vec<&'static Day>, String> {
+    let selected = match selection {
+        Selection::One(number) => days::find(year, *number).into_iter().collect(),
+        Selection::All => days::of_year(year).collect::<Vec<_>>(),
+    };
+    match (selected.is_empty(), selection) {
+        (true, Selection::One(number)) => Err(format!("day {number} of {year} is not solved yet")),
+        (true, Selection::All) => Err(format!(
+            "no day of {year} is solved yet, solved years: {:?}",
+            days::years()
+        )),
+        (false, _) => Ok(selected),
+    }
+}
+
+pub fn load_input(
+    day: &Day,
+    input: Option<&Path>,
+    input_dir: Option<&Path>,
+) -> Result<String, String> {
+    match input {
+        Some(path) => read_path(path),
+        None => try_read_input(day.year, day.number, input_dir),
+    }
+    .map_err(|e| e.to_string())
+}
+
+/// The `number`th example of `day`, counting from 1.
+pub fn example(day: &Day, number: usize) -> Result<&'static Example, String> {
+    number
+        .checked_sub(1)
+        .and_then(|index| day.examples.get(index))
+        .ok_or(match day.examples.len() {
+            0 => format!("day {} of {} has no examples", day.number, day.year),
+            n => format!(
+                "day {} of {} has {n} example(s), not {number}",
+                day.number, day.year
+            ),
+        })
+}
+
+/// Describes each answer of the `parts` run in `result` that differs from the one
+/// `example` expects, including those that gave no answer.
+pub fn example_mismatches(result: &DayResult, example: &Example, parts: &[u8]) -> Vec<String> {
+    parts
+        .iter()
+        .filter_map(|&part| {
+            let expected = example.expected(part)?;
+            match result.answer(part) {
+                Answer::Unsolved => {
+                    Some(format!("part {part}: expected {expected}, got no answer"))
+                }
+                answer => (answer.to_string() != expected)
+                    .then(|| format!("part {part}: expected {expected}, got {answer}")),
+            }
+        })
+        .collect()
+}
+
+/// Runs the `number`th example of `day`, with `overrides` replacing its parameters.
+/// Without `part`, only the parts the example has answers for are run, since some
+/// solvers never finish on an example of the other part.
+fn run_example(
+    day: &'static Day,
+    number: usize,
+    part: Option<u8>,
+    overrides: &Params,
+) -> Result<(), String> {
+    let example = example(day, number)?;
+    let mut params = example.to_params();
+    params.merge(overrides);
+    params
+        .check(day.params)
+        .map_err(|e| format!("day {} of {}: {e}", day.number, day.year))?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => [1, 2]
+            .into_iter()
+            .filter(|&part| example.expected(part).is_some())
+            .collect(),
+    };
+    let result = solve(day, example.input, &params, &parts)?;
+    println!("Example {number} of day {}: {}", day.number, example.name);
+    println!("{}", results_table(std::slice::from_ref(&result)));
+    let mismatches = example_mismatches(&result, example, &parts);
+    match mismatches.is_empty() {
+        true => Ok(()),
+        false => Err(mismatches.join("\n")),
+    }
+}
+
+/// The input and parameters `args` run `day` on: those of an example, or else the
+/// puzzle input with the parameters of the config and the command line.
+pub fn prepare(day: &'static Day, args: &RunArgs) -> Result<(String, Params), String> {
+    let (input, mut params) = match args.example {
+        Some(number) => {
+            let example = example(day, number)?;
+            (example.input.to_string(), example.to_params())
+        }
+        None => (
+            load_input(day, args.input.as_deref(), args.input_dir.as_deref())?,
+            Config::find(args.config.as_deref(), args.year)?.params(day, &Params::default())?,
+        ),
+    };
+    params.merge(&args.params);
+    params
+        .check(day.params)
+        .map_err(|e| format!("day {} of {}: {e}", day.number, day.year))?;
+    Ok((input, params))
+}
+
+/// Writes each tick of the simulation of `day` to `dir`.
+fn write_frames(day: &'static Day, args: &RunArgs, dir: &Path) -> Result<(), String> {
+    let (input, params) = prepare(day, args)?;
+    let part = args.part.unwrap_or(1);
+    let mut writer = FrameWriter::new(dir)?;
+    let answer = simulate(day, &input, &params, part, &mut writer)?;
+    let frames = writer.finish()?;
+    println!("Part {part} = {answer}");
+    println!("wrote {frames} frame(s) to {}", dir.display());
+    Ok(())
+}
+
+/// The pixels of a side of a cell when `--scale` is not given.
+pub const DEFAULT_SCALE: usize = 4;
+
+/// Draws each tick of the simulation of `day` to a PNG in `dir`, and all of them to
+/// `animation.gif` there.
+fn render_frames(day: &'static Day, args: &RunArgs, dir: &Path) -> Result<(), String> {
+    let (input, params) = prepare(day, args)?;
+    let part = args.part.unwrap_or(1);
+    let scale = args.scale.unwrap_or(DEFAULT_SCALE);
+    let images = ImageWriter::new(dir, ImageFormat::Png, scale)?;
+    let animation = dir.join("animation.gif");
+    let mut writers = (images, GifWriter::new(&animation, scale));
+    let answer = simulate(day, &input, &params, part, &mut writers)?;
+    let frames = writers.0.finish()?;
+    writers.1.finish()?;
+    println!("Part {part} = {answer}");
+    println!("rendered {frames} frame(s) to {}", dir.display());
+    Ok(())
+}
+
+pub fn execute(args: &RunArgs) -> Result<(), String> {
+    if let Some(dir) = &args.frames {
+        let day = select(args.year, &args.selection)?[0];
+        return write_frames(day, args, dir);
+    }
+    if let Some(dir) = &args.render {
+        let day = select(args.year, &args.selection)?[0];
+        return render_frames(day, args, dir);
+    }
+    if args.step {
+        let day = select(args.year, &args.selection)?[0];
+        return step::step(day, args);
+    }
+    if let Some(number) = args.example {
+        let day = select(args.year, &args.selection)?[0];
+        return run_example(day, number, args.part, &args.params);
+    }
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let config = Config::find(args.config.as_deref(), args.year)?;
+    let results = select(args.year, &args.selection)?
+        .into_iter()
+        .map(|day| {
+            let params = config.params(day, &args.params)?;
+            let input = load_input(day, args.input.as_deref(), args.input_dir.as_deref())?;
+            solve(day, &input, &params, &parts)
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    println!("{}", results_table(&results));
+    Ok(())
+}
+
+#[cfg(test)]
+mod run_tests {
+    use std::{env, fs};
+
+    use common::{observe::FrameCollector, render::Palette};
+
+    use super::*;
+
+    #[test]
+    fn test_solve_selected_parts() {
+        let day = days::find(2024, 1).unwrap();
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+        let result = solve(day, input, &Params::default(), &[2]).unwrap();
+        assert_eq!(result.run.parts.len(), 1);
+        assert_eq!(result.answer(1), &Answer::Unsolved);
+        assert_eq!(result.answer(2), &Answer::Number(31));
+    }
+
+    #[test]
+    fn test_solve_missing_part() {
+        let day = days::find(2024, 25).unwrap();
+        let input = "#####\n.####\n.####\n.####\n.#.#.\n.#...\n.....";
+        let result = solve(day, input, &Params::default(), &[2]).unwrap();
+        assert!(result.run.parts.is_empty());
+    }
+
+    #[test]
+    fn test_solve_malformed_input() {
+        let day = days::find(2024, 1).unwrap();
+        assert_eq!(
+            solve(day, "3   4\n4   x", &Params::default(), &[1]).unwrap_err(),
+            "cannot parse the input of day 1: line 2, column 5: expected a number, found `x`"
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let day = days::find(2024, 18).unwrap();
+        assert_eq!(example(day, 1).unwrap().name, "example");
+        assert_eq!(
+            example(day, 2).unwrap_err(),
+            "day 18 of 2024 has 1 example(s), not 2"
+        );
+        assert!(example(day, 0).is_err());
+    }
+
+    #[test]
+    fn test_every_example() {
+        for day in days::DAYS {
+            for example in day.examples {
+                let parts = [1, 2].map(|part| example.expected(part).map(|_| part));
+                let parts = parts.into_iter().flatten().collect::<Vec<_>>();
+                let result = solve(day, example.input, &example.to_params(), &parts).unwrap();
+                assert_eq!(
+                    example_mismatches(&result, example, &parts),
+                    Vec::<String>::new(),
+                    "day {} example {}",
+                    day.number,
+                    example.name
+                );
+                assert_eq!(result.run.parts.len(), parts.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_example_mismatches() {
+        let day = days::find(2024, 1).unwrap();
+        let example = Example::new("wrong", "1   2\n").part1("2").part2("0");
+        let result = solve(day, example.input, &example.to_params(), &[1, 2]).unwrap();
+        assert_eq!(
+            example_mismatches(&result, &example, &[1, 2]),
+            vec!["part 1: expected 2, got 1".to_string()]
+        );
+        assert_eq!(
+            example_mismatches(&result, &example, &[2]),
+            Vec::<String>::new()
+        );
+
+        let day = days::find(2024, 25).unwrap();
+        let example = Example::new(
+            "no part 2",
+            "#####\n.####\n.####\n.####\n.#.#.\n.#...\n.....",
+        )
+        .part1("0")
+        .part2("1");
+        let result = solve(day, example.input, &example.to_params(), &[1, 2]).unwrap();
+        assert_eq!(
+            example_mismatches(&result, &example, &[1, 2]),
+            vec!["part 2: expected 1, got no answer".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_simulate() {
+        let day = days::find(2024, 15).unwrap();
+        let input = "######\n#@.O.#\n######\n\n>>>";
+        let mut frames = FrameCollector::default();
+        assert_eq!(
+            simulate(day, input, &Params::default(), 1, &mut frames),
+            Ok(Answer::Number(104))
+        );
+        assert_eq!(frames.frames().len(), 4);
+        assert_eq!(frames.frames()[3].text, "######\n#..@O#\n######");
+        assert_eq!(
+            simulate(
+                days::find(2024, 1).unwrap(),
+                "1   2",
+                &Params::default(),
+                1,
+                &mut ()
+            ),
+            Err("part 1 of day 1 of 2024 is not a simulation".to_string())
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let day = days::find(2024, 14).unwrap();
+        let example = example(day, 1).unwrap();
+        let dir = env::temp_dir().join(format!("aoc-run-render-{}", std::process::id()));
+        let images = ImageWriter::new(&dir, ImageFormat::Ppm, 2).unwrap();
+        let animation = GifWriter::new(&dir.join("animation.gif"), 2);
+        let mut observers = ((images, animation), FrameCollector::default());
+        assert_eq!(
+            simulate(day, example.input, &example.to_params(), 1, &mut observers),
+            Ok(Answer::Number(12))
+        );
+        let ((images, animation), frames) = observers;
+        assert_eq!(images.finish(), Ok(101));
+        assert_eq!(animation.finish(), Ok(101));
+        let ppm = fs::read(dir.join("frame_00100.ppm")).unwrap();
+        let header = b"P6\n22 14\n255\n";
+        assert_eq!(ppm[..header.len()], header[..]);
+        let palette = Palette::default();
+        for (y, line) in frames.frames()[100].text.lines().enumerate() {
+            for (x, cell) in line.chars().enumerate() {
+                let expected = palette.colors()[palette.index(cell) as usize];
+                for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                    let offset = header.len() + ((2 * y + dy) * 22 + 2 * x + dx) * 3;
+                    assert_eq!(ppm[offset..offset + 3], expected, "cell {cell} at {x},{y}");
+                }
+            }
+        }
+        let gif = fs::read(dir.join("animation.gif")).unwrap();
+        assert_eq!(gif[..10], *b"GIF89a\x16\0\x0e\0");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_results_table() {
+        let day = days::find(2024, 1).unwrap();
+        let result = DayResult {
+            day,
+            run: Run {
+                parse: Duration::from_millis(1),
+                parts: vec![PartResult {
+                    part: 1,
+                    answer: Answer::Number(11),
+                    elapsed: Duration::from_millis(2),
+                }],
+            },
+        };
+        assert_eq!(
+            results_table(&[result]),
+            "Day | Title              | Part 1 | Part 2 | Time
+----+--------------------+--------+--------+-------
+1   | Historian Hysteria | 11     | -      | 3.00ms"
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use common::{input_dir, input_file, workspace_root};
+
+use crate::cli::NewArgs;
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+
+const MEMBERS: &str = "members = [";
+const DEPENDENCIES: &str = "[workspace.dependencies]";
+
+fn crate_name(year: u16, day: u8) -> String {
+    format!("y{year}_day{day:02}")
+}
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{crate}}", &crate_name(year, day))
+        .replace("{{module}}", &format!("day{day:02}"))
+        .replace("{{type}}", &format!("Day{day:02}"))
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Adds `member` to the `members` list of a workspace manifest, keeping it sorted and
+/// on one line or one per line as it was. Returns `None` if it is already there.
+fn insert_member(manifest: &str, member: &str) -> Result<Option<String>, String> {
+    let start = manifest
+        .find(MEMBERS)
+        .ok_or("no `members` list in the workspace manifest")?
+        + MEMBERS.len();
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("unterminated `members` list in the workspace manifest")?;
+    let list = &manifest[start..end];
+    let mut members = list
+        .split(',')
+        .map(|m| m.trim().trim_matches('"'))
+        .filter(|m| !m.is_empty())
+        .collect::<Vec<_>>();
+    if members.contains(&member) {
+        return Ok(None);
+    }
+    let position = members
+        .iter()
+        .position(|&m| m > member)
+        .unwrap_or(members.len());
+    members.insert(position, member);
+    let quoted = members.iter().map(|m| format!("\"{m}\""));
+    let list = match list.contains('\n') {
+        true => quoted.map(|m| format!("\n    {m},")).collect::<String>() + "\n",
+        false => quoted.collect::<Vec<_>>().join(", "),
+    };
+    Ok(Some(format!(
+        "{}{list}{}",
+        &manifest[..start],
+        &manifest[end..]
+    )))
+}
+
+/// Adds `name = { path = "<path>" }` to `[workspace.dependencies]`, after the last day.
+/// Returns `None` if `name` is already there.
+fn insert_dependency(manifest: &str, name: &str, path: &str) -> Result<Option<String>, String> {
+    let start = manifest
+        .find(DEPENDENCIES)
+        .ok_or("no `[workspace.dependencies]` in the workspace manifest")?;
+    let section = manifest[start..]
+        .lines()
+        .skip(1)
+        .take_while(|l| !l.trim_start().starts_with('['));
+    let mut insert_at = start + DEPENDENCIES.len();
+    let mut offset = insert_at + 1;
+    for line in section {
+        if line.split('=').next().map(str::trim) == Some(name) {
+            return Ok(None);
+        }
+        let end = offset + line.len();
+        if line.starts_with('y') || line.starts_with("common") {
+            insert_at = end;
+        }
+        offset = end + 1;
+    }
+    let entry = format!("\n{name} = {{ path = \"{path}\" }}");
+    Ok(Some(format!(
+        "{}{entry}{}",
+        &manifest[..insert_at],
+        &manifest[insert_at..]
+    )))
+}
+
+fn create(path: &Path, contents: &str, created: &mut Vec<PathBuf>) -> Result<(), String> {
+    if path.exists() {
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("cannot create `{}`: {e}", parent.display()))?;
+    }
+    fs::write(path, contents).map_err(|e| format!("cannot write `{}`: {e}", path.display()))?;
+    created.push(path.to_path_buf());
+    Ok(())
+}
+
+/// Generates the crate of `day` of `year` under `root` and registers it in the workspace manifest.
+/// Existing files are left alone, so running it again only fills in what is missing.
+/// Returns the files it created or changed.
+pub fn scaffold(root: &Path, inputs: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    let name = crate_name(year, day);
+    let member = format!("days/{year}/day{day:02}");
+    let dir = root.join(&member);
+    let mut changed = vec![];
+    create(
+        &dir.join("Cargo.toml"),
+        &render(CARGO_TEMPLATE, year, day),
+        &mut changed,
+    )?;
+    create(
+        &dir.join("src/main.rs"),
+        &render(MAIN_TEMPLATE, year, day),
+        &mut changed,
+    )?;
+    create(
+        &dir.join("src/lib.rs"),
+        &render(LIB_TEMPLATE, year, day),
+        &mut changed,
+    )?;
+    create(&dir.join("examples/example.txt"), "", &mut changed)?;
+    create(&inputs.join(input_file(year, day)), "", &mut changed)?;
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .map_err(|e| format!("cannot read `{}`: {e}", manifest_path.display()))?;
+    let with_member = insert_member(&manifest, &member)?;
+    let updated = insert_dependency(with_member.as_deref().unwrap_or(&manifest), &name, &member)?
+        .or(with_member);
+    if let Some(updated) = updated {
+        fs::write(&manifest_path, updated)
+            .map_err(|e| format!("cannot write `{}`: {e}", manifest_path.display()))?;
+        changed.push(manifest_path);
+    }
+    Ok(changed)
+}
+
+pub fn execute(args: &NewArgs) -> Result<(), String> {
+    let root = workspace_root().map_err(|e| e.to_string())?;
+    let inputs = input_dir(args.input_dir.as_deref()).map_err(|e| e.to_string())?;
+    let changed = scaffold(&root, &inputs, args.year, args.day)?;
+    if changed.is_empty() {
+        println!("day {} of {} is already set up", args.day, args.year);
+    }
+    for path in changed {
+        println!("wrote {}", path.display());
+    }
+    println!(
+        "add `{}.workspace = true` to aoc/Cargo.toml and register it in aoc/src/days.rs to run it with `aoc`",
+        crate_name(args.year, args.day)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod scaffold_tests {
+    use std::env;
+
+    use super::*;
+
+    const MANIFEST: &str = r#"[workspace]
+members = ["aoc", "days/2024/day01", "days/2024/day03"]
+resolver = "2"
+
+[workspace.dependencies]
+itertools = "0.13.0"
+common = { path = "common"}
+y2024_day01 = { path = "days/2024/day01" }
+y2024_day03 = { path = "days/2024/day03" }
+
+[profile.release]
+debug = true
+"#;
+
+    #[test]
+    fn test_insert_member() {
+        let manifest = insert_member(MANIFEST, "days/2024/day02").unwrap().unwrap();
+        assert!(manifest.contains(
+            r#"members = ["aoc", "days/2024/day01", "days/2024/day02", "days/2024/day03"]"#
+        ));
+        assert_eq!(insert_member(&manifest, "days/2024/day02"), Ok(None));
+        let manifest = insert_member(&manifest, "days/2023/day25")
+            .unwrap()
+            .unwrap();
+        assert!(manifest.contains(r#"["aoc", "days/2023/day25", "days/2024/day01","#));
+        let multi_line = "members = [\n    \"aoc\",\n]\n";
+        assert_eq!(
+            insert_member(multi_line, "days/2024/day01"),
+            Ok(Some(
+                "members = [\n    \"aoc\",\n    \"days/2024/day01\",\n]\n".to_string()
+            ))
+        );
+        assert!(insert_member("[workspace]\n", "days/2024/day01").is_err());
+    }
+
+    #[test]
+    fn test_insert_dependency() {
+        let manifest = insert_dependency(MANIFEST, "y2024_day04", "days/2024/day04")
+            .unwrap()
+            .unwrap();
+        assert!(manifest.contains(
+            "y2024_day03 = { path = \"days/2024/day03\" }\ny2024_day04 = { path = \"days/2024/day04\" }\n\n[profile.release]"
+        ));
+        assert_eq!(
+            insert_dependency(&manifest, "y2024_day04", "days/2024/day04"),
+            Ok(None)
+        );
+        assert_eq!(
+            insert_dependency(MANIFEST, "y2024_day01", "days/2024/day01"),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_scaffold_is_idempotent() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+        let inputs = root.join("inputs");
+        let changed = scaffold(&root, &inputs, 2024, 7).unwrap();
+        assert_eq!(changed.len(), 6);
+        let cargo = fs::read_to_string(root.join("days/2024/day07/Cargo.toml")).unwrap();
+        assert!(cargo.contains("name = \"y2024_day07\""));
+        let lib = fs::read_to_string(root.join("days/2024/day07/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day07;"));
+        assert!(lib.contains("mod day07_tests {"));
+        assert!(lib.contains("check_examples::<Day07>()"));
+        assert!(root.join("days/2024/day07/examples/example.txt").exists());
+        let main = fs::read_to_string(root.join("days/2024/day07/src/main.rs")).unwrap();
+        assert!(main.contains("use y2024_day07::Day07;"));
+        assert!(main.contains("read_input(2024, 7)"));
+        assert_eq!(
+            fs::read_to_string(inputs.join("2024/day07.txt")).unwrap(),
+            ""
+        );
+        let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert_eq!(manifest.matches("\"days/2024/day07\"").count(), 2);
+        assert!(manifest.contains("y2024_day07 = {"));
+        assert_eq!(scaffold(&root, &inputs, 2024, 7), Ok(vec![]));
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            manifest
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,550 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream},
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
+    thread,
+};
+
+use common::{info, warn, Answer, Params, ParseError};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cli::{Selection, ServeArgs},
+    config::Config,
+    days::{self, Day},
+    run::{self, Run},
+};
+
+pub const DEFAULT_PORT: u16 = 2024;
+const MAX_BODY: usize = 16 << 20;
+/// The stack of the thread of each solve. A stack overflow aborts the whole service
+/// rather than unwinding, so the solvers get far more than the usual thread stack.
+const SOLVE_STACK: usize = 256 << 20;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Solved {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParamInfo {
+    pub name: String,
+    pub default: u64,
+    pub value: u64,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayInfo {
+    pub year: u16,
+    pub day: u8,
+    pub title: String,
+    pub params: Vec<ParamInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Failure {
+    pub error: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub query: String,
+    pub body: Vec<u8>,
+}
+
+/// A JSON reply.
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn json<T: Serialize>(status: u16, value: &T) -> Self {
+        Self {
+            status,
+            body: serde_json::to_string(value).expect("replies serialize to JSON"),
+        }
+    }
+
+    fn failure(status: u16, error: impl Into<String>) -> Self {
+        Self::json(
+            status,
+            &Failure {
+                error: error.into(),
+            },
+        )
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        _ => "Internal Server Error",
+    }
+}
+
+/// Reads an HTTP/1.1 request with its body, which must come with a `Content-Length`.
+pub fn read_request(reader: &mut impl BufRead) -> Result<Request, Response> {
+    let mut line = String::new();
+    reader
+        .read_line(&mut line)
+        .map_err(|e| Response::failure(400, format!("cannot read the request: {e}")))?;
+    let mut words = line.split_whitespace();
+    let (Some(method), Some(target), Some(_)) = (words.next(), words.next(), words.next()) else {
+        return Err(Response::failure(400, "malformed request line"));
+    };
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let mut length = None;
+    loop {
+        let mut line = String::new();
+        reader
+            .read_line(&mut line)
+            .map_err(|e| Response::failure(400, format!("cannot read the request: {e}")))?;
+        if line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                let value = value.trim();
+                length = Some(value.parse::<usize>().map_err(|_| {
+                    Response::failure(400, format!("invalid Content-Length `{value}`"))
+                })?);
+            }
+        }
+    }
+    let length = match (length, method) {
+        (Some(length), _) if length > MAX_BODY => {
+            return Err(Response::failure(
+                413,
+                format!("the body is larger than {MAX_BODY} bytes"),
+            ))
+        }
+        (Some(length), _) => length,
+        (None, "POST") => return Err(Response::failure(411, "missing Content-Length")),
+        (None, _) => 0,
+    };
+    let mut body = vec![0; length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|e| Response::failure(400, format!("cannot read the body: {e}")))?;
+    Ok(Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        query: query.to_string(),
+        body,
+    })
+}
+
+fn list_days(year: u16, config: &Config) -> Response {
+    let days = days::of_year(year)
+        .map(|day| {
+            let params = config.params(day, &Params::default())?;
+            Ok(DayInfo {
+                year: day.year,
+                day: day.number,
+                title: day.title.to_string(),
+                params: day
+                    .params
+                    .iter()
+                    .map(|param| ParamInfo {
+                        name: param.name.to_string(),
+                        default: param.default,
+                        value: params.value(param),
+                        description: param.help.to_string(),
+                    })
+                    .collect(),
+            })
+        })
+        .collect::<Result<Vec<_>, String>>();
+    match days {
+        Ok(days) => Response::json(200, &days),
+        Err(e) => Response::failure(500, e),
+    }
+}
+
+/// Runs `part` of `day` on `input` on a thread of its own, with a [`SOLVE_STACK`] stack,
+/// failing if the thread cannot start or panics.
+fn run_solver(
+    day: &'static Day,
+    part: u8,
+    input: &str,
+    params: &Params,
+) -> Result<Result<Run, ParseError>, String> {
+    thread::scope(|scope| {
+        thread::Builder::new()
+            .name(format!("day{:02}", day.number))
+            .stack_size(SOLVE_STACK)
+            .spawn_scoped(scope, || (day.solve)(input, params, &[part]))
+            .map_err(|e| format!("cannot start the solver: {e}"))?
+            .join()
+            .map_err(|_| "the solver panicked".to_string())
+    })
+}
+
+/// Solves `part` of `day` on the body, with the parameters of the config and then
+/// those of the query string, such as `size=7&bytes=12`.
+fn solve(day: &'static Day, part: u8, config: &Config, request: &Request) -> Response {
+    let Ok(input) = std::str::from_utf8(&request.body) else {
+        return Response::failure(400, "the body is not UTF-8 text");
+    };
+    let overrides = match request.query.replace('&', ",").parse::<Params>() {
+        Ok(overrides) => overrides,
+        Err(e) => return Response::failure(400, e),
+    };
+    let params = match config.params(day, &overrides) {
+        Ok(params) => params,
+        Err(e) => return Response::failure(400, e),
+    };
+    let mut solved = Solved {
+        year: day.year,
+        day: day.number,
+        part,
+        answer: None,
+        parse_ns: None,
+        solve_ns: None,
+        error: None,
+    };
+    let result = match run_solver(day, part, input, &params) {
+        Ok(result) => result,
+        Err(e) => return Response::failure(500, e),
+    };
+    match result {
+        Ok(run) => {
+            solved.parse_ns = Some(run.parse.as_nanos() as u64);
+            match run.parts.first() {
+                Some(result) if result.answer != Answer::Unsolved => {
+                    solved.answer = Some(result.answer.to_string());
+                    solved.solve_ns = Some(result.elapsed.as_nanos() as u64);
+                    Response::json(200, &solved)
+                }
+                _ => Response::failure(
+                    404,
+                    format!(
+                        "part {part} of day {} of {} is not solved",
+                        day.number, day.year
+                    ),
+                ),
+            }
+        }
+        Err(e) => {
+            solved.error = Some(format!("cannot parse the input of day {}: {e}", day.number));
+            Response::json(422, &solved)
+        }
+    }
+}
+
+/// Routes a request of the service for `year`.
+pub fn handle(year: u16, config: &Config, request: &Request) -> Response {
+    let segments = request
+        .path
+        .trim_matches('/')
+        .split('/')
+        .collect::<Vec<_>>();
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["days"]) => list_days(year, config),
+        ("POST", ["solve", day, part]) => {
+            let day = day
+                .parse::<u8>()
+                .ok()
+                .and_then(|number| days::find(year, number));
+            let part = match *part {
+                "1" => Some(1),
+                "2" => Some(2),
+                _ => None,
+            };
+            match (day, part) {
+                (Some(day), Some(part)) => solve(day, part, config, request),
+                _ => Response::failure(404, format!("no solver at {}", request.path)),
+            }
+        }
+        (_, ["days"] | ["solve", _, _]) => Response::failure(
+            405,
+            format!("{} is not allowed on {}", request.method, request.path),
+        ),
+        _ => Response::failure(404, format!("no route {}", request.path)),
+    }
+}
+
+fn respond(mut stream: TcpStream, year: u16, config: &Config) {
+    let response = match read_request(&mut BufReader::new(&mut stream)) {
+        Ok(request) => {
+            let response = panic::catch_unwind(AssertUnwindSafe(|| handle(year, config, &request)))
+                .unwrap_or_else(|_| Response::failure(500, "the solver panicked"));
+            info!("{} {} {}", request.method, request.path, response.status);
+            response
+        }
+        Err(response) => response,
+    };
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        reason(response.status),
+        response.body.len()
+    );
+    if let Err(e) = stream
+        .write_all(head.as_bytes())
+        .and_then(|()| stream.write_all(response.body.as_bytes()))
+    {
+        warn!("cannot reply: {e}");
+    }
+}
+
+/// The solve service of a year, answering each connection on its own thread.
+pub struct Server {
+    listener: TcpListener,
+    year: u16,
+    config: Arc<Config>,
+}
+
+impl Server {
+    /// Listens on `port` of localhost, or on any free one for port 0.
+    pub fn bind(port: u16, year: u16, config: Config) -> Result<Self, String> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+            .map_err(|e| format!("cannot listen on port {port}: {e}"))?;
+        Ok(Self {
+            listener,
+            year,
+            config: Arc::new(config),
+        })
+    }
+
+    pub fn local_addr(&self) -> Result<SocketAddr, String> {
+        self.listener.local_addr().map_err(|e| e.to_string())
+    }
+
+    pub fn run(self) -> Result<(), String> {
+        for stream in self.listener.incoming() {
+            match stream {
+                Ok(stream) => {
+                    let config = Arc::clone(&self.config);
+                    let year = self.year;
+                    thread::spawn(move || respond(stream, year, &config));
+                }
+                Err(e) => warn!("cannot accept a connection: {e}"),
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn execute(args: &ServeArgs) -> Result<(), String> {
+    run::select(args.year, &Selection::All)?;
+    let config = Config::find(args.config.as_deref(), args.year)?;
+    let server = Server::bind(args.port, args.year, config)?;
+    println!(
+        "serving the days of {} on http://{}",
+        args.year,
+        server.local_addr()?
+    );
+    server.run()
+}
+
+#[cfg(test)]
+mod serve_tests {
+    use super::*;
+
+    const LISTS: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    /// Starts a server on a free port and returns its base URL.
+    fn start(config: Config) -> String {
+        let server = Server::bind(0, 2024, config).unwrap();
+        let base_url = format!("http://{}", server.local_addr().unwrap());
+        thread::spawn(move || server.run());
+        base_url
+    }
+
+    /// The status and body of a reply, whatever its status.
+    fn reply(result: Result<ureq::Response, ureq::Error>) -> (u16, String) {
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => panic!("{e}"),
+        };
+        (response.status(), response.into_string().unwrap())
+    }
+
+    #[test]
+    fn test_read_request() {
+        let raw = "POST /solve/1/2?size=7 HTTP/1.1\r\nHost: x\r\ncontent-length: 5\r\n\r\n1   2";
+        assert_eq!(
+            read_request(&mut raw.as_bytes()),
+            Ok(Request {
+                method: "POST".to_string(),
+                path: "/solve/1/2".to_string(),
+                query: "size=7".to_string(),
+                body: b"1   2".to_vec(),
+            })
+        );
+        let missing = "POST /solve/1/2 HTTP/1.1\r\n\r\n";
+        assert_eq!(
+            read_request(&mut missing.as_bytes()).unwrap_err().status,
+            411
+        );
+        assert_eq!(
+            read_request(&mut "nonsense\r\n\r\n".as_bytes())
+                .unwrap_err()
+                .status,
+            400
+        );
+        let large = format!(
+            "POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY + 1
+        );
+        assert_eq!(read_request(&mut large.as_bytes()).unwrap_err().status, 413);
+    }
+
+    #[test]
+    fn test_routes() {
+        let request = |method: &str, path: &str, body: &str| Request {
+            method: method.to_string(),
+            path: path.to_string(),
+            query: String::new(),
+            body: body.as_bytes().to_vec(),
+        };
+        let config = Config::default();
+        assert_eq!(
+            handle(2024, &config, &request("GET", "/days", "")).status,
+            200
+        );
+        assert_eq!(
+            handle(2024, &config, &request("POST", "/days", "")).status,
+            405
+        );
+        assert_eq!(
+            handle(2024, &config, &request("GET", "/solve/1/1", "")).status,
+            405
+        );
+        assert_eq!(
+            handle(2024, &config, &request("POST", "/solve/26/1", "")).status,
+            404
+        );
+        assert_eq!(
+            handle(2024, &config, &request("POST", "/solve/1/3", "")).status,
+            404
+        );
+        assert_eq!(
+            handle(2024, &config, &request("POST", "/solve/25/2", "")).status,
+            404
+        );
+        assert_eq!(handle(2024, &config, &request("GET", "/", "")).status, 404);
+        assert_eq!(
+            handle(2023, &config, &request("POST", "/solve/1/1", LISTS)).status,
+            404
+        );
+    }
+
+    #[test]
+    fn test_solve() {
+        let base_url = start(Config::default());
+        let (status, body) = reply(ureq::post(&format!("{base_url}/solve/1/2")).send_string(LISTS));
+        assert_eq!(status, 200);
+        let solved = serde_json::from_str::<Solved>(&body).unwrap();
+        assert_eq!((solved.year, solved.day, solved.part), (2024, 1, 2));
+        assert_eq!(solved.answer.as_deref(), Some("31"));
+        assert!(solved.parse_ns.is_some() && solved.solve_ns.is_some());
+        assert_eq!(solved.error, None);
+
+        let (status, body) = reply(ureq::post(&format!("{base_url}/solve/1/1")).send_string("1 x"));
+        assert_eq!(status, 422);
+        let solved = serde_json::from_str::<Solved>(&body).unwrap();
+        assert_eq!(solved.answer, None);
+        assert!(solved
+            .error
+            .unwrap()
+            .starts_with("cannot parse the input of day 1:"));
+    }
+
+    #[test]
+    fn test_solve_with_params() {
+        let config = Config::parse("[day18]\nsize = 7").unwrap();
+        let base_url = start(config);
+        let example = days::find(2024, 18).unwrap().examples[0].input;
+        let url = format!("{base_url}/solve/18/1?bytes=12");
+        let (status, body) = reply(ureq::post(&url).send_string(example));
+        assert_eq!(status, 200, "{body}");
+        let solved = serde_json::from_str::<Solved>(&body).unwrap();
+        assert_eq!(solved.answer.as_deref(), Some("22"));
+
+        let url = format!("{base_url}/solve/18/1?threshold=12");
+        let (status, body) = reply(ureq::post(&url).send_string(example));
+        assert_eq!(status, 400);
+        assert_eq!(
+            serde_json::from_str::<Failure>(&body).unwrap().error,
+            "day 18 of 2024: unknown parameter `threshold`, expected one of size, bytes"
+        );
+    }
+
+    fn panicking(_: &str, _: &Params, _: &[u8]) -> Result<Run, ParseError> {
+        panic!("a solver bug")
+    }
+
+    #[test]
+    fn test_solver_failure() {
+        let request = Request {
+            method: "POST".to_string(),
+            path: "/solve/1/1".to_string(),
+            query: String::new(),
+            body: LISTS.as_bytes().to_vec(),
+        };
+        let day = Box::leak(Box::new(Day {
+            solve: panicking,
+            ..*days::find(2024, 1).unwrap()
+        }));
+        let response = solve(day, 1, &Config::default(), &request);
+        assert_eq!(response.status, 500);
+        assert_eq!(
+            serde_json::from_str::<Failure>(&response.body)
+                .unwrap()
+                .error,
+            "the solver panicked"
+        );
+    }
+
+    #[test]
+    fn test_malformed_circuit() {
+        let base_url = start(Config::default());
+        let circuit = "x00: 1\n\nx00 AND a -> b\nb OR x00 -> a\nb XOR x00 -> z00\n";
+        let url = format!("{base_url}/solve/24/1");
+        let (status, body) = reply(ureq::post(&url).send_string(circuit));
+        assert_eq!(status, 422, "{body}");
+        let (status, _) = reply(ureq::get(&format!("{base_url}/days")).call());
+        assert_eq!(status, 200);
+    }
+
+    #[test]
+    fn test_list_days() {
+        let base_url = start(Config::parse("[day18]\nsize = 7").unwrap());
+        let (status, body) = reply(ureq::get(&format!("{base_url}/days")).call());
+        assert_eq!(status, 200);
+        let days = serde_json::from_str::<Vec<DayInfo>>(&body).unwrap();
+        assert_eq!(days.len(), 25);
+        assert_eq!(days[0].title, "Historian Hysteria");
+        assert!(days[0].params.is_empty());
+        assert_eq!(
+            days[17].params[0],
+            ParamInfo {
+                name: "size".to_string(),
+                default: 71,
+                value: 7,
+                description: days::find(2024, 18).unwrap().params[0].help.to_string(),
+            }
+        );
+    }
+}
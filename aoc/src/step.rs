@@ -0,0 +1,436 @@
+use std::{
+    io::{self, BufRead, Write},
+    str::FromStr,
+    sync::mpsc::{self, Receiver, SyncSender},
+    thread::{self, JoinHandle},
+};
+
+use common::{
+    observe::{Frame, Observer, View},
+    Answer, Params,
+};
+
+use crate::{
+    cli::RunArgs,
+    days::Day,
+    run::{prepare, simulate},
+};
+
+const ENTER_SCREEN: &str = "\x1b[?1049h";
+const LEAVE_SCREEN: &str = "\x1b[?1049l";
+const CLEAR: &str = "\x1b[H\x1b[2J";
+
+const HELP: &str = "Commands:
+    <enter>, n [<n>]        step forward, n ticks at once
+    b [<n>]                 step back
+    g <tick>                go to a tick
+    r                       run to the end
+    r caption <text>        run until the caption contains the text
+    r grid <text>           run until the grid contains the text
+    r cell <x>,<y> <char>   run until the cell at column x of row y is the char
+    h                       show this help
+    q                       quit";
+
+/// Where `r` stops: the first later tick it holds for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Breakpoint {
+    End,
+    Caption(String),
+    Grid(String),
+    Cell { x: usize, y: usize, cell: char },
+}
+
+impl Breakpoint {
+    pub fn matches(&self, frame: &Frame) -> bool {
+        match self {
+            Breakpoint::End => false,
+            Breakpoint::Caption(text) => frame
+                .caption
+                .as_ref()
+                .is_some_and(|caption| caption.contains(text.as_str())),
+            Breakpoint::Grid(text) => frame.text.contains(text.as_str()),
+            Breakpoint::Cell { x, y, cell } => {
+                frame
+                    .text
+                    .lines()
+                    .nth(*y)
+                    .and_then(|line| line.chars().nth(*x))
+                    == Some(*cell)
+            }
+        }
+    }
+}
+
+impl FromStr for Breakpoint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Ok(Breakpoint::End);
+        }
+        let (kind, rest) = s.split_once(' ').unwrap_or((s, ""));
+        let rest = rest.trim();
+        match kind {
+            "caption" if !rest.is_empty() => Ok(Breakpoint::Caption(rest.to_string())),
+            "grid" if !rest.is_empty() => Ok(Breakpoint::Grid(rest.to_string())),
+            "cell" => {
+                let invalid = || format!("invalid breakpoint `{s}`, expected `cell <x>,<y> <char>`");
+                let (position, cell) = rest.split_once(' ').ok_or_else(invalid)?;
+                let (x, y) = position.split_once(',').ok_or_else(invalid)?;
+                let mut cell = cell.trim().chars();
+                match (x.parse(), y.parse(), cell.next(), cell.next()) {
+                    (Ok(x), Ok(y), Some(cell), None) => Ok(Breakpoint::Cell { x, y, cell }),
+                    _ => Err(invalid()),
+                }
+            }
+            _ => Err(format!(
+                "invalid breakpoint `{s}`, expected caption <text>, grid <text> or cell <x>,<y> <char>"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step {
+    Forward(usize),
+    Back(usize),
+    GoTo(usize),
+    Run(Breakpoint),
+    Help,
+    Quit,
+}
+
+impl FromStr for Step {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (command, rest) = s.split_once(' ').unwrap_or((s, ""));
+        let count = |rest: &str| match rest.trim() {
+            "" => Ok(1),
+            n => n
+                .parse::<usize>()
+                .map_err(|_| format!("invalid number of ticks `{n}`")),
+        };
+        match command {
+            "" | "n" => Ok(Step::Forward(count(rest)?)),
+            "b" => Ok(Step::Back(count(rest)?)),
+            "g" => rest
+                .trim()
+                .parse()
+                .map(Step::GoTo)
+                .map_err(|_| format!("invalid tick `{}`", rest.trim())),
+            "r" => Ok(Step::Run(rest.parse()?)),
+            "h" | "?" => Ok(Step::Help),
+            "q" => Ok(Step::Quit),
+            _ => Err(format!("unknown command `{command}`, `h` lists them")),
+        }
+    }
+}
+
+/// Moves through the ticks of a simulation, pulling them from `frames` only when it
+/// goes past the last one seen and keeping them all to go back.
+pub struct Stepper<I: Iterator<Item = Frame>> {
+    frames: I,
+    snapshots: Vec<Frame>,
+    current: usize,
+    finished: bool,
+}
+
+impl<I: Iterator<Item = Frame>> Stepper<I> {
+    /// A stepper at the first tick, or `frames` back if there is none.
+    pub fn new(mut frames: I) -> Result<Self, I> {
+        match frames.next() {
+            Some(first) => Ok(Self {
+                frames,
+                snapshots: vec![first],
+                current: 0,
+                finished: false,
+            }),
+            None => Err(frames),
+        }
+    }
+
+    /// Whether tick `index` exists, recording the ticks up to it.
+    fn reach(&mut self, index: usize) -> bool {
+        while self.snapshots.len() <= index && !self.finished {
+            match self.frames.next() {
+                Some(frame) => self.snapshots.push(frame),
+                None => self.finished = true,
+            }
+        }
+        index < self.snapshots.len()
+    }
+
+    pub fn current(&self) -> (usize, &Frame) {
+        (self.current, &self.snapshots[self.current])
+    }
+
+    /// The number of ticks, once the simulation has run to its end.
+    pub fn total(&self) -> Option<usize> {
+        self.finished.then_some(self.snapshots.len())
+    }
+
+    pub fn frames(&self) -> &I {
+        &self.frames
+    }
+
+    /// Goes to tick `index`, or to the last one if the simulation ends before.
+    pub fn go_to(&mut self, index: usize) -> bool {
+        let reached = self.reach(index);
+        self.current = index.min(self.snapshots.len() - 1);
+        reached
+    }
+
+    pub fn forward(&mut self, ticks: usize) -> bool {
+        self.go_to(self.current + ticks)
+    }
+
+    pub fn back(&mut self, ticks: usize) -> bool {
+        let moved = ticks <= self.current;
+        self.current = self.current.saturating_sub(ticks);
+        moved
+    }
+
+    /// Goes to the next tick `breakpoint` holds for, or to the last one if none does.
+    pub fn run_until(&mut self, breakpoint: &Breakpoint) -> bool {
+        while self.forward(1) {
+            if breakpoint.matches(&self.snapshots[self.current]) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Hands every tick to the stepper, waiting for it to ask for the next one.
+struct Relay {
+    ticks: SyncSender<Frame>,
+    closed: bool,
+}
+
+impl Observer for Relay {
+    fn observe(&mut self, state: &dyn View) {
+        if !self.closed && self.ticks.send(Frame::of(state)).is_err() {
+            self.closed = true;
+        }
+    }
+}
+
+/// The ticks of a simulation running on its own thread, one tick ahead of the reader.
+pub struct Simulation {
+    ticks: Receiver<Frame>,
+    handle: Option<JoinHandle<Result<Answer, String>>>,
+    outcome: Option<Result<Answer, String>>,
+}
+
+impl Simulation {
+    pub fn start(day: &'static Day, input: String, params: Params, part: u8) -> Self {
+        let (sender, ticks) = mpsc::sync_channel(0);
+        let handle = thread::spawn(move || {
+            let mut relay = Relay {
+                ticks: sender,
+                closed: false,
+            };
+            simulate(day, &input, &params, part, &mut relay)
+        });
+        Self {
+            ticks,
+            handle: Some(handle),
+            outcome: None,
+        }
+    }
+
+    /// The answer of the simulation, once it has ended.
+    pub fn outcome(&self) -> Option<&Result<Answer, String>> {
+        self.outcome.as_ref()
+    }
+}
+
+impl Iterator for Simulation {
+    type Item = Frame;
+
+    fn next(&mut self) -> Option<Frame> {
+        if let Ok(frame) = self.ticks.recv() {
+            return Some(frame);
+        }
+        if let Some(handle) = self.handle.take() {
+            self.outcome = Some(
+                handle
+                    .join()
+                    .unwrap_or_else(|_| Err("the simulation panicked".to_string())),
+            );
+        }
+        None
+    }
+}
+
+fn draw<W: Write>(
+    out: &mut W,
+    title: &str,
+    stepper: &Stepper<Simulation>,
+    message: &str,
+) -> io::Result<()> {
+    let (index, frame) = stepper.current();
+    let ticks = match stepper.total() {
+        Some(total) => format!("tick {index} of {}", total - 1),
+        None => format!("tick {index}"),
+    };
+    let result = match stepper.frames().outcome() {
+        Some(Ok(answer)) => format!(", answer {answer}"),
+        Some(Err(e)) => format!(", {e}"),
+        None => String::new(),
+    };
+    write!(out, "{CLEAR}{title}: {ticks}{result}\r\n")?;
+    write!(out, "{}\r\n", frame.caption.as_deref().unwrap_or(""))?;
+    for line in frame.text.lines() {
+        write!(out, "{line}\r\n")?;
+    }
+    write!(out, "\r\n{message}\r\n> ")?;
+    out.flush()
+}
+
+/// Reads a command a line from `input` and redraws the current tick to `out` after each.
+pub fn session<R: BufRead, W: Write>(
+    title: &str,
+    mut stepper: Stepper<Simulation>,
+    input: R,
+    out: &mut W,
+) -> io::Result<()> {
+    draw(out, title, &stepper, "h for help")?;
+    for line in input.lines() {
+        let message = match line?.parse::<Step>() {
+            Ok(Step::Quit) => break,
+            Ok(Step::Help) => HELP.replace('\n', "\r\n"),
+            Ok(Step::Forward(ticks)) => match stepper.forward(ticks) {
+                true => String::new(),
+                false => "the simulation has ended".to_string(),
+            },
+            Ok(Step::Back(ticks)) => match stepper.back(ticks) {
+                true => String::new(),
+                false => "this is the first tick".to_string(),
+            },
+            Ok(Step::GoTo(index)) => match stepper.go_to(index) {
+                true => String::new(),
+                false => format!("there is no tick {index}"),
+            },
+            Ok(Step::Run(breakpoint)) => match stepper.run_until(&breakpoint) {
+                true => format!("stopped at tick {}", stepper.current().0),
+                false if breakpoint == Breakpoint::End => String::new(),
+                false => "no later tick matches".to_string(),
+            },
+            Err(e) => e,
+        };
+        draw(out, title, &stepper, &message)?;
+    }
+    Ok(())
+}
+
+/// Steps through the simulation of a day in the terminal, with commands read from stdin.
+pub fn step(day: &'static Day, args: &RunArgs) -> Result<(), String> {
+    let (input, params) = prepare(day, args)?;
+    let part = args.part.unwrap_or(1);
+    let stepper =
+        Stepper::new(Simulation::start(day, input, params, part)).map_err(|simulation| {
+            match simulation.outcome() {
+                Some(Err(e)) => e.clone(),
+                _ => format!("part {part} of day {} has no ticks", day.number),
+            }
+        })?;
+    let title = format!("Day {} of {}, part {part}", day.number, day.year);
+    let mut out = io::stdout().lock();
+    let result = write!(out, "{ENTER_SCREEN}")
+        .and_then(|()| session(&title, stepper, io::stdin().lock(), &mut out));
+    let _ = write!(out, "{LEAVE_SCREEN}");
+    result.map_err(|e| format!("cannot step through day {}: {e}", day.number))
+}
+
+#[cfg(test)]
+mod step_tests {
+    use super::*;
+    use crate::days;
+
+    fn frame(caption: Option<&str>, text: &str) -> Frame {
+        Frame {
+            caption: caption.map(str::to_string),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_steps() {
+        assert_eq!("".parse(), Ok(Step::Forward(1)));
+        assert_eq!("n 10".parse(), Ok(Step::Forward(10)));
+        assert_eq!("b".parse(), Ok(Step::Back(1)));
+        assert_eq!("g 0".parse(), Ok(Step::GoTo(0)));
+        assert_eq!("r".parse(), Ok(Step::Run(Breakpoint::End)));
+        assert_eq!(
+            "r caption move <".parse(),
+            Ok(Step::Run(Breakpoint::Caption("move <".to_string())))
+        );
+        assert_eq!(
+            "r cell 3,4 O".parse(),
+            Ok(Step::Run(Breakpoint::Cell {
+                x: 3,
+                y: 4,
+                cell: 'O'
+            }))
+        );
+        assert!("n -1".parse::<Step>().is_err());
+        assert!("g".parse::<Step>().is_err());
+        assert!("r cell 3 O".parse::<Step>().is_err());
+        assert!("r cell 3,4 OO".parse::<Step>().is_err());
+        assert!("r caption".parse::<Step>().is_err());
+        assert!("jump".parse::<Step>().is_err());
+    }
+
+    #[test]
+    fn test_stepper() {
+        let frames = (0..5).map(|i| frame(Some(&format!("add {i}")), &"#".repeat(i)));
+        let mut stepper = Stepper::new(frames).ok().unwrap();
+        assert_eq!(stepper.current().0, 0);
+        assert!(stepper.forward(2));
+        assert_eq!(stepper.current().1.text, "##");
+        assert_eq!(stepper.total(), None);
+        assert!(stepper.back(1));
+        assert!(!stepper.back(3));
+        assert_eq!(stepper.current().0, 0);
+        assert!(stepper.run_until(&Breakpoint::Grid("###".to_string())));
+        assert_eq!(stepper.current().0, 3);
+        assert!(!stepper.run_until(&Breakpoint::Caption("add 9".to_string())));
+        assert_eq!(stepper.current().0, 4);
+        assert_eq!(stepper.total(), Some(5));
+        assert!(!stepper.go_to(7));
+        assert!(stepper.go_to(1));
+        assert!(stepper.run_until(&Breakpoint::Cell {
+            x: 1,
+            y: 0,
+            cell: '#'
+        }));
+        assert_eq!(stepper.current().0, 2);
+        assert!(Stepper::new(std::iter::empty()).is_err());
+    }
+
+    #[test]
+    fn test_session() {
+        let day = days::find(2024, 15).unwrap();
+        let input = "######\n#@.O.#\n######\n\n>>>".to_string();
+        let simulation = Simulation::start(day, input, Params::default(), 1);
+        let stepper = Stepper::new(simulation).ok().unwrap();
+        let commands = "\nn 2\nb 2\nr cell 4,1 O\nr\nfly\n";
+        let mut out = vec![];
+        session("Day 15", stepper, commands.as_bytes(), &mut out).unwrap();
+        let screens = String::from_utf8(out).unwrap();
+        let screens = screens.split(CLEAR).skip(1).collect::<Vec<_>>();
+        assert_eq!(screens.len(), 7);
+        assert!(screens[0].starts_with("Day 15: tick 0\r\n\r\n######\r\n#@.O.#\r\n"));
+        assert!(screens[1].starts_with("Day 15: tick 1\r\nmove >\r\n"));
+        assert!(screens[2].starts_with("Day 15: tick 3\r\n"));
+        assert!(screens[3].starts_with("Day 15: tick 1\r\nmove >\r\n######\r\n#.@O.#\r\n"));
+        assert!(screens[4].starts_with("Day 15: tick 2\r\nmove >\r\n######\r\n#..@O#\r\n"));
+        assert!(screens[4].contains("stopped at tick 2\r\n"));
+        assert!(screens[5].starts_with("Day 15: tick 3 of 3, answer 104\r\n"));
+        assert!(screens[6].contains("unknown command `fly`"));
+    }
+}
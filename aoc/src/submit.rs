@@ -0,0 +1,350 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use common::{workspace_root, Answer, Params};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cli::SubmitArgs,
+    client::Client,
+    days,
+    run::{self, load_input},
+};
+
+pub const RECORD_FILE: &str = "submissions.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    Wait { seconds: u64 },
+    AlreadySolved,
+}
+
+impl Outcome {
+    /// Whether the server judged the answer, so that sending it again is pointless.
+    pub fn is_judged(self) -> bool {
+        matches!(
+            self,
+            Outcome::Correct | Outcome::Incorrect | Outcome::TooHigh | Outcome::TooLow
+        )
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::TooHigh => write!(f, "incorrect, too high"),
+            Outcome::TooLow => write!(f, "incorrect, too low"),
+            Outcome::Wait { seconds } => write!(f, "submitted too recently, wait {seconds}s"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// The text of the `<article>` of a response page, without tags.
+fn article_text(page: &str) -> &str {
+    let start = page.find("<article").unwrap_or(0);
+    let end = page[start..]
+        .find("</article>")
+        .map_or(page.len(), |end| start + end);
+    &page[start..end]
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses durations like `34s` or `1m 4s`.
+fn parse_wait(text: &str) -> Option<u64> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    wait.split_whitespace().try_fold(0, |seconds, part| {
+        let (value, unit) = part.split_at(part.len().checked_sub(1)?);
+        let value: u64 = value.parse().ok()?;
+        match unit {
+            "s" => Some(seconds + value),
+            "m" => Some(seconds + value * 60),
+            _ => None,
+        }
+    })
+}
+
+pub fn parse_outcome(page: &str) -> Result<Outcome, String> {
+    let text = strip_tags(article_text(page));
+    let outcome = if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Incorrect
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::Wait {
+            seconds: parse_wait(&text).ok_or(format!("cannot read the wait time in `{text}`"))?,
+        }
+    } else if text.contains("Did you already complete it?") {
+        Outcome::AlreadySolved
+    } else {
+        return Err(format!("unrecognised response `{text}`"));
+    };
+    Ok(outcome)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Every answer the server has judged, so that none is ever sent twice.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+impl Record {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("cannot read `{}`: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = toml::to_string(self).map_err(|e| e.to_string())?;
+        fs::write(path, text).map_err(|e| format!("cannot write `{}`: {e}", path.display()))
+    }
+
+    pub fn add(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    /// The outcome `answer` is known to have without sending it: the recorded one for
+    /// the same answer, or one implied by a correct answer or a too high or too low bound.
+    pub fn known(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<Outcome> {
+        let value = answer.parse::<i128>().ok();
+        let mut known = None;
+        for submission in self
+            .submissions
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part)
+        {
+            if submission.answer == answer {
+                return Some(submission.outcome);
+            }
+            let bound = submission.answer.parse::<i128>().ok();
+            known = known.or(match (submission.outcome, value.zip(bound)) {
+                (Outcome::Correct, _) => Some(Outcome::Incorrect),
+                (Outcome::TooHigh, Some((value, bound))) if value >= bound => {
+                    Some(Outcome::TooHigh)
+                }
+                (Outcome::TooLow, Some((value, bound))) if value <= bound => Some(Outcome::TooLow),
+                _ => None,
+            });
+        }
+        known
+    }
+}
+
+/// Sends `answer` unless the record already tells its outcome, recording the verdict.
+/// Returns the outcome and whether it came from the server.
+pub fn submit(
+    record: &mut Record,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+    client: impl FnOnce() -> Result<Client, String>,
+) -> Result<(Outcome, bool), String> {
+    if let Some(outcome) = record.known(year, day, part, answer) {
+        return Ok((outcome, false));
+    }
+    let level = part.to_string();
+    let page = client()?
+        .post(
+            year,
+            day,
+            "/answer",
+            &[("level", &level), ("answer", answer)],
+        )
+        .map_err(|e| e.to_string())?;
+    let outcome = parse_outcome(&page)?;
+    if outcome.is_judged() {
+        record.add(Submission {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+        });
+    }
+    Ok((outcome, true))
+}
+
+fn record_path(record: Option<&Path>) -> Result<PathBuf, String> {
+    match record {
+        Some(path) => Ok(path.to_path_buf()),
+        None => Ok(workspace_root()
+            .map_err(|e| e.to_string())?
+            .join(RECORD_FILE)),
+    }
+}
+
+pub fn execute(args: &SubmitArgs) -> Result<(), String> {
+    let day = days::find(args.year, args.day).ok_or(format!(
+        "day {} of {} is not solved yet",
+        args.day, args.year
+    ))?;
+    let input = load_input(day, args.input.as_deref(), args.input_dir.as_deref())?;
+    let answer = match run::solve(day, &input, &Params::default(), &[args.part])?.answer(args.part)
+    {
+        Answer::Unsolved => return Err(format!("part {} is not solved yet", args.part)),
+        answer => answer.to_string(),
+    };
+    let path = record_path(args.record.as_deref())?;
+    let mut record = Record::load(&path)?;
+    let (outcome, sent) = submit(&mut record, args.year, args.day, args.part, &answer, || {
+        Client::from_env(args.base_url.as_deref()).map_err(|e| e.to_string())
+    })?;
+    if sent {
+        record.save(&path)?;
+        println!(
+            "day {} of {} part {}: {answer} is {outcome}",
+            args.day, args.year, args.part
+        );
+    } else {
+        println!(
+            "day {} of {} part {}: {answer} is known to be {outcome}, not sending it again",
+            args.day, args.year, args.part
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod submit_tests {
+    use super::*;
+    use crate::client::test_server::{serve, Reply};
+
+    const RIGHT: &str = "<main>\n<article><p>That's the right answer!  You are <em>one gold star</em> closer to finding the Chief Historian.</p></article>\n</main>";
+    const TOO_HIGH: &str = "<main>\n<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>\n</main>";
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low.  Please wait one minute before trying again.</p></article>";
+    const WRONG: &str = "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article>";
+    const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 4s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+    const SOLVED: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+
+    fn submission(answer: &str, outcome: Outcome) -> Submission {
+        Submission {
+            year: 2024,
+            day: 1,
+            part: 1,
+            answer: answer.to_string(),
+            outcome,
+        }
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        assert_eq!(parse_outcome(RIGHT), Ok(Outcome::Correct));
+        assert_eq!(parse_outcome(TOO_HIGH), Ok(Outcome::TooHigh));
+        assert_eq!(parse_outcome(TOO_LOW), Ok(Outcome::TooLow));
+        assert_eq!(parse_outcome(WRONG), Ok(Outcome::Incorrect));
+        assert_eq!(parse_outcome(WAIT), Ok(Outcome::Wait { seconds: 64 }));
+        assert_eq!(parse_outcome(SOLVED), Ok(Outcome::AlreadySolved));
+        assert!(parse_outcome("<article><p>Something else</p></article>").is_err());
+    }
+
+    #[test]
+    fn test_known_outcomes() {
+        let mut record = Record::default();
+        record.add(submission("100", Outcome::TooHigh));
+        record.add(submission("10", Outcome::TooLow));
+        record.add(submission("abc", Outcome::Incorrect));
+        assert_eq!(record.known(2024, 1, 1, "100"), Some(Outcome::TooHigh));
+        assert_eq!(record.known(2024, 1, 1, "150"), Some(Outcome::TooHigh));
+        assert_eq!(record.known(2024, 1, 1, "7"), Some(Outcome::TooLow));
+        assert_eq!(record.known(2024, 1, 1, "abc"), Some(Outcome::Incorrect));
+        assert_eq!(record.known(2024, 1, 1, "50"), None);
+        assert_eq!(record.known(2024, 1, 2, "100"), None);
+        assert_eq!(record.known(2023, 1, 1, "100"), None);
+        record.add(submission("50", Outcome::Correct));
+        assert_eq!(record.known(2024, 1, 1, "50"), Some(Outcome::Correct));
+        assert_eq!(record.known(2024, 1, 1, "51"), Some(Outcome::Incorrect));
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-record-{}.toml", std::process::id()));
+        assert_eq!(Record::load(&path), Ok(Record::default()));
+        let mut record = Record::default();
+        record.add(submission("4,6,3", Outcome::TooLow));
+        record.save(&path).unwrap();
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .contains("outcome = \"too-low\""));
+        assert_eq!(Record::load(&path), Ok(record));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_submit_never_resends() {
+        let (base_url, server) = serve(vec![
+            Reply::new(200, WAIT),
+            Reply::new(200, TOO_HIGH),
+            Reply::new(200, RIGHT),
+        ]);
+        let client = || Ok(Client::new(&base_url, "secret"));
+        let mut record = Record::default();
+        let no_client = || Err("the server must not be contacted".to_string());
+        assert_eq!(
+            submit(&mut record, 2024, 1, 1, "200", client),
+            Ok((Outcome::Wait { seconds: 64 }, true))
+        );
+        assert_eq!(
+            submit(&mut record, 2024, 1, 1, "200", client),
+            Ok((Outcome::TooHigh, true))
+        );
+        assert_eq!(
+            submit(&mut record, 2024, 1, 1, "200", no_client),
+            Ok((Outcome::TooHigh, false))
+        );
+        assert_eq!(
+            submit(&mut record, 2024, 1, 1, "300", no_client),
+            Ok((Outcome::TooHigh, false))
+        );
+        assert_eq!(
+            submit(&mut record, 2024, 1, 1, "150", client),
+            Ok((Outcome::Correct, true))
+        );
+        assert_eq!(record.submissions.len(), 2);
+        let requests = server.join().unwrap();
+        assert!(requests[2].ends_with("level=1&answer=150"));
+    }
+}
@@ -0,0 +1,31 @@
+pub fn format_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) -> String {
+    let header = header.map(String::from);
+    let widths = (0..N)
+        .map(|col| {
+            rows.iter()
+                .chain(std::iter::once(&header))
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+    let format_row = |row: &[String; N]| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+    let separator = widths
+        .iter()
+        .map(|width| "-".repeat(*width))
+        .collect::<Vec<_>>()
+        .join("-+-");
+    std::iter::once(format_row(&header))
+        .chain(std::iter::once(separator))
+        .chain(rows.iter().map(format_row))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
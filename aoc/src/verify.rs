@@ -0,0 +1,286 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use common::{workspace_root, Params};
+use toml::{Table, Value};
+
+use crate::{
+    cli::VerifyArgs,
+    run::{self, DayResult},
+    table::format_table,
+};
+
+pub const ANSWERS_DIR: &str = "answers";
+
+/// The answers of `year`, relative to the workspace root.
+pub fn answers_file(year: u16) -> PathBuf {
+    Path::new(ANSWERS_DIR).join(format!("{year}.toml"))
+}
+
+#[derive(Debug, Default)]
+pub struct Manifest {
+    answers: HashMap<(u8, u8), String>,
+}
+
+pub(crate) fn parse_day_key(key: &str) -> Result<u8, String> {
+    key.strip_prefix("day")
+        .and_then(|day| day.parse().ok())
+        .ok_or(format!("invalid section `[{key}]`, expected `[dayNN]`"))
+}
+
+fn parse_part_key(day: &str, key: &str) -> Result<u8, String> {
+    match key {
+        "part1" => Ok(1),
+        "part2" => Ok(2),
+        _ => Err(format!("invalid key `{key}` in `[{day}]`")),
+    }
+}
+
+impl Manifest {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table = text.parse::<Table>().map_err(|e| e.to_string())?;
+        let mut answers = HashMap::new();
+        for (day_key, parts) in &table {
+            let day = parse_day_key(day_key)?;
+            let parts = parts
+                .as_table()
+                .ok_or(format!("`{day_key}` must be a table"))?;
+            for (part_key, answer) in parts {
+                let part = parse_part_key(day_key, part_key)?;
+                let answer = match answer {
+                    Value::Integer(n) => n.to_string(),
+                    Value::String(s) => s.clone(),
+                    _ => {
+                        return Err(format!(
+                            "`{day_key}.{part_key}` must be an integer or a string"
+                        ))
+                    }
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(Self { answers })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("cannot read answers `{}`: {e}", path.display()))?;
+        Self::parse(&text).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn expected(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Correct,
+    Wrong { expected: String, actual: String },
+    Unrecorded { actual: String },
+    Missing { expected: String },
+    Unsolved,
+}
+
+impl Status {
+    fn describe(&self) -> String {
+        match self {
+            Status::Correct => "ok".to_string(),
+            Status::Wrong { expected, actual } => {
+                format!("FAIL: got {actual}, expected {expected}")
+            }
+            Status::Unrecorded { actual } => format!("unrecorded: {actual}"),
+            Status::Missing { expected } => format!("FAIL: no answer, expected {expected}"),
+            Status::Unsolved => "-".to_string(),
+        }
+    }
+}
+
+pub fn check(result: &DayResult, part: u8, manifest: &Manifest) -> Status {
+    let actual = result.answer(part).to_string();
+    let solved = result.run.parts.iter().any(|p| p.part == part);
+    match manifest.expected(result.day.number, part) {
+        Some(expected) if !solved => Status::Missing {
+            expected: expected.to_string(),
+        },
+        _ if !solved => Status::Unsolved,
+        Some(expected) if expected == actual => Status::Correct,
+        Some(expected) => Status::Wrong {
+            expected: expected.to_string(),
+            actual,
+        },
+        None => Status::Unrecorded { actual },
+    }
+}
+
+pub fn execute(args: &VerifyArgs) -> Result<(), String> {
+    let path = match &args.answers {
+        Some(path) => path.clone(),
+        None => workspace_root()
+            .map_err(|e| e.to_string())?
+            .join(answers_file(args.year)),
+    };
+    let manifest = Manifest::load(&path)?;
+    let mut failures = 0;
+    let mut broken = 0;
+    let mut rows = vec![];
+    for day in run::select(args.year, &args.selection)? {
+        let result = run::load_input(day, None, args.input_dir.as_deref())
+            .and_then(|input| run::solve(day, &input, &Params::default(), &[1, 2]));
+        let [part1, part2] = match result {
+            Ok(result) => {
+                let statuses = [1, 2].map(|part| check(&result, part, &manifest));
+                failures += statuses
+                    .iter()
+                    .filter(|status| {
+                        matches!(status, Status::Wrong { .. } | Status::Missing { .. })
+                    })
+                    .count();
+                statuses.map(|status| status.describe())
+            }
+            Err(e) => {
+                broken += 1;
+                [format!("FAIL: {e}"), "FAIL".to_string()]
+            }
+        };
+        rows.push([day.number.to_string(), day.title.to_string(), part1, part2]);
+    }
+    println!(
+        "{}",
+        format_table(["Day", "Title", "Part 1", "Part 2"], &rows)
+    );
+    let mut errors = vec![];
+    if failures > 0 {
+        errors.push(format!(
+            "{failures} answer(s) do not match {}",
+            path.display()
+        ));
+    }
+    if broken > 0 {
+        errors.push(format!("{broken} day(s) cannot be solved"));
+    }
+    match errors.is_empty() {
+        true => Ok(()),
+        false => Err(errors.join(", ")),
+    }
+}
+
+#[cfg(test)]
+mod verify_tests {
+    use std::env;
+
+    use super::*;
+    use crate::{cli::Selection, days};
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest = Manifest::parse(
+            r#"
+[day01]
+part1 = 11
+part2 = 31
+
+[day17]
+part1 = "4,6,3,5,6,3,5,2,1,0"
+"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.expected(1, 1), Some("11"));
+        assert_eq!(manifest.expected(1, 2), Some("31"));
+        assert_eq!(manifest.expected(17, 1), Some("4,6,3,5,6,3,5,2,1,0"));
+        assert_eq!(manifest.expected(17, 2), None);
+    }
+
+    #[test]
+    fn test_parse_manifest_errors() {
+        assert!(Manifest::parse("[first]\npart1 = 1").is_err());
+        assert!(Manifest::parse("[day01]\npart3 = 1").is_err());
+        assert!(Manifest::parse("[day01]\npart1 = 1.5").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let manifest = Manifest::parse("[day01]\npart1 = 11\npart2 = 30").unwrap();
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+        let result = run::solve(
+            days::find(2024, 1).unwrap(),
+            input,
+            &Params::default(),
+            &[1, 2],
+        )
+        .unwrap();
+        assert_eq!(check(&result, 1, &manifest), Status::Correct);
+        assert_eq!(
+            check(&result, 2, &manifest),
+            Status::Wrong {
+                expected: "30".to_string(),
+                actual: "31".to_string()
+            }
+        );
+        assert_eq!(
+            check(&result, 1, &Manifest::default()),
+            Status::Unrecorded {
+                actual: "11".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_check_missing() {
+        let manifest = Manifest::parse("[day01]\npart1 = 11\npart2 = 31").unwrap();
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+        let day = days::find(2024, 1).unwrap();
+        let result = run::solve(day, input, &Params::default(), &[1]).unwrap();
+        assert_eq!(
+            check(&result, 2, &manifest),
+            Status::Missing {
+                expected: "31".to_string()
+            }
+        );
+        assert_eq!(check(&result, 2, &Manifest::default()), Status::Unsolved);
+    }
+
+    #[test]
+    fn test_execute_goes_on_after_errors() {
+        let dir = env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2024")).unwrap();
+        let lists = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        fs::write(dir.join("2024/day01.txt"), lists).unwrap();
+        fs::write(dir.join("2024/day02.txt"), "1 x\n").unwrap();
+        let answers = dir.join("answers.toml");
+        fs::write(&answers, "[day01]\npart1 = 11\npart2 = 30\n").unwrap();
+        let args = VerifyArgs {
+            year: 2024,
+            selection: Selection::All,
+            answers: Some(answers),
+            input_dir: Some(dir.clone()),
+        };
+        let error = execute(&args).unwrap_err();
+        fs::remove_dir_all(dir).unwrap();
+        assert!(error.starts_with("1 answer(s) do not match"), "{error}");
+        // Day 2 does not parse and the other days have no input.
+        assert!(error.ends_with(", 24 day(s) cannot be solved"), "{error}");
+    }
+
+    #[test]
+    #[ignore = "runs every day against its real input"]
+    fn test_verify_all() {
+        let root = workspace_root().unwrap();
+        for day in days::DAYS {
+            let manifest = Manifest::load(&root.join(answers_file(day.year))).unwrap();
+            let input = run::load_input(day, None, Some(&root.join("inputs"))).unwrap();
+            let result = run::solve(day, &input, &Params::default(), &[1, 2]).unwrap();
+            for part in [1, 2] {
+                let status = check(&result, part, &manifest);
+                assert!(
+                    matches!(status, Status::Correct | Status::Unsolved),
+                    "day {} part {part}: {status:?}",
+                    day.number
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+/* Generated from capi/src/lib.rs by `AOC_WRITE_HEADER=1 cargo test -p aoc_capi`. */
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The results of aoc_solve. */
+#define AOC_OK               0 /* the part is solved */
+#define AOC_UNKNOWN_DAY      1 /* the day is not solved yet */
+#define AOC_UNKNOWN_PART     2 /* the part is neither 1 nor 2 */
+#define AOC_NULL_POINTER     3 /* a pointer is null */
+#define AOC_INVALID_UTF8     4 /* the input is not UTF-8 text */
+#define AOC_PARSE_ERROR      5 /* the input does not parse */
+#define AOC_UNSOLVED         6 /* the part of the day is not solved */
+#define AOC_BUFFER_TOO_SMALL 7 /* the answer does not fit in the output buffer */
+#define AOC_PANIC            8 /* the solver panicked */
+
+/*
+ * Solves `part` of `day` on the `input_len` bytes of UTF-8 text at `input`.
+ *
+ * On AOC_OK the answer is written to `out` as a NUL terminated string. Otherwise `out`
+ * gets a description of the error, cut to fit. Either way `*out_len`, the capacity of
+ * `out` on the way in, is set to the length of the whole text without its NUL. An
+ * answer that does not fit is not written and gives AOC_BUFFER_TOO_SMALL.
+ */
+int32_t aoc_solve(uint8_t day, uint8_t part, const char *input, size_t input_len,
+                  char *out, size_t *out_len);
+
+/* A static description of a status of aoc_solve, or NULL for an unknown one. */
+const char *aoc_status_message(int32_t status);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC_H */
@@ -0,0 +1,325 @@
+use std::{
+    ffi::{c_char, CStr},
+    panic::{self, AssertUnwindSafe},
+    ptr, slice,
+};
+
+use aoc::days::{self, Day};
+use common::{Answer, Params};
+
+/// The results of [`aoc_solve`], stable across versions.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok = 0,
+    UnknownDay = 1,
+    UnknownPart = 2,
+    NullPointer = 3,
+    InvalidUtf8 = 4,
+    ParseError = 5,
+    Unsolved = 6,
+    BufferTooSmall = 7,
+    Panic = 8,
+}
+
+impl Status {
+    pub const ALL: [Status; 9] = [
+        Status::Ok,
+        Status::UnknownDay,
+        Status::UnknownPart,
+        Status::NullPointer,
+        Status::InvalidUtf8,
+        Status::ParseError,
+        Status::Unsolved,
+        Status::BufferTooSmall,
+        Status::Panic,
+    ];
+
+    /// The name of the constant in the header.
+    pub fn name(self) -> &'static str {
+        match self {
+            Status::Ok => "AOC_OK",
+            Status::UnknownDay => "AOC_UNKNOWN_DAY",
+            Status::UnknownPart => "AOC_UNKNOWN_PART",
+            Status::NullPointer => "AOC_NULL_POINTER",
+            Status::InvalidUtf8 => "AOC_INVALID_UTF8",
+            Status::ParseError => "AOC_PARSE_ERROR",
+            Status::Unsolved => "AOC_UNSOLVED",
+            Status::BufferTooSmall => "AOC_BUFFER_TOO_SMALL",
+            Status::Panic => "AOC_PANIC",
+        }
+    }
+
+    pub fn message(self) -> &'static CStr {
+        match self {
+            Status::Ok => c"the part is solved",
+            Status::UnknownDay => c"the day is not solved yet",
+            Status::UnknownPart => c"the part is neither 1 nor 2",
+            Status::NullPointer => c"a pointer is null",
+            Status::InvalidUtf8 => c"the input is not UTF-8 text",
+            Status::ParseError => c"the input does not parse",
+            Status::Unsolved => c"the part of the day is not solved",
+            Status::BufferTooSmall => c"the answer does not fit in the output buffer",
+            Status::Panic => c"the solver panicked",
+        }
+    }
+}
+
+/// Solves `part` of `day` of the latest year on `input` with the default parameters.
+/// Returns the answer, or else the error and a description of it.
+pub fn solve(day: u8, part: u8, input: &[u8]) -> Result<String, (Status, String)> {
+    let year = days::latest_year();
+    let Some(day) = days::find(year, day) else {
+        return Err((
+            Status::UnknownDay,
+            format!("day {day} of {year} is not solved"),
+        ));
+    };
+    if !matches!(part, 1 | 2) {
+        return Err((Status::UnknownPart, format!("invalid part {part}")));
+    }
+    let input = std::str::from_utf8(input).map_err(|e| (Status::InvalidUtf8, e.to_string()))?;
+    solve_day(day, part, input)
+}
+
+/// Solves `part` of `day` on `input`, with a panic of the solver as an error.
+fn solve_day(day: &Day, part: u8, input: &str) -> Result<String, (Status, String)> {
+    let run = panic::catch_unwind(AssertUnwindSafe(|| {
+        (day.solve)(input, &Params::default(), &[part])
+    }))
+    .map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        (Status::Panic, format!("the solver panicked: {message}"))
+    })?
+    .map_err(|e| (Status::ParseError, e.to_string()))?;
+    match run.parts.first().map(|result| &result.answer) {
+        Some(answer) if *answer != Answer::Unsolved => Ok(answer.to_string()),
+        _ => Err((
+            Status::Unsolved,
+            format!(
+                "part {part} of day {} of {} is not solved",
+                day.number, day.year
+            ),
+        )),
+    }
+}
+
+/// Copies `text` and a NUL into the `capacity` bytes at `out`, cut to fit unless `whole`.
+/// Returns whether it fits.
+unsafe fn write_text(text: &str, out: *mut u8, capacity: usize, whole: bool) -> bool {
+    let fits = text.len() < capacity;
+    if capacity == 0 || (whole && !fits) {
+        return fits;
+    }
+    let length = text.len().min(capacity - 1);
+    ptr::copy_nonoverlapping(text.as_ptr(), out, length);
+    *out.add(length) = 0;
+    fits
+}
+
+/// Solves `part` of `day` on the `input_len` bytes of UTF-8 text at `input`.
+///
+/// On `AOC_OK` the answer is written to `out` as a NUL terminated string. Otherwise
+/// `out` gets a description of the error, cut to fit. Either way `*out_len`, the
+/// capacity of `out` on the way in, is set to the length of the whole text without
+/// its NUL. An answer that does not fit is not written and gives
+/// `AOC_BUFFER_TOO_SMALL`.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes, or be null if `input_len` is 0,
+/// and `out` to `*out_len` writable bytes, or be null if `*out_len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const c_char,
+    input_len: usize,
+    out: *mut c_char,
+    out_len: *mut usize,
+) -> i32 {
+    if out_len.is_null() || (out.is_null() && *out_len > 0) || (input.is_null() && input_len > 0) {
+        return Status::NullPointer as i32;
+    }
+    let input = match input_len {
+        0 => &[],
+        _ => slice::from_raw_parts(input.cast::<u8>(), input_len),
+    };
+    let capacity = *out_len;
+    let (status, text) = match solve(day, part, input) {
+        Ok(answer) => match write_text(&answer, out.cast(), capacity, true) {
+            true => (Status::Ok, answer),
+            false => (Status::BufferTooSmall, answer),
+        },
+        Err((status, message)) => {
+            write_text(&message, out.cast(), capacity, false);
+            (status, message)
+        }
+    };
+    *out_len = text.len();
+    status as i32
+}
+
+/// A static description of a status of [`aoc_solve`], or null for an unknown one.
+#[no_mangle]
+pub extern "C" fn aoc_status_message(status: i32) -> *const c_char {
+    Status::ALL
+        .iter()
+        .find(|&&s| s as i32 == status)
+        .map_or(ptr::null(), |s| s.message().as_ptr())
+}
+
+/// The C header of the library, which `include/aoc.h` must match.
+pub fn header() -> String {
+    let statuses = Status::ALL
+        .iter()
+        .map(|&s| {
+            format!(
+                "#define {:<20} {} /* {} */\n",
+                s.name(),
+                s as i32,
+                s.message().to_str().unwrap()
+            )
+        })
+        .collect::<String>();
+    format!(
+        "/* Generated from capi/src/lib.rs by `AOC_WRITE_HEADER=1 cargo test -p aoc_capi`. */
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern \"C\" {{
+#endif
+
+/* The results of aoc_solve. */
+{statuses}
+/*
+ * Solves `part` of `day` on the `input_len` bytes of UTF-8 text at `input`.
+ *
+ * On AOC_OK the answer is written to `out` as a NUL terminated string. Otherwise `out`
+ * gets a description of the error, cut to fit. Either way `*out_len`, the capacity of
+ * `out` on the way in, is set to the length of the whole text without its NUL. An
+ * answer that does not fit is not written and gives AOC_BUFFER_TOO_SMALL.
+ */
+int32_t aoc_solve(uint8_t day, uint8_t part, const char *input, size_t input_len,
+                  char *out, size_t *out_len);
+
+/* A static description of a status of aoc_solve, or NULL for an unknown one. */
+const char *aoc_status_message(int32_t status);
+
+#ifdef __cplusplus
+}}
+#endif
+
+#endif /* AOC_H */
+"
+    )
+}
+
+#[cfg(test)]
+mod capi_tests {
+    use std::{env, fs, path::Path};
+
+    use aoc::run::Run;
+    use common::ParseError;
+
+    use super::*;
+
+    const LISTS: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    fn call(day: u8, part: u8, input: &str, capacity: usize) -> (i32, usize, String) {
+        let mut out = vec![0xff_u8; capacity];
+        let mut out_len = capacity;
+        let status = unsafe {
+            aoc_solve(
+                day,
+                part,
+                input.as_ptr().cast(),
+                input.len(),
+                out.as_mut_ptr().cast(),
+                &mut out_len,
+            )
+        };
+        let text = out
+            .iter()
+            .position(|&b| b == 0)
+            .map(|end| String::from_utf8(out[..end].to_vec()).unwrap())
+            .unwrap_or_default();
+        (status, out_len, text)
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(call(1, 1, LISTS, 16), (0, 2, "11".to_string()));
+        assert_eq!(call(1, 2, LISTS, 3), (0, 2, "31".to_string()));
+        assert_eq!(call(1, 2, LISTS, 2), (7, 2, String::new()));
+        assert_eq!(call(26, 1, LISTS, 64).0, 1);
+        assert_eq!(call(1, 3, LISTS, 64).0, 2);
+        assert_eq!(call(25, 2, "", 64).0, 6);
+        let (status, length, text) = call(1, 1, "1 x", 64);
+        assert_eq!(status, 5);
+        assert!(text.starts_with("line 1"));
+        assert_eq!(length, text.len());
+        let (status, length, text) = call(1, 1, "1 x", 8);
+        assert_eq!((status, text.len()), (5, 7));
+        assert!(length > 7);
+    }
+
+    fn panicking(_: &str, _: &Params, _: &[u8]) -> Result<Run, ParseError> {
+        panic!("a solver bug")
+    }
+
+    #[test]
+    fn test_panics_are_caught() {
+        let day = Day {
+            solve: panicking,
+            ..*days::find(2024, 1).unwrap()
+        };
+        assert_eq!(
+            solve_day(&day, 1, LISTS),
+            Err((
+                Status::Panic,
+                "the solver panicked: a solver bug".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_null_pointers() {
+        let mut out_len = 0;
+        let status = unsafe { aoc_solve(1, 1, ptr::null(), 0, ptr::null_mut(), &mut out_len) };
+        assert_eq!((status, out_len), (Status::BufferTooSmall as i32, 1));
+        let status = unsafe { aoc_solve(1, 1, ptr::null(), 4, ptr::null_mut(), &mut out_len) };
+        assert_eq!(status, Status::NullPointer as i32);
+        let status = unsafe { aoc_solve(1, 1, ptr::null(), 0, ptr::null_mut(), ptr::null_mut()) };
+        assert_eq!(status, Status::NullPointer as i32);
+    }
+
+    #[test]
+    fn test_status_message() {
+        let message = unsafe { CStr::from_ptr(aoc_status_message(8)) };
+        assert_eq!(message, c"the solver panicked");
+        assert!(aoc_status_message(99).is_null());
+    }
+
+    #[test]
+    fn test_header_is_current() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("include/aoc.h");
+        if env::var_os("AOC_WRITE_HEADER").is_some() {
+            fs::write(&path, header()).unwrap();
+        }
+        assert_eq!(
+            fs::read_to_string(&path).unwrap_or_default(),
+            header(),
+            "{} is stale, regenerate it with AOC_WRITE_HEADER=1 cargo test -p aoc_capi",
+            path.display()
+        );
+    }
+}
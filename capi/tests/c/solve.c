@@ -0,0 +1,54 @@
+/* Solves a part of a day on the puzzle text read from stdin: solve <day> <part> */
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "aoc.h"
+
+static char *read_all(FILE *file, size_t *length) {
+    size_t capacity = 4096;
+    char *text = malloc(capacity);
+    *length = 0;
+    size_t read;
+    while (text && (read = fread(text + *length, 1, capacity - *length, file)) > 0) {
+        *length += read;
+        if (*length == capacity) {
+            capacity *= 2;
+            text = realloc(text, capacity);
+        }
+    }
+    return text;
+}
+
+int main(int argc, char **argv) {
+    if (argc != 3) {
+        fprintf(stderr, "usage: %s <day> <part>\n", argv[0]);
+        return 2;
+    }
+    size_t input_len;
+    char *input = read_all(stdin, &input_len);
+    if (!input) {
+        return 2;
+    }
+    /* Start small to go through the retry with the length aoc_solve asks for, which
+       also gets the whole description of an error. */
+    size_t capacity = 2;
+    char *out = malloc(capacity);
+    size_t out_len = capacity;
+    uint8_t day = (uint8_t)atoi(argv[1]);
+    uint8_t part = (uint8_t)atoi(argv[2]);
+    int32_t status = aoc_solve(day, part, input, input_len, out, &out_len);
+    if (out_len >= capacity) {
+        capacity = out_len + 1;
+        out = realloc(out, capacity);
+        out_len = capacity;
+        status = aoc_solve(day, part, input, input_len, out, &out_len);
+    }
+    if (status == AOC_OK) {
+        printf("%s\n", out);
+    } else {
+        fprintf(stderr, "error %d (%s): %s\n", status, aoc_status_message(status), out);
+    }
+    free(out);
+    free(input);
+    return status == AOC_OK ? 0 : 1;
+}
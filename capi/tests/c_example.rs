@@ -0,0 +1,77 @@
+use std::{
+    env,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Output, Stdio},
+};
+
+/// The directory of the test binary, where cargo also puts the library it builds for it.
+fn library_dir() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    exe.parent().unwrap().to_path_buf()
+}
+
+/// Compiles `tests/c/solve.c` against the header and the library into `dir`.
+fn compile(dir: &Path) -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let binary = dir.join(format!("solve-c-{}", std::process::id()));
+    let status = Command::new(env::var("CC").unwrap_or("cc".to_string()))
+        .arg(manifest_dir.join("tests/c/solve.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(dir)
+        .arg(format!("-Wl,-rpath,{}", dir.display()))
+        .arg("-laoc_capi")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-o")
+        .arg(&binary)
+        .status()
+        .expect("a C compiler, given by CC or else cc");
+    assert!(status.success(), "cannot compile the C example");
+    binary
+}
+
+fn solve(binary: &Path, day: &str, part: &str, input: &str) -> Output {
+    let mut child = Command::new(binary)
+        .args([day, part])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_c_example() {
+    let dir = library_dir();
+    let binary = compile(&dir);
+    let lists = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    let output = solve(&binary, "1", "2", lists);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "31\n");
+
+    let output = solve(&binary, "1", "1", "1 x");
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.starts_with("error 5 (the input does not parse): line 1"));
+
+    let output = solve(&binary, "26", "1", lists);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.starts_with("error 1 (the day is not solved yet)"),
+        "{stderr}"
+    );
+
+    std::fs::remove_file(binary).unwrap();
+}
@@ -0,0 +1,987 @@
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    hash::Hash,
+    ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Rem, Shl, Shr, Sub},
+    str::FromStr,
+};
+
+use crate::{Answer, Param, Params};
+
+const DECIMAL_BASE: u32 = 1_000_000_000;
+const DECIMAL_DIGITS: usize = 9;
+
+/// The error of parsing a big integer, or of converting one that does not fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BigIntError {
+    Empty,
+    InvalidDigit,
+    OutOfRange,
+}
+
+impl Display for BigIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BigIntError::Empty => write!(f, "cannot parse an integer from empty text"),
+            BigIntError::InvalidDigit => write!(f, "invalid digit in an integer"),
+            BigIntError::OutOfRange => write!(f, "the integer does not fit in the type"),
+        }
+    }
+}
+
+impl Error for BigIntError {}
+
+/// An unsigned integer of any size.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// Base 2^32 digits, least significant first, without leading zeros.
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    fn normalized(mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        Self { limbs }
+    }
+
+    /// The number of bits it takes, without leading zeros.
+    pub fn bits(&self) -> usize {
+        self.limbs.last().map_or(0, |top| {
+            self.limbs.len() * 32 - top.leading_zeros() as usize
+        })
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        self.limbs
+            .get(index / 32)
+            .is_some_and(|limb| (limb >> (index % 32)) & 1 == 1)
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        if self < other {
+            return None;
+        }
+        let mut borrow = false;
+        let limbs = self
+            .limbs
+            .iter()
+            .enumerate()
+            .map(|(i, &limb)| {
+                let (difference, under) = limb.overflowing_sub(other.limb(i));
+                let (difference, under_borrow) = difference.overflowing_sub(borrow as u32);
+                borrow = under || under_borrow;
+                difference
+            })
+            .collect();
+        Some(Self::normalized(limbs))
+    }
+
+    fn sum(&self, other: &Self) -> Self {
+        let mut carry = 0_u64;
+        let limbs = (0..self.limbs.len().max(other.limbs.len()) + 1)
+            .map(|i| {
+                let sum = self.limb(i) as u64 + other.limb(i) as u64 + carry;
+                carry = sum >> 32;
+                sum as u32
+            })
+            .collect();
+        Self::normalized(limbs)
+    }
+
+    fn product(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        let mut limbs = vec![0_u32; self.limbs.len() + other.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0_u64;
+            for (j, &b) in other.limbs.iter().enumerate() {
+                let product = a as u64 * b as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = product as u32;
+                carry = product >> 32;
+            }
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+        Self::normalized(limbs)
+    }
+
+    fn limb(&self, index: usize) -> u32 {
+        self.limbs.get(index).copied().unwrap_or(0)
+    }
+
+    /// The quotient and remainder of dividing by `divisor`, which must not be zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(
+            !divisor.is_zero(),
+            "attempt to divide a big integer by zero"
+        );
+        if self < divisor {
+            return (Self::zero(), self.clone());
+        }
+        if let [divisor] = divisor.limbs[..] {
+            let (quotient, remainder) = self.div_rem_small(divisor);
+            return (quotient, Self::from(remainder));
+        }
+        // Long division one bit at a time, plenty for the sizes of the puzzles.
+        let mut quotient = vec![0; self.limbs.len()];
+        let mut remainder = Self::zero();
+        for index in (0..self.bits()).rev() {
+            remainder = remainder << 1;
+            if self.bit(index) {
+                match remainder.limbs.first_mut() {
+                    Some(low) => *low |= 1,
+                    None => remainder.limbs.push(1),
+                }
+            }
+            if remainder >= *divisor {
+                remainder = &remainder - divisor;
+                quotient[index / 32] |= 1 << (index % 32);
+            }
+        }
+        (Self::normalized(quotient), remainder)
+    }
+
+    fn div_rem_small(&self, divisor: u32) -> (Self, u32) {
+        let mut quotient = vec![0; self.limbs.len()];
+        let mut remainder = 0_u64;
+        for (i, &limb) in self.limbs.iter().enumerate().rev() {
+            let current = (remainder << 32) | limb as u64;
+            quotient[i] = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+        (Self::normalized(quotient), remainder as u32)
+    }
+
+    /// Multiplies by `factor` and adds `term` in place.
+    fn mul_add_small(&mut self, factor: u32, term: u32) {
+        let mut carry = term as u64;
+        for limb in self.limbs.iter_mut() {
+            let product = *limb as u64 * factor as u64 + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    pub fn pow(&self, mut exponent: u32) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::from(1_u8);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    fn parse_digits(s: &str) -> Result<Self, BigIntError> {
+        if s.is_empty() {
+            return Err(BigIntError::Empty);
+        }
+        if !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(BigIntError::InvalidDigit);
+        }
+        let first = match s.len() % DECIMAL_DIGITS {
+            0 => DECIMAL_DIGITS,
+            n => n,
+        };
+        let mut value = Self::zero();
+        let mut start = 0;
+        let mut end = first;
+        while start < s.len() {
+            let chunk: u32 = s[start..end].parse().unwrap();
+            value.mul_add_small(10_u32.pow((end - start) as u32), chunk);
+            start = end;
+            end += DECIMAL_DIGITS;
+        }
+        Ok(value)
+    }
+
+    fn digits(&self) -> String {
+        let mut chunks = vec![];
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (quotient, chunk) = rest.div_rem_small(DECIMAL_BASE);
+            chunks.push(chunk);
+            rest = quotient;
+        }
+        let mut chunks = chunks.into_iter().rev();
+        let mut digits = chunks.next().unwrap_or(0).to_string();
+        for chunk in chunks {
+            digits.push_str(&format!("{chunk:0width$}", width = DECIMAL_DIGITS));
+        }
+        digits
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for BigUint {
+            fn from(value: $t) -> Self {
+                let mut value = value as u128;
+                let mut limbs = vec![];
+                while value > 0 {
+                    limbs.push(value as u32);
+                    value >>= 32;
+                }
+                Self { limbs }
+            }
+        }
+
+        impl TryFrom<&BigUint> for $t {
+            type Error = BigIntError;
+
+            fn try_from(value: &BigUint) -> Result<Self, Self::Error> {
+                if value.bits() > <$t>::BITS as usize {
+                    return Err(BigIntError::OutOfRange);
+                }
+                let value = value
+                    .limbs
+                    .iter()
+                    .rev()
+                    .fold(0_u128, |acc, &limb| (acc << 32) | limb as u128);
+                Ok(value as $t)
+            }
+        })*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = BigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_digits(s.strip_prefix('+').unwrap_or(s))
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "", &self.digits())
+    }
+}
+
+/// Implements the owned variants of an operator implemented on references.
+macro_rules! forward_binop {
+    ($t:ty, $($trait:ident $method:ident),*) => {
+        $(impl $trait<$t> for $t {
+            type Output = $t;
+
+            fn $method(self, other: $t) -> $t {
+                (&self).$method(&other)
+            }
+        }
+
+        impl $trait<&$t> for $t {
+            type Output = $t;
+
+            fn $method(self, other: &$t) -> $t {
+                (&self).$method(other)
+            }
+        }
+
+        impl $trait<$t> for &$t {
+            type Output = $t;
+
+            fn $method(self, other: $t) -> $t {
+                self.$method(&other)
+            }
+        })*
+    };
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        self.sum(other)
+    }
+}
+
+impl Sub for &BigUint {
+    type Output = BigUint;
+
+    fn sub(self, other: &BigUint) -> BigUint {
+        self.checked_sub(other)
+            .expect("attempt to subtract a bigger big integer")
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        self.product(other)
+    }
+}
+
+impl Div for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).1
+    }
+}
+
+macro_rules! impl_bitop {
+    ($($trait:ident $method:ident $op:tt),*) => {
+        $(impl $trait for &BigUint {
+            type Output = BigUint;
+
+            fn $method(self, other: &BigUint) -> BigUint {
+                let limbs = (0..self.limbs.len().max(other.limbs.len()))
+                    .map(|i| self.limb(i) $op other.limb(i))
+                    .collect();
+                BigUint::normalized(limbs)
+            }
+        })*
+    };
+}
+
+impl_bitop!(BitAnd bitand &, BitOr bitor |, BitXor bitxor ^);
+
+forward_binop!(BigUint, Add add, Sub sub, Mul mul, Div div, Rem rem, BitAnd bitand, BitOr bitor, BitXor bitxor);
+
+impl Shl<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shl(self, shift: usize) -> BigUint {
+        if self.is_zero() {
+            return BigUint::zero();
+        }
+        let (words, bits) = (shift / 32, shift % 32);
+        let mut limbs = vec![0; words];
+        match bits {
+            0 => limbs.extend_from_slice(&self.limbs),
+            _ => {
+                let mut carry = 0;
+                for &limb in &self.limbs {
+                    limbs.push((limb << bits) | carry);
+                    carry = limb >> (32 - bits);
+                }
+                limbs.push(carry);
+            }
+        }
+        BigUint::normalized(limbs)
+    }
+}
+
+impl Shr<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shr(self, shift: usize) -> BigUint {
+        let (words, bits) = (shift / 32, shift % 32);
+        if words >= self.limbs.len() {
+            return BigUint::zero();
+        }
+        let limbs = (words..self.limbs.len())
+            .map(|i| match bits {
+                0 => self.limbs[i],
+                _ => (self.limbs[i] >> bits) | (self.limb(i + 1) << (32 - bits)),
+            })
+            .collect();
+        BigUint::normalized(limbs)
+    }
+}
+
+impl Shl<usize> for BigUint {
+    type Output = BigUint;
+
+    fn shl(self, shift: usize) -> BigUint {
+        &self << shift
+    }
+}
+
+impl Shr<usize> for BigUint {
+    type Output = BigUint;
+
+    fn shr(self, shift: usize) -> BigUint {
+        &self >> shift
+    }
+}
+
+/// A signed integer of any size.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    /// Never set for zero, so that it has a single representation.
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    fn new(negative: bool, magnitude: BigUint) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// The absolute value.
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    pub fn pow(&self, exponent: u32) -> Self {
+        Self::new(
+            self.negative && exponent % 2 == 1,
+            self.magnitude.pow(exponent),
+        )
+    }
+
+    /// The quotient rounded toward zero and the remainder with the sign of `self`, as
+    /// for the machine integers. `divisor` must not be zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let (quotient, remainder) = self.magnitude.div_rem(&divisor.magnitude);
+        (
+            Self::new(self.negative != divisor.negative, quotient),
+            Self::new(self.negative, remainder),
+        )
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(magnitude: BigUint) -> Self {
+        Self::new(false, magnitude)
+    }
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for BigInt {
+            fn from(value: $t) -> Self {
+                Self::new(value < 0, BigUint::from(value.unsigned_abs()))
+            }
+        }
+
+        impl TryFrom<&BigInt> for $t {
+            type Error = BigIntError;
+
+            fn try_from(value: &BigInt) -> Result<Self, Self::Error> {
+                let magnitude = u128::try_from(&value.magnitude)?;
+                let value = match value.negative {
+                    true => 0_i128.checked_sub_unsigned(magnitude),
+                    false => i128::try_from(magnitude).ok(),
+                };
+                value
+                    .and_then(|value| <$t>::try_from(value).ok())
+                    .ok_or(BigIntError::OutOfRange)
+            }
+        })*
+    };
+}
+
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+
+impl From<u16> for BigInt {
+    fn from(value: u16) -> Self {
+        Self::from(BigUint::from(value))
+    }
+}
+
+impl From<u64> for BigInt {
+    fn from(value: u64) -> Self {
+        Self::from(BigUint::from(value))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = BigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        Ok(Self::new(negative, BigUint::parse_digits(digits)?))
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad_integral(!self.negative, "", &self.magnitude.digits())
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude)
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(self.negative, &self.magnitude + &other.magnitude);
+        }
+        match self.magnitude.cmp(&other.magnitude) {
+            Ordering::Less => BigInt::new(other.negative, &other.magnitude - &self.magnitude),
+            _ => BigInt::new(self.negative, &self.magnitude - &other.magnitude),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &-other
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::new(
+            self.negative != other.negative,
+            &self.magnitude * &other.magnitude,
+        )
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).1
+    }
+}
+
+forward_binop!(BigInt, Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+impl From<BigUint> for Answer {
+    fn from(value: BigUint) -> Self {
+        BigInt::from(value).into()
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        match i128::try_from(&value) {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+/// How a solver does the arithmetic that could overflow on large or adversarial inputs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Arithmetic {
+    /// Machine integers, which wrap around in release builds.
+    #[default]
+    Machine,
+    /// Machine integers, stopping with an [`Overflow`] in any build.
+    Checked,
+    /// Big integers, which never overflow.
+    Big,
+}
+
+pub const ARITHMETIC: Param = Param::new(
+    "arithmetic",
+    0,
+    "0 for machine integers, 1 to stop when they overflow, 2 for big integers",
+)
+.range(0, 2);
+
+impl Arithmetic {
+    /// The arithmetic of `params`, whose range [`Params::check`] enforces.
+    pub fn from_params(params: &Params) -> Self {
+        match params.value(&ARITHMETIC) {
+            0 => Arithmetic::Machine,
+            1 => Arithmetic::Checked,
+            _ => Arithmetic::Big,
+        }
+    }
+}
+
+/// The operation that overflowed with [`Arithmetic::Checked`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow(pub &'static str);
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} overflows, use {}=2 for big integers",
+            self.0, ARITHMETIC.name
+        )
+    }
+}
+
+impl Error for Overflow {}
+
+/// A part that overflowed has no answer, the warning tells which operation it was.
+impl<T: Into<Answer>> From<Result<T, Overflow>> for Answer {
+    fn from(value: Result<T, Overflow>) -> Self {
+        match value {
+            Ok(answer) => answer.into(),
+            Err(overflow) => {
+                crate::warn!("{overflow}");
+                Answer::Unsolved
+            }
+        }
+    }
+}
+
+/// The result of a checked `operation`, or the [`Overflow`] if there is none.
+pub fn checked<T>(result: Option<T>, operation: &'static str) -> Result<T, Overflow> {
+    result.ok_or(Overflow(operation))
+}
+
+/// The integers a solver can be generic over to run with any [`Arithmetic`]. Machine
+/// integers check for overflow unless it is [`Arithmetic::Machine`], big ones never
+/// need to.
+pub trait Integer:
+    Clone
+    + Ord
+    + Hash
+    + Display
+    + FromStr
+    + From<u16>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Into<Answer>
+{
+    fn plus(&self, other: &Self, arithmetic: Arithmetic) -> Result<Self, Overflow>;
+
+    fn minus(&self, other: &Self, arithmetic: Arithmetic) -> Result<Self, Overflow>;
+
+    fn times(&self, other: &Self, arithmetic: Arithmetic) -> Result<Self, Overflow>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            fn plus(&self, other: &Self, arithmetic: Arithmetic) -> Result<Self, Overflow> {
+                match arithmetic {
+                    Arithmetic::Machine => Ok(self + other),
+                    _ => checked(self.checked_add(*other), "an addition"),
+                }
+            }
+
+            fn minus(&self, other: &Self, arithmetic: Arithmetic) -> Result<Self, Overflow> {
+                match arithmetic {
+                    Arithmetic::Machine => Ok(self - other),
+                    _ => checked(self.checked_sub(*other), "a subtraction"),
+                }
+            }
+
+            fn times(&self, other: &Self, arithmetic: Arithmetic) -> Result<Self, Overflow> {
+                match arithmetic {
+                    Arithmetic::Machine => Ok(self * other),
+                    _ => checked(self.checked_mul(*other), "a multiplication"),
+                }
+            }
+        })*
+    };
+}
+
+impl_integer!(i64, u64, usize);
+
+macro_rules! impl_big_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            fn plus(&self, other: &Self, _: Arithmetic) -> Result<Self, Overflow> {
+                Ok(self + other)
+            }
+
+            fn minus(&self, other: &Self, _: Arithmetic) -> Result<Self, Overflow> {
+                Ok(self - other)
+            }
+
+            fn times(&self, other: &Self, _: Arithmetic) -> Result<Self, Overflow> {
+                Ok(self * other)
+            }
+        })*
+    };
+}
+
+impl_big_integer!(BigInt, BigUint);
+
+#[cfg(test)]
+mod bigint_tests {
+    use super::*;
+
+    fn samples() -> Vec<u128> {
+        let mut state = 0x2024_u128;
+        let mut samples = vec![0, 1, 2, 9, 10, u32::MAX as u128, 1 << 32, u64::MAX as u128];
+        for _ in 0..60 {
+            state = state
+                .wrapping_mul(0x5851_f42d_4c95_7f2d)
+                .wrapping_add(0x1405_7b7e_f767_814f);
+            samples.push(state >> (state % 120));
+        }
+        samples
+    }
+
+    fn big(value: u128) -> BigUint {
+        BigUint::from(value)
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!("0".parse(), Ok(BigUint::zero()));
+        assert_eq!("+000123".parse(), Ok(big(123)));
+        assert_eq!("".parse::<BigUint>(), Err(BigIntError::Empty));
+        assert_eq!("-1".parse::<BigUint>(), Err(BigIntError::InvalidDigit));
+        assert_eq!("12a".parse::<BigUint>(), Err(BigIntError::InvalidDigit));
+        let huge = "123456789012345678901234567890123456789012345678901234567890";
+        assert_eq!(huge.parse::<BigUint>().unwrap().to_string(), huge);
+        assert_eq!(format!("{:>6}", big(42)), "    42");
+        assert_eq!(format!("{:+}", big(42)), "+42");
+        for n in samples() {
+            assert_eq!(big(n).to_string(), n.to_string());
+            assert_eq!(n.to_string().parse(), Ok(big(n)));
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(u64::try_from(&big(u64::MAX as u128)), Ok(u64::MAX));
+        assert_eq!(u64::try_from(&big(1 << 64)), Err(BigIntError::OutOfRange));
+        assert_eq!(u8::try_from(&big(255)), Ok(255));
+        assert_eq!(i128::try_from(&BigInt::from(i128::MIN)), Ok(i128::MIN));
+        assert_eq!(i64::try_from(&BigInt::from(-5_i64)), Ok(-5));
+        assert_eq!(
+            i64::try_from(&BigInt::from(i64::MIN as i128 - 1)),
+            Err(BigIntError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let samples = samples();
+        for &a in &samples {
+            for &b in &samples {
+                if let Some(sum) = a.checked_add(b) {
+                    assert_eq!(big(a) + big(b), big(sum), "{a} + {b}");
+                }
+                if let Some(difference) = a.checked_sub(b) {
+                    assert_eq!(big(a) - big(b), big(difference), "{a} - {b}");
+                } else {
+                    assert_eq!(big(a).checked_sub(&big(b)), None);
+                }
+                if let Some(product) = a.checked_mul(b) {
+                    assert_eq!(big(a) * big(b), big(product), "{a} * {b}");
+                }
+                if b != 0 {
+                    assert_eq!(
+                        big(a).div_rem(&big(b)),
+                        (big(a / b), big(a % b)),
+                        "{a} / {b}"
+                    );
+                }
+                assert_eq!(big(a) ^ big(b), big(a ^ b));
+                assert_eq!(big(a) & big(b), big(a & b));
+                assert_eq!(big(a) | big(b), big(a | b));
+                assert_eq!(big(a).cmp(&big(b)), a.cmp(&b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_big_values() {
+        let two = big(2);
+        let huge = two.pow(200);
+        assert_eq!(huge.bits(), 201);
+        assert_eq!(
+            huge.to_string(),
+            "1606938044258990275541962092341162602522202993782792835301376"
+        );
+        assert_eq!(&huge >> 190, big(1024));
+        assert_eq!(big(1) << 200, huge);
+        let (quotient, remainder) = (&huge + big(7)).div_rem(&two.pow(100));
+        assert_eq!((quotient, remainder), (two.pow(100), big(7)));
+        let product = &huge * &huge;
+        assert_eq!(&product / &huge, huge);
+        assert_eq!(
+            BigUint::from(10_u8).pow(30).to_string(),
+            format!("1{}", "0".repeat(30))
+        );
+    }
+
+    #[test]
+    fn test_shifts() {
+        for n in samples() {
+            for shift in [0, 1, 5, 31, 32, 33, 64, 100] {
+                let shifted = big(n) << shift;
+                assert_eq!(&shifted >> shift, big(n));
+                if shift < 128 {
+                    assert_eq!(big(n) >> shift, big(n >> shift));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_signed() {
+        let values = [
+            -1_000_000_000_007_i128,
+            -12,
+            -1,
+            0,
+            1,
+            5,
+            12,
+            99_999_999_999,
+        ];
+        for &a in &values {
+            for &b in &values {
+                let (x, y) = (BigInt::from(a), BigInt::from(b));
+                assert_eq!(&x + &y, BigInt::from(a + b), "{a} + {b}");
+                assert_eq!(&x - &y, BigInt::from(a - b), "{a} - {b}");
+                assert_eq!(&x * &y, BigInt::from(a * b), "{a} * {b}");
+                if b != 0 {
+                    assert_eq!(&x / &y, BigInt::from(a / b), "{a} / {b}");
+                    assert_eq!(&x % &y, BigInt::from(a % b), "{a} % {b}");
+                }
+                assert_eq!(x.cmp(&y), a.cmp(&b));
+            }
+            assert_eq!(BigInt::from(a).to_string(), a.to_string());
+            assert_eq!(a.to_string().parse(), Ok(BigInt::from(a)));
+        }
+        assert_eq!(-BigInt::zero(), BigInt::zero());
+        assert_eq!("-0".parse(), Ok(BigInt::zero()));
+        assert_eq!("--1".parse::<BigInt>(), Err(BigIntError::InvalidDigit));
+        assert_eq!(BigInt::from(-3_i64).pow(3), BigInt::from(-27_i64));
+        assert_eq!(format!("{:>5}", BigInt::from(-42_i64)), "  -42");
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::from(big(42)), Answer::Number(42));
+        assert_eq!(Answer::from(BigInt::from(-42_i64)), Answer::Number(-42));
+        let huge = big(3).pow(100);
+        assert_eq!(Answer::from(huge.clone()), Answer::Text(huge.to_string()));
+    }
+
+    #[test]
+    fn test_arithmetic_param() {
+        assert_eq!(
+            Arithmetic::from_params(&Params::default()),
+            Arithmetic::Machine
+        );
+        let params = Params::from_pairs(&[("arithmetic", 2)]);
+        assert_eq!(Arithmetic::from_params(&params), Arithmetic::Big);
+        let params = Params::from_pairs(&[("arithmetic", 3)]);
+        assert_eq!(
+            params.check(&[ARITHMETIC]),
+            Err(
+                "invalid value `3` of parameter `arithmetic`, expected a number from 0 to 2"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(u64::MAX.plus(&0, Arithmetic::Checked), Ok(u64::MAX));
+        assert_eq!(3_i64.minus(&5, Arithmetic::Checked), Ok(-2));
+        let huge = BigUint::from(u64::MAX);
+        assert_eq!(
+            huge.times(&huge, Arithmetic::Big),
+            Ok(big(u64::MAX as u128 * u64::MAX as u128))
+        );
+    }
+
+    #[test]
+    fn test_checked_overflow() {
+        let overflow = u64::MAX.times(&2, Arithmetic::Checked);
+        assert_eq!(overflow, Err(Overflow("a multiplication")));
+        assert_eq!(
+            overflow.unwrap_err().to_string(),
+            "a multiplication overflows, use arithmetic=2 for big integers"
+        );
+        assert_eq!(Answer::from(overflow), Answer::Unsolved);
+    }
+}
@@ -0,0 +1,442 @@
+use std::{
+    env,
+    fmt::Debug,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+use crate::{random::Rng, Answer, Params, Solution};
+
+/// Overrides the seed of the random cases, to reproduce a failure or try new ones.
+pub const SEED_VAR: &str = "AOC_FUZZ_SEED";
+/// Overrides how many random cases each day tries.
+pub const CASES_VAR: &str = "AOC_FUZZ_CASES";
+
+const DEFAULT_SEED: u64 = 2024;
+const DEFAULT_CASES: u64 = 100;
+const MAX_SHRINKS: usize = 1000;
+const FIXTURE_DIR: &str = "regressions";
+const SEPARATOR: &str = "---\n";
+
+/// A random small input of a puzzle, which a deliberately naive solver can answer.
+pub trait Case: Clone + Debug {
+    fn generate(rng: &mut Rng) -> Self;
+
+    /// The puzzle input.
+    fn input(&self) -> String;
+
+    /// The parameters to solve the input with.
+    fn params(&self) -> Params {
+        Params::default()
+    }
+
+    /// Smaller inputs, tried in order to shrink a failing one.
+    fn shrink(&self) -> Vec<Self> {
+        vec![]
+    }
+
+    /// The answer of the naive solver, or [`Answer::Unsolved`] to skip `part`.
+    fn reference(&self, part: u8) -> Answer;
+}
+
+/// The lists left by removing one chunk of `items`, from halves down to single items.
+pub fn shrink_list<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut shrunk = vec![];
+    let mut size = items.len().div_ceil(2);
+    while size > 0 {
+        for start in (0..items.len()).step_by(size) {
+            let mut rest = items[..start].to_vec();
+            rest.extend_from_slice(&items[(start + size).min(items.len())..]);
+            shrunk.push(rest);
+        }
+        size = match size {
+            1 => 0,
+            size => size.div_ceil(2),
+        };
+    }
+    shrunk
+}
+
+/// The grids left by removing one row or one column of `grid`, keeping at least one.
+pub fn shrink_grid<T: Clone>(grid: &[Vec<T>]) -> Vec<Vec<Vec<T>>> {
+    let rows = (0..grid.len()).filter(|_| grid.len() > 1).map(|row| {
+        let mut rest = grid.to_vec();
+        rest.remove(row);
+        rest
+    });
+    let width = grid.first().map_or(0, Vec::len);
+    let cols = (0..width).filter(|_| width > 1).map(|col| {
+        grid.iter()
+            .map(|line| {
+                let mut rest = line.clone();
+                rest.remove(col);
+                rest
+            })
+            .collect()
+    });
+    rows.chain(cols).collect()
+}
+
+/// The lines of `grid`, as most puzzles write theirs.
+pub fn render_grid(grid: &[Vec<char>]) -> String {
+    grid.iter()
+        .map(|row| format!("{}\n", row.iter().collect::<String>()))
+        .collect()
+}
+
+/// The answer of `S` for `part` of `input`, or a description of its failure.
+fn solve<S: Solution>(input: &str, params: &Params, part: u8) -> Result<Answer, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        let input = S::parse_with(input, params).map_err(|e| e.to_string())?;
+        Ok(match part {
+            1 => S::part1(&input),
+            _ => S::part2(&input),
+        })
+    }))
+    .unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(format!("panic: {message}"))
+    })
+}
+
+/// The first part on which `S` disagrees with the reference, with both answers.
+fn mismatch<S: Solution, C: Case>(case: &C) -> Option<(u8, String, String)> {
+    let (input, params) = (case.input(), case.params());
+    [1, 2].into_iter().find_map(|part| {
+        let expected = case.reference(part);
+        if expected == Answer::Unsolved {
+            return None;
+        }
+        let found = match solve::<S>(&input, &params, part) {
+            Ok(answer) => answer.to_string(),
+            Err(e) => e,
+        };
+        (found != expected.to_string()).then(|| (part, expected.to_string(), found))
+    })
+}
+
+/// Replaces `case` with the smallest of its shrinks that still fails, as long as it can.
+fn shrink<S: Solution, C: Case>(mut case: C) -> C {
+    for _ in 0..MAX_SHRINKS {
+        match case
+            .shrink()
+            .into_iter()
+            .find(|smaller| mismatch::<S, C>(smaller).is_some())
+        {
+            Some(smaller) => case = smaller,
+            None => break,
+        }
+    }
+    case
+}
+
+/// A failing case saved with the answers of the reference, to be solved on every run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Fixture {
+    params: Params,
+    answers: [Option<String>; 2],
+    input: String,
+}
+
+impl Fixture {
+    fn of<C: Case>(case: &C) -> Self {
+        let answers = [1, 2].map(|part| match case.reference(part) {
+            Answer::Unsolved => None,
+            answer => Some(answer.to_string()),
+        });
+        Self {
+            params: case.params(),
+            answers,
+            input: case.input(),
+        }
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let (header, input) = text
+            .split_once(SEPARATOR)
+            .ok_or(format!("expected a `{}` line", SEPARATOR.trim()))?;
+        let mut fixture = Self {
+            params: Params::default(),
+            answers: [None, None],
+            input: input.to_string(),
+        };
+        for line in header.lines() {
+            match line.split_once(':').map(|(key, value)| (key, value.trim())) {
+                Some(("part1", answer)) => fixture.answers[0] = Some(answer.to_string()),
+                Some(("part2", answer)) => fixture.answers[1] = Some(answer.to_string()),
+                Some(("params", params)) => fixture.params = params.parse()?,
+                _ => return Err(format!("unknown header line `{line}`")),
+            }
+        }
+        Ok(fixture)
+    }
+
+    fn render(&self) -> String {
+        let mut text = String::new();
+        for (part, answer) in self.answers.iter().enumerate() {
+            if let Some(answer) = answer {
+                text.push_str(&format!("part{}: {answer}\n", part + 1));
+            }
+        }
+        if self.params != Params::default() {
+            text.push_str(&format!("params: {}\n", self.params));
+        }
+        format!("{text}{SEPARATOR}{}", self.input)
+    }
+
+    /// Describes each answer of `S` that differs from the saved one.
+    fn check<S: Solution>(&self) -> Vec<String> {
+        (1..=2)
+            .filter_map(|part| {
+                let expected = self.answers[part as usize - 1].as_ref()?;
+                let found = match solve::<S>(&self.input, &self.params, part) {
+                    Ok(answer) => answer.to_string(),
+                    Err(e) => e,
+                };
+                (found != *expected)
+                    .then(|| format!("part {part}: expected {expected}, got {found}"))
+            })
+            .collect()
+    }
+}
+
+fn env_number(var: &str, default: u64) -> u64 {
+    env::var(var)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+/// Solves the regression fixtures of the crate at `manifest_dir`, then random cases of
+/// `C`, with `S` and describes each answer that differs from the reference. The first
+/// failing random case is shrunk and saved as a new fixture.
+pub fn check_against_reference<S: Solution, C: Case>(manifest_dir: &str) -> Result<(), String> {
+    run::<S, C>(
+        &Path::new(manifest_dir).join(FIXTURE_DIR),
+        env_number(SEED_VAR, DEFAULT_SEED),
+        env_number(CASES_VAR, DEFAULT_CASES),
+    )
+}
+
+/// The tests every day crate runs: the examples of `$solution`, then its answers
+/// against the naive solver of the [`Case`] `$case`.
+#[macro_export]
+macro_rules! differential_tests {
+    ($solution:ty, $case:ty) => {
+        #[test]
+        fn test_examples() {
+            assert_eq!($crate::check_examples::<$solution>(), Ok(()));
+        }
+
+        #[test]
+        fn test_against_reference() {
+            assert_eq!(
+                $crate::differential::check_against_reference::<$solution, $case>(env!(
+                    "CARGO_MANIFEST_DIR"
+                )),
+                Ok(())
+            );
+        }
+    };
+}
+
+fn run<S: Solution, C: Case>(fixtures: &Path, seed: u64, cases: u64) -> Result<(), String> {
+    let mut mismatches = vec![];
+    let mut paths = fs::read_dir(fixtures)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    paths.sort();
+    for path in &paths {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let errors = match fs::read_to_string(path).map_err(|e| e.to_string()) {
+            Ok(text) => Fixture::parse(&text).map_or_else(|e| vec![e], |f| f.check::<S>()),
+            Err(e) => vec![e],
+        };
+        mismatches.extend(errors.into_iter().map(|e| format!("{name} {e}")));
+    }
+    for index in 0..cases {
+        let case = C::generate(&mut Rng::new(seed.wrapping_add(index)));
+        case.params().check(S::PARAMS)?;
+        if mismatch::<S, C>(&case).is_none() {
+            continue;
+        }
+        let case = shrink::<S, C>(case);
+        let (part, expected, found) = mismatch::<S, C>(&case).unwrap_or_default();
+        let path = save(fixtures, seed, index, &Fixture::of(&case));
+        mismatches.push(format!(
+            "seed {seed} case {index} part {part}: expected {expected}, got {found} for\n{}{}",
+            case.input(),
+            path.map_or_else(|e| e, |path| format!("saved as {}", path.display()))
+        ));
+        break;
+    }
+    match mismatches.is_empty() {
+        true => Ok(()),
+        false => Err(mismatches.join("\n")),
+    }
+}
+
+fn save(fixtures: &Path, seed: u64, index: u64, fixture: &Fixture) -> Result<PathBuf, String> {
+    let path = fixtures.join(format!("seed-{seed}-case-{index}.txt"));
+    fs::create_dir_all(fixtures)
+        .and_then(|_| fs::write(&path, fixture.render()))
+        .map_err(|e| format!("cannot save {}: {e}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod differential_tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        parse::{parse_at, parse_lines},
+        ParseError,
+    };
+
+    /// Sums numbers, but forgets those above 50.
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input<'a> = Vec<u64>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            let numbers = parse_lines::<Number, Vec<_>>(input, input)?;
+            Ok(numbers.into_iter().map(|n| n.0).collect())
+        }
+
+        fn part1(numbers: &Self::Input<'_>) -> Answer {
+            numbers.iter().filter(|&&n| n <= 50).sum::<u64>().into()
+        }
+
+        fn part2(numbers: &Self::Input<'_>) -> Answer {
+            numbers.len().into()
+        }
+    }
+
+    struct Number(u64);
+
+    impl FromStr for Number {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            parse_at(s, s, "a number").map(Number)
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Numbers(Vec<u64>);
+
+    impl Case for Numbers {
+        fn generate(rng: &mut Rng) -> Self {
+            Numbers((0..rng.below(10)).map(|_| rng.below(60) as u64).collect())
+        }
+
+        fn input(&self) -> String {
+            self.0.iter().map(|n| format!("{n}\n")).collect()
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_list(&self.0).into_iter().map(Numbers).collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            match part {
+                1 => self.0.iter().sum::<u64>().into(),
+                _ => self.0.len().into(),
+            }
+        }
+    }
+
+    fn fixtures(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_differential_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_shrink_list() {
+        assert_eq!(shrink_list::<u8>(&[]), Vec::<Vec<u8>>::new());
+        assert_eq!(shrink_list(&[1]), vec![vec![]]);
+        assert_eq!(
+            shrink_list(&[1, 2, 3, 4]),
+            vec![
+                vec![3, 4],
+                vec![1, 2],
+                vec![2, 3, 4],
+                vec![1, 3, 4],
+                vec![1, 2, 4],
+                vec![1, 2, 3]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shrink_grid() {
+        let grid = vec![vec![1, 2], vec![3, 4]];
+        assert_eq!(
+            shrink_grid(&grid),
+            vec![
+                vec![vec![3, 4]],
+                vec![vec![1, 2]],
+                vec![vec![2], vec![4]],
+                vec![vec![1], vec![3]]
+            ]
+        );
+        assert!(shrink_grid(&[vec![1]]).is_empty());
+        assert_eq!(render_grid(&[vec!['#', '.'], vec!['.', '@']]), "#.\n.@\n");
+    }
+
+    #[test]
+    fn test_shrinks_and_saves_failures() {
+        let dir = fixtures("failures");
+        let error = run::<Sum, Numbers>(&dir, 7, 100).unwrap_err();
+        let (summary, rest) = error.split_once(" for\n").unwrap();
+        assert!(summary.starts_with("seed 7 case "), "{error}");
+        let (input, saved) = rest.split_once("saved as ").unwrap();
+        assert_eq!(input.lines().count(), 1);
+        assert!(input.trim().parse::<u64>().unwrap() > 50);
+        let fixture = Fixture::parse(&fs::read_to_string(saved).unwrap()).unwrap();
+        assert_eq!(fixture.input, input);
+        assert_eq!(
+            fixture.answers,
+            [Some(input.trim().to_string()), Some("1".to_string())]
+        );
+        let replayed = run::<Sum, Numbers>(&dir, 7, 0).unwrap_err();
+        assert!(
+            replayed.ends_with(&format!("part 1: expected {}, got 0", input.trim())),
+            "{replayed}"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_passes() {
+        let dir = fixtures("passes");
+        assert_eq!(run::<Sum, Numbers>(&dir, 7, 0), Ok(()));
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_fixtures() {
+        let fixture = Fixture {
+            params: Params::from_pairs(&[("size", 7)]),
+            answers: [None, Some("4".to_string())],
+            input: "#.#\n".to_string(),
+        };
+        assert_eq!(fixture.render(), "part2: 4\nparams: size=7\n---\n#.#\n");
+        assert_eq!(Fixture::parse(&fixture.render()), Ok(fixture));
+        assert!(Fixture::parse("part3: 1\n---\n").is_err());
+        assert!(Fixture::parse("part1: 1\n").is_err());
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{Params, Solution};
+
+/// A worked example from the puzzle text, with the parameters it uses and the answers
+/// the text gives for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub params: &'static [(&'static str, u64)],
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    pub const fn new(name: &'static str, input: &'static str) -> Self {
+        Self {
+            name,
+            input,
+            params: &[],
+            part1: None,
+            part2: None,
+        }
+    }
+
+    pub const fn params(self, params: &'static [(&'static str, u64)]) -> Self {
+        Self { params, ..self }
+    }
+
+    pub const fn part1(self, answer: &'static str) -> Self {
+        Self {
+            part1: Some(answer),
+            ..self
+        }
+    }
+
+    pub const fn part2(self, answer: &'static str) -> Self {
+        Self {
+            part2: Some(answer),
+            ..self
+        }
+    }
+
+    pub fn expected(&self, part: u8) -> Option<&'static str> {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => None,
+        }
+    }
+
+    pub fn to_params(&self) -> Params {
+        Params::from_pairs(self.params)
+    }
+}
+
+/// The input of the example of `S` called `name`.
+pub fn example_input<S: Solution>(name: &str) -> &'static str {
+    S::EXAMPLES
+        .iter()
+        .find(|example| example.name == name)
+        .map(|example| example.input)
+        .unwrap_or_else(|| panic!("no example called `{name}`"))
+}
+
+/// Solves every example of `S` and describes each answer that differs from the one
+/// the example expects, or each parameter `S` does not declare.
+pub fn check_examples<S: Solution>() -> Result<(), String> {
+    let mut mismatches = vec![];
+    for example in S::EXAMPLES {
+        let params = example.to_params();
+        if let Err(e) = params.check(S::PARAMS) {
+            mismatches.push(format!("{}: {e}", example.name));
+            continue;
+        }
+        let input = match S::parse_with(example.input, &params) {
+            Ok(input) => input,
+            Err(e) => {
+                mismatches.push(format!("{}: {e}", example.name));
+                continue;
+            }
+        };
+        for part in [1, 2] {
+            let Some(expected) = example.expected(part) else {
+                continue;
+            };
+            let answer = match part {
+                1 => S::part1(&input),
+                _ => S::part2(&input),
+            };
+            if answer.to_string() != expected {
+                mismatches.push(format!(
+                    "{} part {part}: expected {expected}, got {answer}",
+                    example.name
+                ));
+            }
+        }
+    }
+    match mismatches.is_empty() {
+        true => Ok(()),
+        false => Err(mismatches.join("\n")),
+    }
+}
+
+#[cfg(test)]
+mod example_tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        parse::{parse_at, parse_lines},
+        Answer, Param, ParseError,
+    };
+
+    const FACTOR: Param = Param::new("factor", 2, "what part 2 multiplies the sum by");
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input<'a> = (Vec<u64>, u64);
+
+        const EXAMPLES: &'static [Example] = &[
+            Example::new("small", "1\n2\n3\n").part1("6").part2("12"),
+            Example::new("scaled", "1\n2\n3\n")
+                .params(&[("factor", 3)])
+                .part2("18"),
+            Example::new("wrong", "4\n").part1("5"),
+            Example::new("broken", "4\nx\n").part1("4"),
+            Example::new("undeclared", "4\n")
+                .params(&[("offset", 1)])
+                .part1("4"),
+        ];
+
+        const PARAMS: &'static [Param] = &[FACTOR];
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            Self::parse_with(input, &Params::default())
+        }
+
+        fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+            let numbers = parse_lines::<Number, Vec<_>>(input, input)?;
+            let numbers = numbers.into_iter().map(|n| n.0).collect();
+            Ok((numbers, params.value(&FACTOR)))
+        }
+
+        fn part1((numbers, _): &Self::Input<'_>) -> Answer {
+            numbers.iter().sum::<u64>().into()
+        }
+
+        fn part2((numbers, factor): &Self::Input<'_>) -> Answer {
+            (numbers.iter().sum::<u64>() * factor).into()
+        }
+    }
+
+    struct Number(u64);
+
+    impl FromStr for Number {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            parse_at(s, s, "a number").map(Number)
+        }
+    }
+
+    #[test]
+    fn test_check_examples() {
+        assert_eq!(
+            check_examples::<Sum>(),
+            Err(
+                "wrong part 1: expected 5, got 4\nbroken: line 2, column 1: expected a number, found `x`\nundeclared: unknown parameter `offset`, expected one of factor"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_example_builder() {
+        let example = Sum::EXAMPLES[1];
+        assert_eq!(example.name, "scaled");
+        assert_eq!(example.expected(1), None);
+        assert_eq!(example.expected(2), Some("18"));
+        assert_eq!(example.to_params().get("factor"), Some(3));
+        assert_eq!(example_input::<Sum>("wrong"), "4\n");
+    }
+}
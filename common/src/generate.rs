@@ -0,0 +1,163 @@
+use crate::{random::Rng, Params};
+
+/// A synthetic puzzle input with the params it is solved with, such as the size of
+/// its grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub params: Params,
+}
+
+impl Generated {
+    pub fn new(input: String) -> Self {
+        Self {
+            input,
+            params: Params::default(),
+        }
+    }
+
+    pub fn param(mut self, name: &str, value: u64) -> Self {
+        self.params.set(name, value);
+        self
+    }
+}
+
+/// A maze of `#` walls with a single way between any two open cells, `side` wide and
+/// high rounded up to an odd number of at least 5. Open cells are at odd rows and columns
+/// and the passages between them.
+pub fn maze(side: usize, rng: &mut Rng) -> Vec<Vec<char>> {
+    let side = side.max(5) | 1;
+    let mut maze = vec![vec!['#'; side]; side];
+    maze[1][1] = '.';
+    let mut stack = vec![(1_usize, 1_usize)];
+    while let Some(&(row, col)) = stack.last() {
+        let next = [(0, 2), (2, 0), (2, 4), (4, 2)]
+            .into_iter()
+            .filter_map(|(dr, dc)| Some(((row + dr).checked_sub(2)?, (col + dc).checked_sub(2)?)))
+            .filter(|&(r, c)| r > 0 && c > 0 && r < side - 1 && c < side - 1)
+            .filter(|&(r, c)| maze[r][c] == '#')
+            .collect::<Vec<_>>();
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let (r, c) = *rng.pick(&next);
+        maze[(row + r) / 2][(col + c) / 2] = '.';
+        maze[r][c] = '.';
+        stack.push((r, c));
+    }
+    maze
+}
+
+/// A single track of `.` from `S` to `E` winding through every other cell of a grid of
+/// `#` walls, `side` wide and high rounded to 4 times a number from 2, plus 1. The track
+/// goes around a random tree of [`maze`], which closes it in a loop, cut between the
+/// start and the end.
+pub fn single_track(side: usize, rng: &mut Rng) -> Vec<Vec<char>> {
+    let blocks = ((side.max(9) - 1) / 4).max(2);
+    let tree = maze(2 * blocks + 1, rng);
+    // Each block of 2 by 2 cells of the loop belongs to a node of the tree.
+    let cells = 2 * blocks;
+    let mut links = vec![vec![vec![]; cells]; cells];
+    let mut link = |a: (usize, usize), b: (usize, usize)| {
+        links[a.0][a.1].push(b);
+        links[b.0][b.1].push(a);
+    };
+    for (a, b) in (0..blocks).flat_map(|a| (0..blocks).map(move |b| (a, b))) {
+        let (row, col) = (2 * a, 2 * b);
+        let up = a > 0 && tree[2 * a][2 * b + 1] == '.';
+        let down = a + 1 < blocks && tree[2 * a + 2][2 * b + 1] == '.';
+        let left = b > 0 && tree[2 * a + 1][2 * b] == '.';
+        let right = b + 1 < blocks && tree[2 * a + 1][2 * b + 2] == '.';
+        if !up {
+            link((row, col), (row, col + 1));
+        }
+        if !down {
+            link((row + 1, col), (row + 1, col + 1));
+        }
+        if !left {
+            link((row, col), (row + 1, col));
+        }
+        if !right {
+            link((row, col + 1), (row + 1, col + 1));
+        }
+        if down {
+            link((row + 1, col), (row + 2, col));
+            link((row + 1, col + 1), (row + 2, col + 1));
+        }
+        if right {
+            link((row, col + 1), (row, col + 2));
+            link((row + 1, col + 1), (row + 1, col + 2));
+        }
+    }
+    let start = (rng.below(cells), rng.below(cells));
+    let end = links[start.0][start.1][0];
+    let mut track = vec![vec!['#'; 2 * cells + 1]; 2 * cells + 1];
+    let (mut previous, mut cell) = (end, start);
+    loop {
+        track[2 * cell.0 + 1][2 * cell.1 + 1] = '.';
+        if cell == end {
+            break;
+        }
+        let next = *links[cell.0][cell.1]
+            .iter()
+            .find(|&&next| next != previous)
+            .unwrap();
+        track[cell.0 + next.0 + 1][cell.1 + next.1 + 1] = '.';
+        (previous, cell) = (cell, next);
+    }
+    track[2 * start.0 + 1][2 * start.1 + 1] = 'S';
+    track[2 * end.0 + 1][2 * end.1 + 1] = 'E';
+    track
+}
+
+#[cfg(test)]
+mod generate_tests {
+    use super::*;
+
+    #[test]
+    fn test_generated() {
+        let generated = Generated::new("1,2\n".to_string()).param("size", 7);
+        assert_eq!(generated.input, "1,2\n");
+        assert_eq!(generated.params, Params::from_pairs(&[("size", 7)]));
+    }
+
+    #[test]
+    fn test_maze() {
+        let maze = maze(8, &mut Rng::new(1));
+        assert_eq!((maze.len(), maze[0].len()), (9, 9));
+        assert!(maze[0].iter().chain(&maze[8]).all(|&c| c == '#'));
+        let open = maze.iter().flatten().filter(|&&c| c == '.').count();
+        // A tree of the 16 cells has 15 passages between them.
+        assert_eq!(open, 16 + 15);
+        assert_eq!(maze, super::maze(8, &mut Rng::new(1)));
+    }
+
+    #[test]
+    fn test_single_track() {
+        let track = single_track(20, &mut Rng::new(3));
+        assert_eq!((track.len(), track[0].len()), (17, 17));
+        let open = |row: usize, col: usize| track[row][col] != '#';
+        let cells = (1..16).flat_map(|row| (1..16).map(move |col| (row, col)));
+        let ends = cells
+            .clone()
+            .filter(|&(row, col)| open(row, col))
+            .map(|(row, col)| {
+                let neighbours = [
+                    (row - 1, col),
+                    (row + 1, col),
+                    (row, col - 1),
+                    (row, col + 1),
+                ];
+                neighbours.iter().filter(|&&(r, c)| open(r, c)).count()
+            })
+            .collect::<Vec<_>>();
+        // Every tile but the start and the end continues the track both ways.
+        assert_eq!(ends.iter().filter(|&&n| n == 1).count(), 2);
+        assert!(ends.iter().all(|&n| n == 1 || n == 2));
+        // 64 cells with the 63 tiles between them.
+        assert_eq!(ends.len(), 64 + 63);
+        assert_eq!(track.iter().flatten().filter(|&&c| c == 'S').count(), 1);
+        assert_eq!(track.iter().flatten().filter(|&&c| c == 'E').count(), 1);
+    }
+}
@@ -0,0 +1,357 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Index, IndexMut},
+};
+
+use crate::{geom::Direction, ParseError};
+
+/// A `(row, col)` position in a [`Grid`].
+pub type Pos = (usize, usize);
+
+const DELTAS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const DELTAS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(rows: usize, cols: usize, value: T) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: vec![value; rows * cols],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from the trimmed lines of `input`, mapping every char through `f`.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        Self::from_rows(
+            input
+                .trim()
+                .lines()
+                .map(|l| l.trim().chars().map(&mut f).collect())
+                .collect(),
+        )
+    }
+
+    /// Like [`Grid::parse`], but reports the position of the first char `f` rejects
+    /// and of any row whose length differs from the first one.
+    pub fn try_parse(
+        input: &str,
+        mut f: impl FnMut(char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<T>> = vec![];
+        for line in input.trim().lines().map(str::trim) {
+            let row = line
+                .char_indices()
+                .map(|(i, c)| f(c).map_err(|e| e.within(input, &line[i..])))
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("a row of {} cells", first.len()),
+                    ));
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "all grid rows must have {cols} columns"
+        );
+        Self {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn in_bounds(&self, (row, col): Pos) -> bool {
+        row < self.rows && col < self.cols
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.in_bounds(pos)
+            .then(|| &self.cells[pos.0 * self.cols + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.in_bounds(pos)
+            .then(|| &mut self.cells[pos.0 * self.cols + pos.1])
+    }
+
+    /// The position `delta` away from `pos`, if it is inside the grid.
+    pub fn offset(&self, (row, col): Pos, (delta_row, delta_col): (isize, isize)) -> Option<Pos> {
+        let pos = (
+            row.checked_add_signed(delta_row)?,
+            col.checked_add_signed(delta_col)?,
+        );
+        self.in_bounds(pos).then_some(pos)
+    }
+
+    /// The position one step from `pos` towards `direction`, if it is inside the grid.
+    pub fn step(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        self.offset(pos, direction.delta().into())
+    }
+
+    /// Orthogonal neighbours of `pos` inside the grid, in reading order.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DELTAS_4
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    /// Orthogonal and diagonal neighbours of `pos` inside the grid, in reading order.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DELTAS_8
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        (0..self.rows).map(move |row| &self[(row, col)])
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.rows).map(|row| self.row(row))
+    }
+
+    pub fn iter_cols(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.cols).map(|col| self.col(col))
+    }
+
+    fn diagonal_count(&self) -> usize {
+        match self.cells.is_empty() {
+            true => 0,
+            false => self.rows + self.cols - 1,
+        }
+    }
+
+    /// Lines of cells sharing `row + col`, each from bottom-left to top-right,
+    /// starting at the top-left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.diagonal_count()).map(move |sum| {
+            let first_col = sum.saturating_sub(self.rows - 1);
+            let last_col = sum.min(self.cols - 1);
+            (first_col..=last_col).map(move |col| &self[(sum - col, col)])
+        })
+    }
+
+    /// Lines of cells sharing `col - row`, each from top-left to bottom-right,
+    /// starting at the top-right corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.diagonal_count()).map(move |d| {
+            let first_row = d.saturating_sub(self.cols - 1);
+            let last_row = d.min(self.rows - 1);
+            let first_col = (self.cols - 1).saturating_sub(d);
+            (first_row..=last_row).map(move |row| &self[(row, first_col + row - first_row)])
+        })
+    }
+
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    pub fn find(&self, value: &T) -> Option<Pos> {
+        self.position(|cell| cell == value)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "position {pos:?} out of bounds for a {}x{} grid",
+                self.rows, self.cols
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {pos:?} out of bounds for a {rows}x{cols} grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (row, cells) in self.iter_rows().enumerate() {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for cell in cells {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use super::*;
+
+    const INPUT: &str = r#"XMAS
+MASX
+SAXM"#;
+
+    fn lines<'a, I>(lines: impl Iterator<Item = I>) -> Vec<String>
+    where
+        I: Iterator<Item = &'a char>,
+    {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::parse(INPUT, |c| c);
+        assert_eq!((grid.rows(), grid.cols()), (3, 4));
+        assert_eq!(grid[(1, 2)], 'S');
+        assert_eq!(grid.to_string(), INPUT);
+    }
+
+    #[test]
+    fn test_try_parse() {
+        let digits = |c: char| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::new("a digit", &c.to_string()))
+        };
+        let grid = Grid::try_parse("12\n34\n", digits).unwrap();
+        assert_eq!(grid[(1, 0)], 3);
+        assert_eq!(
+            Grid::try_parse("12\n3x", digits).unwrap_err().to_string(),
+            "line 2, column 2: expected a digit, found `x`"
+        );
+        assert_eq!(
+            Grid::try_parse("12\n345", digits).unwrap_err().to_string(),
+            "line 2, column 1: expected a row of 2 cells, found `345`"
+        );
+    }
+
+    #[test]
+    fn test_checked_access() {
+        let mut grid = Grid::new(2, 3, 0);
+        assert_eq!(grid.get((1, 2)), Some(&0));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        *grid.get_mut((1, 1)).unwrap() = 5;
+        grid[(0, 2)] = 7;
+        assert_eq!(grid.to_string(), "007\n050");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let grid = Grid::new(2, 2, '.');
+        let _ = grid[(0, 2)];
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, ());
+        assert_eq!(
+            grid.neighbours4((1, 1)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0), (1, 2), (2, 1)]
+        );
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.step((1, 1), Direction::West), Some((1, 0)));
+        assert_eq!(grid.step((0, 1), Direction::North), None);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            vec![(1, 1), (1, 2), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn test_rows_and_cols() {
+        let grid = Grid::parse(INPUT, |c| c);
+        assert_eq!(grid.row(1), &['M', 'A', 'S', 'X']);
+        assert_eq!(lines(grid.iter_cols()), vec!["XMS", "MAA", "ASX", "SXM"]);
+    }
+
+    #[test]
+    fn test_diagonals() {
+        let grid = Grid::parse(INPUT, |c| c);
+        assert_eq!(
+            lines(grid.anti_diagonals()),
+            vec!["X", "MM", "SAA", "ASS", "XX", "M"]
+        );
+        assert_eq!(
+            lines(grid.diagonals()),
+            vec!["S", "AX", "MSM", "XAX", "MA", "S"]
+        );
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = Grid::parse(INPUT, |c| c);
+        assert_eq!(grid.find(&'X'), Some((0, 0)));
+        assert_eq!(grid.position(|&c| c != 'X'), Some((0, 1)));
+        assert_eq!(grid.find(&'Z'), None);
+        assert_eq!(grid.map(|&c| c == 'S').find(&true), Some((0, 3)));
+    }
+}
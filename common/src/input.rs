@@ -0,0 +1,183 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const STDIN: &str = "-";
+
+#[derive(Debug)]
+pub enum InputError {
+    Read { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+    WorkspaceNotFound { start: PathBuf },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Read { path, source } => {
+                write!(f, "cannot read input `{}`: {source}", path.display())
+            }
+            InputError::Stdin(source) => write!(f, "cannot read input from stdin: {source}"),
+            InputError::WorkspaceNotFound { start } => write!(
+                f,
+                "no workspace root found above `{}`, set {INPUT_DIR_VAR} or pass --input-dir",
+                start.display()
+            ),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Read { source, .. } | InputError::Stdin(source) => Some(source),
+            InputError::WorkspaceNotFound { .. } => None,
+        }
+    }
+}
+
+fn is_workspace_root(dir: &Path) -> bool {
+    fs::read_to_string(dir.join("Cargo.toml"))
+        .map(|manifest| manifest.lines().any(|l| l.trim() == "[workspace]"))
+        .unwrap_or(false)
+}
+
+fn find_workspace_root(start: &Path) -> Result<PathBuf, InputError> {
+    start
+        .ancestors()
+        .find(|dir| is_workspace_root(dir))
+        .map(Path::to_path_buf)
+        .ok_or_else(|| InputError::WorkspaceNotFound {
+            start: start.to_path_buf(),
+        })
+}
+
+fn find_input_dir(
+    dir: Option<&Path>,
+    env_dir: Option<PathBuf>,
+    start: &Path,
+) -> Result<PathBuf, InputError> {
+    match dir.map(Path::to_path_buf).or(env_dir) {
+        Some(dir) => Ok(dir),
+        None => Ok(find_workspace_root(start)?.join("inputs")),
+    }
+}
+
+fn current_dir() -> Result<PathBuf, InputError> {
+    env::current_dir().map_err(|source| InputError::Read {
+        path: PathBuf::from("."),
+        source,
+    })
+}
+
+pub fn workspace_root() -> Result<PathBuf, InputError> {
+    find_workspace_root(&current_dir()?)
+}
+
+pub fn input_dir(dir: Option<&Path>) -> Result<PathBuf, InputError> {
+    find_input_dir(
+        dir,
+        env::var_os(INPUT_DIR_VAR).map(PathBuf::from),
+        &current_dir()?,
+    )
+}
+
+pub fn read_path(path: &Path) -> Result<String, InputError> {
+    if path == Path::new(STDIN) {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(InputError::Stdin)?;
+        return Ok(input);
+    }
+    fs::read_to_string(path).map_err(|source| InputError::Read {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// The input of `day` of `year`, relative to the input directory: `<year>/dayNN.txt`.
+pub fn input_file(year: u16, day: u8) -> PathBuf {
+    Path::new(&year.to_string()).join(format!("day{day:02}.txt"))
+}
+
+pub fn try_read_input(year: u16, day: u8, dir: Option<&Path>) -> Result<String, InputError> {
+    read_path(&input_dir(dir)?.join(input_file(year, day)))
+}
+
+pub fn read_input(year: u16, day: u8) -> String {
+    try_read_input(year, day, None).unwrap_or_else(|e| panic!("{e}"))
+}
+
+#[cfg(test)]
+mod input_tests {
+    use super::*;
+
+    fn temp_workspace(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-input-{name}-{}", std::process::id()));
+        fs::create_dir_all(root.join("inputs/2024")).unwrap();
+        fs::create_dir_all(root.join("days/2024/day01/src")).unwrap();
+        fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = []\n").unwrap();
+        fs::write(root.join("days/2024/day01/Cargo.toml"), "[package]\n").unwrap();
+        root
+    }
+
+    #[test]
+    fn test_find_input_dir_precedence() {
+        let start = Path::new("/nowhere");
+        let flag = Path::new("from/flag");
+        let env_dir = Some(PathBuf::from("from/env"));
+        assert_eq!(
+            find_input_dir(Some(flag), env_dir.clone(), start).unwrap(),
+            PathBuf::from("from/flag")
+        );
+        assert_eq!(
+            find_input_dir(None, env_dir, start).unwrap(),
+            PathBuf::from("from/env")
+        );
+    }
+
+    #[test]
+    fn test_find_input_dir_searches_upward() {
+        let root = temp_workspace("upward");
+        assert_eq!(
+            find_input_dir(None, None, &root.join("days/2024/day01/src")).unwrap(),
+            root.join("inputs")
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_find_input_dir_without_workspace() {
+        let error = find_input_dir(None, None, Path::new("/")).unwrap_err();
+        assert!(matches!(error, InputError::WorkspaceNotFound { .. }));
+    }
+
+    #[test]
+    fn test_missing_input_names_path() {
+        let root = temp_workspace("missing");
+        let error = try_read_input(2015, 1, Some(&root.join("inputs"))).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains(&root.join("inputs/2015/day01.txt").display().to_string()));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_read_input_from_dir() {
+        let root = temp_workspace("read");
+        fs::write(root.join("inputs/2024/day01.txt"), "3   4\n").unwrap();
+        assert_eq!(
+            try_read_input(2024, 1, Some(&root.join("inputs"))).unwrap(),
+            "3   4\n"
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -1,7 +1,24 @@
-use std::{fs, path::PathBuf};
-
-pub fn read_input(file_name: &str) -> String {
-    let path = PathBuf::from(format!("inputs/{file_name}"));
-    fs::read_to_string(path).unwrap()
-}
+pub mod bigint;
+pub mod differential;
+mod example;
+pub mod generate;
+pub mod geom;
+pub mod grid;
+mod input;
+pub mod log;
+pub mod observe;
+mod params;
+pub mod parse;
+pub mod random;
+pub mod render;
+pub mod search;
+mod solution;
 
+pub use example::{check_examples, example_input, Example};
+pub use input::{
+    input_dir, input_file, read_input, read_path, try_read_input, workspace_root, InputError,
+    INPUT_DIR_VAR,
+};
+pub use params::{Param, Params};
+pub use parse::ParseError;
+pub use solution::{Answer, Solution};
@@ -0,0 +1,262 @@
+use std::{
+    env,
+    fmt::{self, Arguments, Display, Formatter},
+    str::FromStr,
+    sync::OnceLock,
+};
+
+pub const LOG_VAR: &str = "AOC_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Off => "OFF",
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "off" => Ok(Level::Off),
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!(
+                "invalid log level `{s}`, expected off, error, warn, info, debug or trace"
+            )),
+        }
+    }
+}
+
+/// The most detailed level logged overall and for some targets, the modules that emit
+/// the events. It comes from `AOC_LOG`, such as `info,y2024_day18=trace`, unless the
+/// program sets one with [`init`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    level: Level,
+    targets: Vec<(String, Level)>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self::new(Level::Warn)
+    }
+}
+
+impl Filter {
+    pub fn new(level: Level) -> Self {
+        Self {
+            level,
+            targets: vec![],
+        }
+    }
+
+    /// Raises the overall level by `steps`, as `--verbose` does for each time it is given.
+    pub fn louder(mut self, steps: usize) -> Self {
+        let levels = [Level::Warn, Level::Info, Level::Debug, Level::Trace];
+        let current = levels.iter().position(|&l| l >= self.level).unwrap_or(0);
+        if steps > 0 {
+            self.level = levels[(current + steps).min(levels.len() - 1)].max(self.level);
+        }
+        self
+    }
+
+    /// Logs `target` and the modules under it up to `level`.
+    pub fn with_target(mut self, target: &str, level: Level) -> Self {
+        self.targets.push((target.to_string(), level));
+        self
+    }
+
+    /// Whether an event of `level` from `target` is logged. The longest target of the
+    /// filter that `target` is in decides, or else the overall level.
+    pub fn enabled(&self, level: Level, target: &str) -> bool {
+        let max = self
+            .targets
+            .iter()
+            .filter(|(name, _)| {
+                target
+                    .strip_prefix(name.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(name, _)| name.len())
+            .map_or(self.level, |&(_, level)| level);
+        level != Level::Off && level <= max
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    /// Parses comma separated levels and `target=level` pairs, such as
+    /// `warn,y2024_day19=debug`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Filter::default();
+        for directive in s.split(',').filter(|d| !d.trim().is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => {
+                    filter = filter.with_target(target.trim(), level.parse()?);
+                }
+                None => filter.level = directive.parse()?,
+            }
+        }
+        Ok(filter)
+    }
+}
+
+static FILTER: OnceLock<Filter> = OnceLock::new();
+
+/// Sets the filter of the program. Only the first call, before any event, has an effect.
+pub fn init(filter: Filter) {
+    let _ = FILTER.set(filter);
+}
+
+/// The filter set by [`init`], or else the one in `AOC_LOG`, or else warnings and errors.
+/// An invalid `AOC_LOG` is reported once on stderr and leaves the default.
+pub fn filter() -> &'static Filter {
+    FILTER.get_or_init(|| {
+        env_filter(env::var(LOG_VAR).ok().as_deref()).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            Filter::default()
+        })
+    })
+}
+
+/// The filter of the `AOC_LOG` value `env`, the default one if it is unset.
+fn env_filter(env: Option<&str>) -> Result<Filter, String> {
+    env.map_or(Ok(Filter::default()), |env| {
+        env.parse().map_err(|e| format!("invalid {LOG_VAR}: {e}"))
+    })
+}
+
+pub fn enabled(level: Level, target: &str) -> bool {
+    filter().enabled(level, target)
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, target: &str, args: Arguments<'_>) {
+    eprintln!("[{level} {target}] {args}");
+}
+
+#[macro_export]
+macro_rules! log {
+    (target: $target:expr, $level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, $target) {
+            $crate::log::write($level, $target, format_args!($($arg)+));
+        }
+    };
+    ($level:expr, $($arg:tt)+) => {
+        $crate::log!(target: module_path!(), $level, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod log_tests {
+    use super::*;
+
+    #[test]
+    fn test_default_filter() {
+        let filter = Filter::default();
+        assert!(filter.enabled(Level::Error, "y2024_day18"));
+        assert!(filter.enabled(Level::Warn, "y2024_day18"));
+        assert!(!filter.enabled(Level::Info, "y2024_day18"));
+        assert!(!filter.enabled(Level::Trace, "y2024_day18"));
+    }
+
+    #[test]
+    fn test_target_filter() {
+        let filter = "info,y2024_day18=trace,y2024_day18::memory=off"
+            .parse::<Filter>()
+            .unwrap();
+        assert!(filter.enabled(Level::Info, "aoc::run"));
+        assert!(!filter.enabled(Level::Debug, "aoc::run"));
+        assert!(filter.enabled(Level::Trace, "y2024_day18"));
+        assert!(filter.enabled(Level::Trace, "y2024_day18::grid"));
+        assert!(!filter.enabled(Level::Error, "y2024_day18::memory"));
+        assert!(!filter.enabled(Level::Debug, "y2024_day180"));
+        assert!(!filter.enabled(Level::Off, "y2024_day18"));
+    }
+
+    #[test]
+    fn test_louder() {
+        assert_eq!(Filter::default().louder(0), Filter::new(Level::Warn));
+        assert_eq!(Filter::default().louder(1), Filter::new(Level::Info));
+        assert_eq!(Filter::default().louder(5), Filter::new(Level::Trace));
+        assert_eq!(Filter::new(Level::Off).louder(1), Filter::new(Level::Info));
+        assert_eq!(
+            Filter::new(Level::Debug).louder(1),
+            Filter::new(Level::Trace)
+        );
+    }
+
+    #[test]
+    fn test_parse_filter() {
+        assert_eq!("".parse(), Ok(Filter::default()));
+        assert_eq!(
+            "debug, y2024_day19=trace".parse(),
+            Ok(Filter::new(Level::Debug).with_target("y2024_day19", Level::Trace))
+        );
+        assert_eq!(
+            "loud".parse::<Filter>(),
+            Err(
+                "invalid log level `loud`, expected off, error, warn, info, debug or trace"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_env_filter() {
+        assert_eq!(env_filter(None), Ok(Filter::default()));
+        assert_eq!(env_filter(Some("info")), Ok(Filter::new(Level::Info)));
+        assert_eq!(
+            env_filter(Some("y2024_day18=loud")),
+            Err("invalid AOC_LOG: invalid log level `loud`, expected off, error, warn, info, debug or trace".to_string())
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A read-only view of a simulation between two ticks, drawn by its `Display`.
+pub trait View: Display {
+    /// What the last tick did, such as the move or the instruction it ran.
+    fn caption(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Watches a simulation: it is shown the state before the first tick and after each one.
+pub trait Observer {
+    fn observe(&mut self, state: &dyn View);
+}
+
+/// Ignores every tick, for runs that nobody watches.
+impl Observer for () {
+    fn observe(&mut self, _state: &dyn View) {}
+}
+
+/// Shows every tick to both observers, the first one first.
+impl<A: Observer, B: Observer> Observer for (A, B) {
+    fn observe(&mut self, state: &dyn View) {
+        self.0.observe(state);
+        self.1.observe(state);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: Option<String>,
+    pub text: String,
+}
+
+impl Frame {
+    pub fn of(state: &dyn View) -> Self {
+        Self {
+            caption: state.caption(),
+            text: state.to_string(),
+        }
+    }
+}
+
+/// Keeps every tick as a [`Frame`].
+#[derive(Debug, Default)]
+pub struct FrameCollector {
+    frames: Vec<Frame>,
+}
+
+impl FrameCollector {
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn into_frames(self) -> Vec<Frame> {
+        self.frames
+    }
+}
+
+impl Observer for FrameCollector {
+    fn observe(&mut self, state: &dyn View) {
+        self.frames.push(Frame::of(state));
+    }
+}
+
+/// Writes every tick to `frame_NNNNN.txt` in a directory, with its caption as the first
+/// line. Writing stops at the first error, which [`FrameWriter::finish`] reports.
+#[derive(Debug)]
+pub struct FrameWriter {
+    dir: PathBuf,
+    written: usize,
+    error: Option<String>,
+}
+
+impl FrameWriter {
+    pub fn new(dir: &Path) -> Result<Self, String> {
+        fs::create_dir_all(dir).map_err(|e| format!("cannot create `{}`: {e}", dir.display()))?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            written: 0,
+            error: None,
+        })
+    }
+
+    /// The number of frames written.
+    pub fn finish(self) -> Result<usize, String> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.written),
+        }
+    }
+}
+
+impl Observer for FrameWriter {
+    fn observe(&mut self, state: &dyn View) {
+        if self.error.is_some() {
+            return;
+        }
+        let path = self.dir.join(format!("frame_{:05}.txt", self.written));
+        let contents = match state.caption() {
+            Some(caption) => format!("{caption}\n{state}\n"),
+            None => format!("{state}\n"),
+        };
+        match fs::write(&path, contents) {
+            Ok(()) => self.written += 1,
+            Err(e) => self.error = Some(format!("cannot write `{}`: {e}", path.display())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod observe_tests {
+    use std::{
+        env,
+        fmt::{self, Formatter},
+    };
+
+    use super::*;
+
+    struct Counter {
+        value: usize,
+    }
+
+    impl Display for Counter {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", "#".repeat(self.value))
+        }
+    }
+
+    impl View for Counter {
+        fn caption(&self) -> Option<String> {
+            (self.value > 0).then(|| format!("add {}", self.value))
+        }
+    }
+
+    fn count<O: Observer + ?Sized>(to: usize, observer: &mut O) -> usize {
+        let mut counter = Counter { value: 0 };
+        observer.observe(&counter);
+        while counter.value < to {
+            counter.value += 1;
+            observer.observe(&counter);
+        }
+        counter.value
+    }
+
+    #[test]
+    fn test_frame_collector() {
+        let mut collector = FrameCollector::default();
+        assert_eq!(count(2, &mut collector), 2);
+        assert_eq!(
+            collector.into_frames(),
+            vec![
+                Frame {
+                    caption: None,
+                    text: "".to_string()
+                },
+                Frame {
+                    caption: Some("add 1".to_string()),
+                    text: "#".to_string()
+                },
+                Frame {
+                    caption: Some("add 2".to_string()),
+                    text: "##".to_string()
+                },
+            ]
+        );
+        assert_eq!(count(2, &mut ()), 2);
+    }
+
+    #[test]
+    fn test_erased_observer() {
+        let mut collector = FrameCollector::default();
+        let observer: &mut dyn Observer = &mut collector;
+        count(3, observer);
+        assert_eq!(collector.frames().len(), 4);
+        assert_eq!(collector.frames()[3].text, "###");
+    }
+
+    #[test]
+    fn test_pair_observer() {
+        let mut pair = (FrameCollector::default(), FrameCollector::default());
+        count(1, &mut pair);
+        assert_eq!(pair.0.frames().len(), 2);
+        assert_eq!(pair.0.frames(), pair.1.frames());
+    }
+
+    #[test]
+    fn test_frame_writer() {
+        let dir = env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let mut writer = FrameWriter::new(&dir).unwrap();
+        count(1, &mut writer);
+        assert_eq!(writer.finish(), Ok(2));
+        assert_eq!(
+            fs::read_to_string(dir.join("frame_00000.txt")).unwrap(),
+            "\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("frame_00001.txt")).unwrap(),
+            "add 1\n#\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,226 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// A constant of a puzzle that can be overridden, with the value the puzzle text gives
+/// and the range of values the solver accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: u64,
+    pub help: &'static str,
+    pub min: u64,
+    pub max: u64,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: u64, help: &'static str) -> Self {
+        Self {
+            name,
+            default,
+            help,
+            min: 0,
+            max: u64::MAX,
+        }
+    }
+
+    pub const fn range(self, min: u64, max: u64) -> Self {
+        Self { min, max, ..self }
+    }
+
+    /// Checks that `value` is within the range of this parameter.
+    pub fn check(&self, value: u64) -> Result<(), String> {
+        if (self.min..=self.max).contains(&value) {
+            return Ok(());
+        }
+        let expected = match self.max {
+            u64::MAX => format!("a number from {}", self.min),
+            max => format!("a number from {} to {max}", self.min),
+        };
+        Err(format!(
+            "invalid value `{value}` of parameter `{}`, expected {expected}",
+            self.name
+        ))
+    }
+}
+
+/// Values that replace constants of a puzzle, such as the size of its grid, so that
+/// it can be solved for an example or a variant of the real input. Kept sorted by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: Vec<(String, u64)>,
+}
+
+impl Params {
+    pub fn from_pairs(pairs: &[(&str, u64)]) -> Self {
+        let mut params = Self::default();
+        for &(name, value) in pairs {
+            params.set(name, value);
+        }
+        params
+    }
+
+    pub fn set(&mut self, name: &str, value: u64) {
+        match self.values.binary_search_by(|(n, _)| n.as_str().cmp(name)) {
+            Ok(index) => self.values[index].1 = value,
+            Err(index) => self.values.insert(index, (name.to_string(), value)),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<u64> {
+        self.values
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|&(_, value)| value)
+    }
+
+    /// The value of `name`, or `default` if it is not overridden.
+    pub fn get_or(&self, name: &str, default: u64) -> u64 {
+        self.get(name).unwrap_or(default)
+    }
+
+    /// The value of `param`, or its default if it is not overridden.
+    pub fn value(&self, param: &Param) -> u64 {
+        self.get_or(param.name, param.default)
+    }
+
+    /// Overrides these values with those of `other`.
+    pub fn merge(&mut self, other: &Params) {
+        for (name, value) in &other.values {
+            self.set(name, *value);
+        }
+    }
+
+    /// Checks that every value overrides one of `declared` and is within its range.
+    pub fn check(&self, declared: &[Param]) -> Result<(), String> {
+        let Some((name, _)) = self
+            .values
+            .iter()
+            .find(|(name, _)| declared.iter().all(|param| param.name != name))
+        else {
+            return declared
+                .iter()
+                .try_for_each(|param| param.check(self.value(param)));
+        };
+        match declared.is_empty() {
+            true => Err(format!("unknown parameter `{name}`, there are none")),
+            false => Err(format!(
+                "unknown parameter `{name}`, expected one of {}",
+                declared
+                    .iter()
+                    .map(|param| param.name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+    }
+}
+
+impl FromStr for Params {
+    type Err = String;
+
+    /// Parses comma separated `name=value` pairs, such as `size=7,bytes=12`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+        for pair in s.split(',').filter(|pair| !pair.trim().is_empty()) {
+            let (name, value) = pair
+                .split_once('=')
+                .ok_or(format!("invalid parameter `{pair}`, expected `name=value`"))?;
+            let value = value.trim().parse().map_err(|_| {
+                format!("invalid value `{value}` of parameter `{name}`, expected a number")
+            })?;
+            params.set(name.trim(), value);
+        }
+        Ok(params)
+    }
+}
+
+impl Display for Params {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let pairs = self
+            .values
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>();
+        write!(f, "{}", pairs.join(","))
+    }
+}
+
+#[cfg(test)]
+mod params_tests {
+    use super::*;
+
+    #[test]
+    fn test_params() {
+        let mut params = Params::from_pairs(&[("size", 7), ("bytes", 12)]);
+        assert_eq!(params.get("size"), Some(7));
+        assert_eq!(params.get_or("bytes", 1024), 12);
+        assert_eq!(params.get_or("threshold", 100), 100);
+        params.set("size", 71);
+        assert_eq!(params.get("size"), Some(71));
+        assert_eq!(Params::default().get("size"), None);
+    }
+
+    #[test]
+    fn test_declared_params() {
+        let size = Param::new("size", 71, "width and height of the grid");
+        let bytes = Param::new("bytes", 1024, "bytes fallen before escaping");
+        let mut params = "size=7".parse::<Params>().unwrap();
+        assert_eq!(params.value(&size), 7);
+        assert_eq!(params.value(&bytes), 1024);
+        params.merge(&Params::from_pairs(&[("bytes", 12), ("size", 9)]));
+        assert_eq!(params, Params::from_pairs(&[("size", 9), ("bytes", 12)]));
+        assert_eq!(params.check(&[size, bytes]), Ok(()));
+        assert_eq!(
+            params.check(&[size]),
+            Err("unknown parameter `bytes`, expected one of size".to_string())
+        );
+        assert_eq!(
+            params.check(&[]),
+            Err("unknown parameter `bytes`, there are none".to_string())
+        );
+    }
+
+    #[test]
+    fn test_param_range() {
+        let size = Param::new("size", 71, "width and height of the grid").range(1, 100);
+        let bytes = Param::new("bytes", 1024, "bytes fallen before escaping").range(1, u64::MAX);
+        assert_eq!(size.check(100), Ok(()));
+        assert_eq!(
+            Params::from_pairs(&[("size", 7)]).check(&[size, bytes]),
+            Ok(())
+        );
+        assert_eq!(
+            Params::from_pairs(&[("size", 0)]).check(&[size, bytes]),
+            Err(
+                "invalid value `0` of parameter `size`, expected a number from 1 to 100"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            Params::from_pairs(&[("bytes", 0)]).check(&[size, bytes]),
+            Err("invalid value `0` of parameter `bytes`, expected a number from 1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_params() {
+        assert_eq!(
+            "size=7, bytes=12".parse(),
+            Ok(Params::from_pairs(&[("size", 7), ("bytes", 12)]))
+        );
+        assert_eq!("".parse(), Ok(Params::default()));
+        let params = Params::from_pairs(&[("size", 7), ("bytes", 12)]);
+        assert_eq!(params.to_string(), "bytes=12,size=7");
+        assert_eq!(params.to_string().parse(), Ok(params));
+        assert_eq!(
+            "size".parse::<Params>(),
+            Err("invalid parameter `size`, expected `name=value`".to_string())
+        );
+        assert_eq!(
+            "size=-1".parse::<Params>(),
+            Err("invalid value `-1` of parameter `size`, expected a number".to_string())
+        );
+    }
+}
@@ -0,0 +1,422 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    iter,
+    str::FromStr,
+};
+
+/// A malformed puzzle input, with the 1-based line and column of the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    expected: String,
+    found: String,
+}
+
+fn describe(found: &str) -> String {
+    match found.chars().next() {
+        None => "end of input".to_string(),
+        Some(_) if found.trim().is_empty() => format!("{found:?}"),
+        Some(_) => format!("`{found}`"),
+    }
+}
+
+/// The byte offset of `fragment` inside `text`, or 0 if it is not a slice of it.
+fn offset(text: &str, fragment: &str) -> usize {
+    (fragment.as_ptr() as usize)
+        .checked_sub(text.as_ptr() as usize)
+        .filter(|&offset| offset <= text.len())
+        .unwrap_or(0)
+}
+
+/// The 1-based line and column where `fragment` starts inside `text`.
+fn position(text: &str, fragment: &str) -> (usize, usize) {
+    let before = text.get(..offset(text, fragment)).unwrap_or_default();
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .count()
+        + 1;
+    (line, column)
+}
+
+impl ParseError {
+    /// An error at the very start of the text being parsed.
+    pub fn new(expected: impl Into<String>, found: &str) -> Self {
+        Self {
+            line: 1,
+            column: 1,
+            expected: expected.into(),
+            found: describe(found),
+        }
+    }
+
+    /// An error about `fragment`, a slice of `text`, located where it starts.
+    pub fn at(text: &str, fragment: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = position(text, fragment);
+        let at_line_end = fragment.is_empty()
+            && text
+                .get(offset(text, fragment)..)
+                .is_some_and(|rest| rest.starts_with(['\r', '\n']));
+        Self {
+            line,
+            column,
+            expected: expected.into(),
+            found: match at_line_end {
+                true => "end of line".to_string(),
+                false => describe(fragment),
+            },
+        }
+    }
+
+    /// Rebases an error raised while parsing `part` onto `text`, of which `part` is a slice.
+    pub fn within(mut self, text: &str, part: &str) -> Self {
+        let (line, column) = position(text, part);
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `fragment`, a slice of `text`, reporting `expected` at its position on failure.
+pub fn parse_at<T: FromStr>(text: &str, fragment: &str, expected: &str) -> Result<T, ParseError> {
+    fragment
+        .parse()
+        .map_err(|_| ParseError::at(text, fragment, expected))
+}
+
+/// Splits `fragment`, a slice of `text`, around the first `delimiter`.
+pub fn split_at<'a>(
+    text: &str,
+    fragment: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    fragment
+        .split_once(delimiter)
+        .ok_or_else(|| ParseError::at(text, fragment, format!("`{delimiter}`")))
+}
+
+/// The trimmed, non-blank lines of `fragment`.
+pub fn lines(fragment: &str) -> impl Iterator<Item = &str> {
+    fragment.lines().map(str::trim).filter(|l| !l.is_empty())
+}
+
+/// Parses every non-blank line of `fragment`, a slice of `text`, rebasing errors onto `text`.
+pub fn parse_lines<T, C>(text: &str, fragment: &str) -> Result<C, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+    C: FromIterator<T>,
+{
+    lines(fragment)
+        .map(|l| l.parse().map_err(|e: ParseError| e.within(text, l)))
+        .collect()
+}
+
+/// The trimmed items of a `separator`-separated list, none if `fragment` is blank.
+pub fn list<'a>(fragment: &'a str, separator: &'a str) -> impl Iterator<Item = &'a str> {
+    let fragment = fragment.trim();
+    fragment
+        .split(separator)
+        .map(str::trim)
+        .take_while(move |_| !fragment.is_empty())
+}
+
+/// Parses every item of a `separator`-separated list, reporting `expected` for the bad ones.
+pub fn parse_list<T: FromStr, C: FromIterator<T>>(
+    text: &str,
+    fragment: &str,
+    separator: &str,
+    expected: &str,
+) -> Result<C, ParseError> {
+    list(fragment, separator)
+        .map(|item| parse_at(text, item, expected))
+        .collect()
+}
+
+/// The trimmed, blank-line separated sections of `fragment`.
+pub fn sections(fragment: &str) -> impl Iterator<Item = &str> {
+    let mut sections = vec![];
+    let mut current: Option<(usize, usize)> = None;
+    let mut start = 0;
+    for line in fragment.split_inclusive('\n') {
+        let end = start + line.len();
+        match (line.trim().is_empty(), current) {
+            (false, section) => current = Some((section.map_or(start, |(first, _)| first), end)),
+            (true, Some((first, last))) => {
+                sections.push(fragment[first..last].trim());
+                current = None;
+            }
+            (true, None) => (),
+        }
+        start = end;
+    }
+    sections.extend(current.map(|(first, last)| fragment[first..last].trim()));
+    sections.into_iter()
+}
+
+/// Splits `fragment`, a slice of `text`, at its first blank line, trimming both halves.
+pub fn split_sections<'a>(text: &str, fragment: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+    let mut parts = sections(fragment);
+    match (parts.next(), parts.next()) {
+        (Some(first), Some(second)) => Ok((first, fragment[offset(fragment, second)..].trim())),
+        _ => Err(ParseError::at(
+            text,
+            &fragment[fragment.len()..],
+            "a blank line between sections",
+        )),
+    }
+}
+
+fn integer_tokens(fragment: &str) -> impl Iterator<Item = &str> {
+    let bytes = fragment.as_bytes();
+    let mut i = 0;
+    iter::from_fn(move || {
+        while i < bytes.len() {
+            let start = i;
+            let signed = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+            i += 1;
+            if signed || bytes[start].is_ascii_digit() {
+                while bytes.get(i).is_some_and(u8::is_ascii_digit) {
+                    i += 1;
+                }
+                return Some(&fragment[start..i]);
+            }
+        }
+        None
+    })
+}
+
+/// Every integer in `fragment`, a slice of `text`, skipping the text around them.
+/// A `-` directly before the digits makes the integer negative.
+pub fn integers<T: FromStr>(text: &str, fragment: &str) -> Result<Vec<T>, ParseError> {
+    integer_tokens(fragment)
+        .map(|n| parse_at(text, n, "an integer"))
+        .collect()
+}
+
+/// Like [`integers`], but `fragment` must hold exactly `N` of them.
+pub fn integers_n<T: FromStr, const N: usize>(
+    text: &str,
+    fragment: &str,
+) -> Result<[T; N], ParseError> {
+    let mut tokens = integer_tokens(fragment);
+    let values = tokens
+        .by_ref()
+        .take(N)
+        .map(|n| parse_at(text, n, "an integer"))
+        .collect::<Result<Vec<T>, _>>()?;
+    if let Some(extra) = tokens.next() {
+        return Err(ParseError::at(text, extra, format!("only {N} integers")));
+    }
+    values
+        .try_into()
+        .map_err(|_| ParseError::at(text, &fragment[fragment.len()..], format!("{N} integers")))
+}
+
+/// Splits a `key<separator>value` line, a slice of `text`, trimming both sides.
+pub fn key_value<'a>(
+    text: &str,
+    line: &'a str,
+    separator: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    let (key, value) = split_at(text, line, separator)?;
+    match key.trim() {
+        "" => Err(ParseError::at(text, line, "a key")),
+        key => Ok((key, value.trim())),
+    }
+}
+
+/// The trimmed rest of `line`, a slice of `text`, after `label`.
+pub fn labelled<'a>(text: &str, line: &'a str, label: &str) -> Result<&'a str, ParseError> {
+    line.trim_start()
+        .strip_prefix(label)
+        .map(str::trim)
+        .ok_or_else(|| ParseError::at(text, line, format!("`{label}`")))
+}
+
+/// The values of a block of non-blank lines that start with `labels`, in that order.
+pub fn labelled_block<'a, const N: usize>(
+    text: &str,
+    block: &'a str,
+    labels: [&str; N],
+) -> Result<[&'a str; N], ParseError> {
+    let mut lines = lines(block);
+    let mut values = [""; N];
+    for (value, label) in values.iter_mut().zip(labels) {
+        let line = lines.next().unwrap_or(&block[block.len()..]);
+        *value = labelled(text, line, label)?;
+    }
+    match lines.next() {
+        Some(extra) => Err(ParseError::at(text, extra, "the end of the block")),
+        None => Ok(values),
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    const TEXT: &str = "3   4\n4   x\n\n5";
+
+    #[derive(Debug, PartialEq)]
+    struct Number(u32);
+
+    impl FromStr for Number {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            parse_at(s, s, "a number").map(Number)
+        }
+    }
+
+    #[test]
+    fn test_position() {
+        assert_eq!(position(TEXT, TEXT), (1, 1));
+        assert_eq!(position(TEXT, &TEXT[4..]), (1, 5));
+        assert_eq!(position(TEXT, &TEXT[10..11]), (2, 5));
+        assert_eq!(position(TEXT, &TEXT[TEXT.len()..]), (4, 2));
+        assert_eq!(position(TEXT, "elsewhere"), (1, 1));
+    }
+
+    #[test]
+    fn test_parse_at() {
+        let line = TEXT.lines().nth(1).unwrap();
+        let error = parse_at::<u32>(TEXT, &line[4..], "a number").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected a number, found `x`"
+        );
+        assert_eq!(parse_at::<u32>(TEXT, &TEXT[..1], "a number"), Ok(3));
+    }
+
+    #[test]
+    fn test_split_at() {
+        assert_eq!(split_at(TEXT, &TEXT[..5], "   "), Ok(("3", "4")));
+        let error = split_at(TEXT, &TEXT[13..], ",").unwrap_err();
+        assert_eq!((error.line(), error.column()), (4, 1));
+        assert_eq!(error.expected(), "`,`");
+        assert_eq!(error.found(), "`5`");
+    }
+
+    #[test]
+    fn test_within() {
+        let line = TEXT.lines().nth(1).unwrap();
+        let error = ParseError::at(line, &line[4..], "a digit").within(TEXT, line);
+        assert_eq!((error.line(), error.column()), (2, 5));
+        let error = ParseError::new("a digit", "").within(TEXT, &TEXT[12..]);
+        assert_eq!((error.line(), error.column()), (3, 1));
+        assert_eq!(error.found(), "end of input");
+        let error = ParseError::at(TEXT, &TEXT[5..5], "a digit");
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 6: expected a digit, found end of line"
+        );
+    }
+
+    #[test]
+    fn test_lines_and_lists() {
+        assert_eq!(lines(TEXT).collect::<Vec<_>>(), vec!["3   4", "4   x", "5"]);
+        assert_eq!(
+            list(" a, b ,c", ",").collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(list("  ", ",").count(), 0);
+        let program = "Program: 0,3,x";
+        let error = parse_list::<u8, Vec<_>>(program, &program[9..], ",", "an opcode").unwrap_err();
+        assert_eq!((error.column(), error.found()), (14, "`x`"));
+        let text = "1\n\n 2\n x";
+        let numbers: Result<Vec<Number>, _> = parse_lines(text, &text[..6]);
+        assert_eq!(numbers, Ok(vec![Number(1), Number(2)]));
+        let error = parse_lines::<Number, Vec<_>>(text, text).unwrap_err();
+        assert_eq!((error.line(), error.column()), (4, 2));
+    }
+
+    #[test]
+    fn test_sections() {
+        let text = "a\nb\n\n \n\nc\r\n\r\nd\n";
+        assert_eq!(sections(text).collect::<Vec<_>>(), vec!["a\nb", "c", "d"]);
+        assert_eq!(sections("\n\n").count(), 0);
+        assert_eq!(split_sections(text, text), Ok(("a\nb", "c\r\n\r\nd")));
+        let error = split_sections(TEXT, &TEXT[..11]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: expected a blank line between sections, found end of line"
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        let line = "Button A: X+94, Y-34 p=0,4";
+        assert_eq!(integers::<i64>(line, line), Ok(vec![94, -34, 0, 4]));
+        assert_eq!(integers_n::<i64, 2>(line, &line[10..20]), Ok([94, -34]));
+        let error = integers_n::<i64, 2>(line, line).unwrap_err();
+        assert_eq!((error.column(), error.found()), (24, "`0`"));
+        let error = integers_n::<i64, 3>(line, &line[..20]).unwrap_err();
+        assert_eq!(error.expected(), "3 integers");
+        let error = integers::<u8>(line, line).unwrap_err();
+        assert_eq!((error.column(), error.expected()), (18, "an integer"));
+        assert_eq!(integers::<u8>("a-b - 1", "a-b - 1"), Ok(vec![1]));
+    }
+
+    #[test]
+    fn test_key_value() {
+        let line = "Register A: 729";
+        assert_eq!(key_value(line, line, ":"), Ok(("Register A", "729")));
+        assert_eq!(
+            key_value(": 1", ": 1", ":").unwrap_err().expected(),
+            "a key"
+        );
+        assert_eq!(labelled(line, line, "Register A:"), Ok("729"));
+        let error = labelled(line, line, "Program:").unwrap_err();
+        assert_eq!(error.expected(), "`Program:`");
+    }
+
+    #[test]
+    fn test_labelled_block() {
+        let block = "Button A: X+94\nPrize: X=8400\n";
+        assert_eq!(
+            labelled_block(block, block, ["Button A:", "Prize:"]),
+            Ok(["X+94", "X=8400"])
+        );
+        let error = labelled_block(block, block, ["Button A:", "Button B:"]).unwrap_err();
+        assert_eq!((error.line(), error.expected()), (2, "`Button B:`"));
+        let error = labelled_block(block, block, ["Button A:", "Prize:", "Score:"]).unwrap_err();
+        assert_eq!((error.line(), error.found()), (3, "end of input"));
+        let error = labelled_block(block, block, ["Button A:"]).unwrap_err();
+        assert_eq!(error.expected(), "the end of the block");
+    }
+}
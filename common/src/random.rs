@@ -0,0 +1,106 @@
+/// A small pseudorandom generator (SplitMix64). The same seed always gives the same
+/// numbers, so that whatever it generates can be reproduced.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from 0 to `bound` excluded, which must not be zero.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "cannot pick a number below 0");
+        self.below_u64(bound as u64) as usize
+    }
+
+    /// A number from `low` to `high` included.
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "cannot pick a number between {low} and {high}");
+        let span = high.abs_diff(low);
+        match span.checked_add(1) {
+            Some(bound) => low.wrapping_add(self.below_u64(bound) as i64),
+            None => self.next_u64() as i64,
+        }
+    }
+
+    fn below_u64(&mut self, bound: u64) -> u64 {
+        // Rejects the top of the range that would favour the small numbers.
+        let limit = u64::MAX - u64::MAX % bound;
+        loop {
+            let n = self.next_u64();
+            if n < limit {
+                return n % bound;
+            }
+        }
+    }
+
+    /// True with the given `probability`, from 0 to 1.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64) < probability
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod random_tests {
+    use super::*;
+
+    #[test]
+    fn test_reproducible() {
+        let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+        let numbers = (0..10).map(|_| a.next_u64()).collect::<Vec<_>>();
+        assert_eq!(numbers, (0..10).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(
+            numbers,
+            (0..10).map(|_| Rng::new(8).next_u64()).collect::<Vec<_>>()
+        );
+        assert_eq!(Rng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut rng = Rng::new(2024);
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            seen[rng.below(6)] = true;
+            let n = rng.between(-3, 3);
+            assert!((-3..=3).contains(&n));
+        }
+        assert!(seen.iter().all(|&seen| seen));
+        assert_eq!(rng.between(5, 5), 5);
+        rng.between(i64::MIN, i64::MAX);
+        assert!(!rng.chance(0.0));
+        assert!(rng.chance(1.0));
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = Rng::new(1);
+        let mut items = (0..20).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+        assert!([1, 2, 3].contains(rng.pick(&[1, 2, 3])));
+    }
+}
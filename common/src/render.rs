@@ -0,0 +1,484 @@
+use std::{
+    fs::{self, File},
+    io::BufWriter,
+    iter,
+    path::{Path, PathBuf},
+};
+
+use crate::observe::{Observer, View};
+
+mod gif;
+mod png;
+
+pub use gif::GifEncoder;
+
+pub const BACKGROUND: [u8; 3] = [15, 15, 35];
+pub const WALL: [u8; 3] = [128, 128, 128];
+pub const BOX: [u8; 3] = [180, 120, 60];
+pub const ROBOT: [u8; 3] = [230, 50, 50];
+pub const VISITED: [u8; 3] = [230, 200, 60];
+pub const COUNT: [u8; 3] = [60, 200, 80];
+pub const OTHER: [u8; 3] = [230, 230, 230];
+
+/// The colours of the cells of a grid, by the character that draws them. Cells without
+/// a colour of their own get the one for everything else.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<[u8; 3]>,
+    cells: Vec<(char, u8)>,
+    other: u8,
+}
+
+impl Default for Palette {
+    /// The colours of the maps of the puzzles: walls, boxes, the robot or guard facing
+    /// any direction, visited cells and the counts of day 14.
+    fn default() -> Self {
+        Self::new(BACKGROUND, OTHER)
+            .with(".", BACKGROUND)
+            .with("#", WALL)
+            .with("O[]", BOX)
+            .with("@^>v<", ROBOT)
+            .with("X", VISITED)
+            .with("123456789", COUNT)
+    }
+}
+
+impl Palette {
+    /// A palette where spaces, and the padding of short lines, are `background`.
+    pub fn new(background: [u8; 3], other: [u8; 3]) -> Self {
+        let palette = Self {
+            colors: vec![background],
+            cells: vec![],
+            other: 0,
+        };
+        let mut palette = palette.with(" ", background);
+        palette.other = palette.color_index(other);
+        palette
+    }
+
+    /// Draws each of `cells` with `color`, replacing the colour they had.
+    pub fn with(mut self, cells: &str, color: [u8; 3]) -> Self {
+        let index = self.color_index(color);
+        for cell in cells.chars() {
+            self.cells.retain(|&(c, _)| c != cell);
+            self.cells.push((cell, index));
+        }
+        self
+    }
+
+    fn color_index(&mut self, color: [u8; 3]) -> u8 {
+        match self.colors.iter().position(|&c| c == color) {
+            Some(index) => index as u8,
+            None => {
+                assert!(self.colors.len() < 256, "a palette has at most 256 colours");
+                self.colors.push(color);
+                (self.colors.len() - 1) as u8
+            }
+        }
+    }
+
+    pub fn colors(&self) -> &[[u8; 3]] {
+        &self.colors
+    }
+
+    /// The index in [`Palette::colors`] of the colour of `cell`.
+    pub fn index(&self, cell: char) -> u8 {
+        self.cells
+            .iter()
+            .find(|&&(c, _)| c == cell)
+            .map_or(self.other, |&(_, index)| index)
+    }
+}
+
+/// The number of columns and rows of a grid drawn as text.
+pub fn text_size(text: &str) -> (usize, usize) {
+    let columns = text.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+    (columns, text.lines().count())
+}
+
+/// A picture of a grid, with each cell a square of `scale` pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    colors: Vec<[u8; 3]>,
+    indices: Vec<u8>,
+}
+
+impl Image {
+    pub fn from_text(text: &str, palette: &Palette, scale: usize) -> Self {
+        Self::from_cells(text, palette, scale, text_size(text))
+    }
+
+    /// Draws the first `columns` and `rows` of the grid, padding it with the background,
+    /// so that every frame of a simulation has the same size.
+    pub fn from_cells(
+        text: &str,
+        palette: &Palette,
+        scale: usize,
+        (columns, rows): (usize, usize),
+    ) -> Self {
+        let background = palette.index(' ');
+        let mut indices = Vec::with_capacity(columns * rows * scale * scale);
+        let mut lines = text.lines();
+        for _ in 0..rows {
+            let mut cells = lines.next().unwrap_or_default().chars();
+            let row = (0..columns)
+                .flat_map(|_| {
+                    let index = cells.next().map_or(background, |c| palette.index(c));
+                    iter::repeat_n(index, scale)
+                })
+                .collect::<Vec<_>>();
+            for _ in 0..scale {
+                indices.extend(&row);
+            }
+        }
+        Self {
+            width: columns * scale,
+            height: rows * scale,
+            colors: palette.colors().to_vec(),
+            indices,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> [u8; 3] {
+        self.colors[self.indices[y * self.width + x] as usize]
+    }
+
+    fn indices(&self) -> &[u8] {
+        &self.indices
+    }
+
+    /// The image as a binary PPM, the `P6` format.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        for &index in &self.indices {
+            out.extend(self.colors[index as usize]);
+        }
+        out
+    }
+
+    /// The image as an 8-bit RGB PNG.
+    pub fn to_png(&self) -> Vec<u8> {
+        png::encode(self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Ppm,
+}
+
+impl ImageFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Ppm => "ppm",
+        }
+    }
+
+    pub fn encode(self, image: &Image) -> Vec<u8> {
+        match self {
+            ImageFormat::Png => image.to_png(),
+            ImageFormat::Ppm => image.to_ppm(),
+        }
+    }
+}
+
+/// Draws every tick to `frame_NNNNN.png` (or `.ppm`) in a directory, at the size of the
+/// first. Writing stops at the first error, which [`ImageWriter::finish`] reports.
+#[derive(Debug)]
+pub struct ImageWriter {
+    dir: PathBuf,
+    format: ImageFormat,
+    palette: Palette,
+    scale: usize,
+    size: Option<(usize, usize)>,
+    written: usize,
+    error: Option<String>,
+}
+
+impl ImageWriter {
+    pub fn new(dir: &Path, format: ImageFormat, scale: usize) -> Result<Self, String> {
+        fs::create_dir_all(dir).map_err(|e| format!("cannot create `{}`: {e}", dir.display()))?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            format,
+            palette: Palette::default(),
+            scale,
+            size: None,
+            written: 0,
+            error: None,
+        })
+    }
+
+    pub fn with_palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// The number of frames written.
+    pub fn finish(self) -> Result<usize, String> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.written),
+        }
+    }
+}
+
+impl Observer for ImageWriter {
+    fn observe(&mut self, state: &dyn View) {
+        if self.error.is_some() {
+            return;
+        }
+        let text = state.to_string();
+        let size = *self.size.get_or_insert_with(|| text_size(&text));
+        let image = Image::from_cells(&text, &self.palette, self.scale, size);
+        let path = self.dir.join(format!(
+            "frame_{:05}.{}",
+            self.written,
+            self.format.extension()
+        ));
+        match fs::write(&path, self.format.encode(&image)) {
+            Ok(()) => self.written += 1,
+            Err(e) => self.error = Some(format!("cannot write `{}`: {e}", path.display())),
+        }
+    }
+}
+
+/// Draws every tick as a frame of an animated GIF, at the size of the first. The file
+/// is created with the first frame and completed by [`GifWriter::finish`].
+pub struct GifWriter {
+    path: PathBuf,
+    palette: Palette,
+    scale: usize,
+    delay: u16,
+    size: Option<(usize, usize)>,
+    encoder: Option<GifEncoder<BufWriter<File>>>,
+    written: usize,
+    error: Option<String>,
+}
+
+impl GifWriter {
+    pub fn new(path: &Path, scale: usize) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            palette: Palette::default(),
+            scale,
+            delay: 10,
+            size: None,
+            encoder: None,
+            written: 0,
+            error: None,
+        }
+    }
+
+    pub fn with_palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Shows each frame for `delay` hundredths of a second, a tenth by default.
+    pub fn with_delay(mut self, delay: u16) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    fn error(&self, e: std::io::Error) -> String {
+        format!("cannot write `{}`: {e}", self.path.display())
+    }
+
+    fn add(&mut self, text: &str) -> Result<(), String> {
+        let size = *self.size.get_or_insert_with(|| text_size(text));
+        let image = Image::from_cells(text, &self.palette, self.scale, size);
+        if image.width() > u16::MAX as usize || image.height() > u16::MAX as usize {
+            return Err(format!(
+                "cannot write `{}`: {}x{} is too large for a GIF",
+                self.path.display(),
+                image.width(),
+                image.height()
+            ));
+        }
+        if self.encoder.is_none() {
+            let file = File::create(&self.path).map_err(|e| self.error(e))?;
+            let encoder = GifEncoder::new(
+                BufWriter::new(file),
+                image.width(),
+                image.height(),
+                self.palette.colors(),
+                self.delay,
+            )
+            .map_err(|e| self.error(e))?;
+            self.encoder = Some(encoder);
+        }
+        let result = self.encoder.as_mut().unwrap().add(&image);
+        result.map_err(|e| self.error(e))
+    }
+
+    /// The number of frames written. Without any, no file is created.
+    pub fn finish(self) -> Result<usize, String> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+        if let Some(encoder) = self.encoder {
+            encoder
+                .finish()
+                .map_err(|e| format!("cannot write `{}`: {e}", self.path.display()))?;
+        }
+        Ok(self.written)
+    }
+}
+
+impl Observer for GifWriter {
+    fn observe(&mut self, state: &dyn View) {
+        if self.error.is_some() {
+            return;
+        }
+        match self.add(&state.to_string()) {
+            Ok(()) => self.written += 1,
+            Err(e) => self.error = Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod render_tests {
+    use std::{
+        env,
+        fmt::{self, Display, Formatter},
+    };
+
+    use super::{gif::gif_tests::unlzw, png::png_tests::decode, *};
+
+    const MAP: &str = "##\n#@\n.O";
+
+    struct Grid(&'static str);
+
+    impl Display for Grid {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl View for Grid {}
+
+    #[test]
+    fn test_palette() {
+        let palette = Palette::default();
+        assert_eq!(palette.colors()[palette.index(' ') as usize], BACKGROUND);
+        assert_eq!(palette.index('.'), palette.index(' '));
+        assert_eq!(palette.colors()[palette.index('[') as usize], BOX);
+        assert_eq!(palette.colors()[palette.index('v') as usize], ROBOT);
+        assert_eq!(palette.colors()[palette.index('?') as usize], OTHER);
+        let palette = palette.with("#", BOX);
+        assert_eq!(palette.index('#'), palette.index('O'));
+    }
+
+    #[test]
+    fn test_from_text() {
+        let image = Image::from_text(MAP, &Palette::default(), 2);
+        assert_eq!((image.width(), image.height()), (4, 6));
+        assert_eq!(image.pixel(0, 0), WALL);
+        assert_eq!(image.pixel(3, 1), WALL);
+        assert_eq!(image.pixel(2, 2), ROBOT);
+        assert_eq!(image.pixel(3, 3), ROBOT);
+        assert_eq!(image.pixel(1, 4), BACKGROUND);
+        assert_eq!(image.pixel(2, 5), BOX);
+        let image = Image::from_cells("#\n##", &Palette::default(), 1, (3, 3));
+        let rows = (0..3)
+            .map(|y| (0..3).map(|x| image.pixel(x, y)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [
+                [WALL, BACKGROUND, BACKGROUND],
+                [WALL, WALL, BACKGROUND],
+                [BACKGROUND, BACKGROUND, BACKGROUND],
+            ]
+        );
+        let image = Image::from_cells(MAP, &Palette::default(), 1, (1, 1));
+        assert_eq!((image.width(), image.height()), (1, 1));
+    }
+
+    #[test]
+    fn test_ppm() {
+        let image = Image::from_text("#@", &Palette::default(), 1);
+        let mut expected = b"P6\n2 1\n255\n".to_vec();
+        expected.extend(WALL);
+        expected.extend(ROBOT);
+        assert_eq!(image.to_ppm(), expected);
+    }
+
+    #[test]
+    fn test_png() {
+        let image = Image::from_text(MAP, &Palette::default(), 3);
+        let (width, height, pixels) = decode(&image.to_png());
+        assert_eq!((width, height), (6, 9));
+        for y in 0..height {
+            for x in 0..width {
+                assert_eq!(pixels[y * width + x], image.pixel(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_gif() {
+        let palette = Palette::default();
+        let first = Image::from_text(MAP, &palette, 2);
+        let second = Image::from_text("##\n#.\n.@", &palette, 2);
+        let mut encoder = GifEncoder::new(vec![], 4, 6, palette.colors(), 5).unwrap();
+        encoder.add(&first).unwrap();
+        encoder.add(&second).unwrap();
+        let gif = encoder.finish().unwrap();
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(gif[6..10], [4, 0, 6, 0]);
+        assert_eq!(gif[10], 0xf2);
+        assert_eq!(gif[13..16], BACKGROUND);
+        assert_eq!(gif.last(), Some(&0x3b));
+        let header = 13 + 3 * 8 + 19;
+        let mut rest = &gif[header..];
+        for image in [first, second] {
+            assert_eq!(rest[..8], [0x21, 0xf9, 4, 0, 5, 0, 0, 0]);
+            assert_eq!(rest[8..19], [0x2c, 0, 0, 0, 0, 4, 0, 6, 0, 0, 3]);
+            let blocks = &rest[19..];
+            let mut length = 0;
+            while blocks[length] != 0 {
+                length += blocks[length] as usize + 1;
+            }
+            assert_eq!(unlzw(&blocks[..=length], 3), image.indices());
+            rest = &blocks[length + 1..];
+        }
+        assert_eq!(rest, [0x3b]);
+    }
+
+    #[test]
+    fn test_writers() {
+        let dir = env::temp_dir().join(format!("aoc-render-{}", std::process::id()));
+        let frames = [MAP, "##\n#.\n.@", "##\n#.\n.@O"];
+        let mut writer = ImageWriter::new(&dir, ImageFormat::Ppm, 1).unwrap();
+        let mut gif = GifWriter::new(&dir.join("animation.gif"), 1);
+        for frame in frames {
+            writer.observe(&Grid(frame));
+            gif.observe(&Grid(frame));
+        }
+        assert_eq!(writer.finish(), Ok(3));
+        assert_eq!(gif.finish(), Ok(3));
+        let last = fs::read(dir.join("frame_00002.ppm")).unwrap();
+        assert_eq!(
+            last,
+            Image::from_text("##\n#.\n.@", &Palette::default(), 1).to_ppm()
+        );
+        let animation = fs::read(dir.join("animation.gif")).unwrap();
+        assert_eq!(animation[6..10], [2, 0, 3, 0]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
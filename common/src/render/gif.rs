@@ -0,0 +1,240 @@
+use std::{collections::HashMap, io::Write};
+
+use super::Image;
+
+const MAX_CODES: u16 = 4096;
+const MAX_CODE_SIZE: u32 = 12;
+
+/// Packs LZW codes into the sub-blocks of at most 255 bytes of a GIF image.
+struct CodeWriter {
+    blocks: Vec<u8>,
+    block: Vec<u8>,
+    buffer: u32,
+    count: u32,
+}
+
+impl CodeWriter {
+    fn new() -> Self {
+        Self {
+            blocks: vec![],
+            block: Vec::with_capacity(255),
+            buffer: 0,
+            count: 0,
+        }
+    }
+
+    fn push(&mut self, byte: u8) {
+        self.block.push(byte);
+        if self.block.len() == 255 {
+            self.blocks.push(255);
+            self.blocks.append(&mut self.block);
+        }
+    }
+
+    fn write(&mut self, code: u16, bits: u32) {
+        self.buffer |= (code as u32) << self.count;
+        self.count += bits;
+        while self.count >= 8 {
+            self.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.push(self.buffer as u8);
+        }
+        if !self.block.is_empty() {
+            self.blocks.push(self.block.len() as u8);
+            self.blocks.append(&mut self.block);
+        }
+        self.blocks.push(0);
+        self.blocks
+    }
+}
+
+/// The smallest code size that fits `colors` indices, which GIF wants at least 2.
+pub(crate) fn min_code_size(colors: usize) -> u32 {
+    (usize::BITS - colors.saturating_sub(1).leading_zeros()).max(2)
+}
+
+/// Compresses palette indices with variable length LZW codes, starting over with a
+/// clear code whenever the 4096 codes are used up.
+pub(crate) fn lzw(indices: &[u8], min_code_size: u32) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+    let mut out = CodeWriter::new();
+    let mut table = HashMap::new();
+    let mut next_code = end + 1;
+    let mut code_size = min_code_size + 1;
+    out.write(clear, code_size);
+    let mut indices = indices.iter();
+    let Some(&first) = indices.next() else {
+        out.write(end, code_size);
+        return out.finish();
+    };
+    let mut prefix = first as u16;
+    for &index in indices {
+        if let Some(&code) = table.get(&(prefix, index)) {
+            prefix = code;
+            continue;
+        }
+        out.write(prefix, code_size);
+        table.insert((prefix, index), next_code);
+        next_code += 1;
+        if next_code > 1 << code_size && code_size < MAX_CODE_SIZE {
+            code_size += 1;
+        }
+        if next_code == MAX_CODES {
+            out.write(clear, code_size);
+            table.clear();
+            next_code = end + 1;
+            code_size = min_code_size + 1;
+        }
+        prefix = index as u16;
+    }
+    out.write(prefix, code_size);
+    out.write(end, code_size);
+    out.finish()
+}
+
+/// Writes images of the same size and palette as the frames of a looping animation.
+pub struct GifEncoder<W: Write> {
+    out: W,
+    width: usize,
+    height: usize,
+    min_code_size: u32,
+    delay: u16,
+}
+
+impl<W: Write> GifEncoder<W> {
+    /// Writes the header, with the colours padded to a power of two, and shows each frame
+    /// for `delay` hundredths of a second.
+    pub fn new(
+        mut out: W,
+        width: usize,
+        height: usize,
+        colors: &[[u8; 3]],
+        delay: u16,
+    ) -> std::io::Result<Self> {
+        let min_code_size = min_code_size(colors.len());
+        let table_size = min_code_size - 1;
+        out.write_all(b"GIF89a")?;
+        out.write_all(&(width as u16).to_le_bytes())?;
+        out.write_all(&(height as u16).to_le_bytes())?;
+        out.write_all(&[0x80 | 0x70 | table_size as u8, 0, 0])?;
+        for i in 0..1 << min_code_size {
+            out.write_all(colors.get(i).unwrap_or(&[0, 0, 0]))?;
+        }
+        out.write_all(&[0x21, 0xff, 11])?;
+        out.write_all(b"NETSCAPE2.0")?;
+        out.write_all(&[3, 1, 0, 0, 0])?;
+        Ok(Self {
+            out,
+            width,
+            height,
+            min_code_size,
+            delay,
+        })
+    }
+
+    pub fn add(&mut self, image: &Image) -> std::io::Result<()> {
+        assert_eq!(
+            (image.width(), image.height()),
+            (self.width, self.height),
+            "every frame of a GIF has the size of the first"
+        );
+        self.out.write_all(&[0x21, 0xf9, 4, 0])?;
+        self.out.write_all(&self.delay.to_le_bytes())?;
+        self.out.write_all(&[0, 0])?;
+        self.out.write_all(&[0x2c, 0, 0, 0, 0])?;
+        self.out.write_all(&(self.width as u16).to_le_bytes())?;
+        self.out.write_all(&(self.height as u16).to_le_bytes())?;
+        self.out.write_all(&[0, self.min_code_size as u8])?;
+        self.out
+            .write_all(&lzw(image.indices(), self.min_code_size))
+    }
+
+    pub fn finish(mut self) -> std::io::Result<W> {
+        self.out.write_all(&[0x3b])?;
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod gif_tests {
+    use super::*;
+
+    /// The palette indices of LZW codes as [`lzw`] writes them.
+    pub(crate) fn unlzw(blocks: &[u8], min_code_size: u32) -> Vec<u8> {
+        let mut bytes: Vec<u8> = vec![];
+        let mut rest = blocks;
+        while rest[0] != 0 {
+            let length = rest[0] as usize;
+            bytes.extend(&rest[1..=length]);
+            rest = &rest[length + 1..];
+        }
+        assert_eq!(rest, [0]);
+        let clear = 1u16 << min_code_size;
+        let mut position = 0;
+        let mut code_size = min_code_size + 1;
+        let mut table: Vec<Vec<u8>> = vec![];
+        let mut previous: Option<Vec<u8>> = None;
+        let mut out = vec![];
+        loop {
+            let code = (0..code_size as usize).fold(0u16, |code, i| {
+                let bit = bytes[(position + i) / 8] >> ((position + i) % 8) & 1;
+                code | (bit as u16) << i
+            });
+            position += code_size as usize;
+            if code == clear {
+                table = (0..clear).map(|i| vec![i as u8]).collect();
+                table.extend([vec![], vec![]]);
+                code_size = min_code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return out;
+            }
+            let entry = match (table.get(code as usize), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(previous)) => [&previous[..], &previous[..1]].concat(),
+                (None, None) => panic!("unknown code {code}"),
+            };
+            if let Some(previous) = previous {
+                table.push([&previous[..], &entry[..1]].concat());
+            }
+            if table.len() == 1 << code_size && code_size < MAX_CODE_SIZE {
+                code_size += 1;
+            }
+            out.extend(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_min_code_size() {
+        assert_eq!(min_code_size(1), 2);
+        assert_eq!(min_code_size(4), 2);
+        assert_eq!(min_code_size(5), 3);
+        assert_eq!(min_code_size(7), 3);
+        assert_eq!(min_code_size(256), 8);
+    }
+
+    #[test]
+    fn test_lzw() {
+        for (indices, bits) in [
+            (vec![], 2),
+            (vec![1], 2),
+            (vec![0, 1, 0, 1, 0, 1, 0, 1, 2, 3], 2),
+            (vec![6; 10000], 3),
+            ((0..40000).map(|i| (i * 7 % 13) as u8).collect(), 4),
+            ((0..100000u32).map(|i| (i ^ (i >> 3)) as u8).collect(), 8),
+        ] {
+            assert_eq!(unlzw(&lzw(&indices, bits), bits), indices);
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use super::Image;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const WINDOW: usize = 32768;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+const HASH_BITS: u32 = 15;
+
+const LENGTH_BASE: [usize; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u32; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [usize; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u32; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xedb8_8320,
+                _ => crc >> 1,
+            };
+        }
+    }
+    !crc
+}
+
+pub(crate) fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+/// Packs codes into bytes from the least significant bit, as deflate wants.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u64,
+    count: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, bits: u32) {
+        self.buffer |= (value as u64) << self.count;
+        self.count += bits;
+        while self.count >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    /// Writes a Huffman code, which deflate stores from its most significant bit.
+    fn write_code(&mut self, code: u32, bits: u32) {
+        self.write(code.reverse_bits() >> (32 - bits), bits);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+fn write_literal(out: &mut BitWriter, symbol: u32) {
+    match symbol {
+        0..=143 => out.write_code(0x30 + symbol, 8),
+        144..=255 => out.write_code(0x190 + symbol - 144, 9),
+        256..=279 => out.write_code(symbol - 256, 7),
+        _ => out.write_code(0xc0 + symbol - 280, 8),
+    }
+}
+
+fn write_match(out: &mut BitWriter, length: usize, distance: usize) {
+    let code = LENGTH_BASE
+        .iter()
+        .rposition(|&base| base <= length)
+        .unwrap();
+    write_literal(out, 257 + code as u32);
+    out.write((length - LENGTH_BASE[code]) as u32, LENGTH_EXTRA[code]);
+    let code = DISTANCE_BASE
+        .iter()
+        .rposition(|&base| base <= distance)
+        .unwrap();
+    out.write_code(code as u32, 5);
+    out.write(
+        (distance - DISTANCE_BASE[code]) as u32,
+        DISTANCE_EXTRA[code],
+    );
+}
+
+fn hash(bytes: &[u8]) -> usize {
+    let value = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]);
+    (value.wrapping_mul(2_654_435_761) >> (32 - HASH_BITS)) as usize
+}
+
+/// Compresses `data` into one deflate block with the fixed Huffman codes, replacing
+/// repeats with the last earlier occurrence of their first three bytes.
+pub(crate) fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut out = BitWriter::default();
+    out.write(1, 1);
+    out.write(1, 2);
+    let mut last_seen = vec![usize::MAX; 1 << HASH_BITS];
+    let mut i = 0;
+    while i < data.len() {
+        let mut length = 0;
+        if i + MIN_MATCH <= data.len() {
+            let h = hash(&data[i..]);
+            let candidate = last_seen[h];
+            last_seen[h] = i;
+            if candidate != usize::MAX && i - candidate <= WINDOW {
+                length = (0..MAX_MATCH.min(data.len() - i))
+                    .take_while(|&k| data[candidate + k] == data[i + k])
+                    .count();
+                if length >= MIN_MATCH {
+                    write_match(&mut out, length, i - candidate);
+                }
+            }
+        }
+        if length >= MIN_MATCH {
+            for j in i + 1..(i + length).min(data.len().saturating_sub(MIN_MATCH - 1)) {
+                last_seen[hash(&data[j..])] = j;
+            }
+            i += length;
+        } else {
+            write_literal(&mut out, data[i] as u32);
+            i += 1;
+        }
+    }
+    write_literal(&mut out, 256);
+    out.finish()
+}
+
+fn zlib(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    out.extend(deflate(data));
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+pub(crate) fn encode(image: &Image) -> Vec<u8> {
+    let mut header = vec![];
+    header.extend((image.width as u32).to_be_bytes());
+    header.extend((image.height as u32).to_be_bytes());
+    header.extend([8, 2, 0, 0, 0]);
+    let mut rows = Vec::with_capacity((image.width * 3 + 1) * image.height);
+    for y in 0..image.height {
+        rows.push(0);
+        for x in 0..image.width {
+            rows.extend(image.pixel(x, y));
+        }
+    }
+    let mut out = SIGNATURE.to_vec();
+    chunk(&mut out, b"IHDR", &header);
+    chunk(&mut out, b"IDAT", &zlib(&rows));
+    chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+#[cfg(test)]
+pub(crate) mod png_tests {
+    use super::*;
+
+    struct BitReader<'a> {
+        bytes: &'a [u8],
+        position: usize,
+    }
+
+    impl BitReader<'_> {
+        fn bit(&mut self) -> u32 {
+            let bit = (self.bytes[self.position / 8] >> (self.position % 8)) & 1;
+            self.position += 1;
+            bit as u32
+        }
+
+        fn bits(&mut self, count: u32) -> usize {
+            (0..count).fold(0, |value, i| value | (self.bit() as usize) << i)
+        }
+
+        fn code(&mut self, count: u32) -> u32 {
+            (0..count).fold(0, |code, _| (code << 1) | self.bit())
+        }
+
+        fn literal(&mut self) -> u32 {
+            let code = self.code(7);
+            if code <= 0x17 {
+                return code + 256;
+            }
+            let code = (code << 1) | self.bit();
+            match code {
+                0x30..=0xbf => code - 0x30,
+                0xc0..=0xc7 => code - 0xc0 + 280,
+                _ => ((code << 1) | self.bit()) - 0x190 + 144,
+            }
+        }
+    }
+
+    /// Inflates a block of fixed Huffman codes, the only kind [`deflate`] writes.
+    pub(crate) fn inflate(bytes: &[u8]) -> Vec<u8> {
+        let mut reader = BitReader { bytes, position: 0 };
+        assert_eq!((reader.bits(1), reader.bits(2)), (1, 1));
+        let mut out: Vec<u8> = vec![];
+        loop {
+            match reader.literal() {
+                literal @ 0..=255 => out.push(literal as u8),
+                256 => return out,
+                symbol => {
+                    let code = (symbol - 257) as usize;
+                    let length = LENGTH_BASE[code] + reader.bits(LENGTH_EXTRA[code]);
+                    let code = reader.code(5) as usize;
+                    let distance = DISTANCE_BASE[code] + reader.bits(DISTANCE_EXTRA[code]);
+                    for _ in 0..length {
+                        out.push(out[out.len() - distance]);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The width, height and RGB pixels of a PNG written by [`encode`], checking its chunks.
+    pub(crate) fn decode(png: &[u8]) -> (usize, usize, Vec<[u8; 3]>) {
+        assert_eq!(png[..8], SIGNATURE);
+        let mut chunks = vec![];
+        let mut rest = &png[8..];
+        while !rest.is_empty() {
+            let length = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            let (body, crc) = rest[4..].split_at(4 + length);
+            assert_eq!(crc32(body).to_be_bytes(), crc[..4]);
+            chunks.push((&body[..4], &body[4..]));
+            rest = &crc[4..];
+        }
+        let kinds = chunks.iter().map(|(kind, _)| *kind).collect::<Vec<_>>();
+        assert_eq!(kinds, [&b"IHDR"[..], b"IDAT", b"IEND"]);
+        let header = chunks[0].1;
+        let width = u32::from_be_bytes(header[..4].try_into().unwrap()) as usize;
+        let height = u32::from_be_bytes(header[4..8].try_into().unwrap()) as usize;
+        assert_eq!(header[8..], [8, 2, 0, 0, 0]);
+        let data = chunks[1].1;
+        assert_eq!(data[..2], [0x78, 0x01]);
+        let rows = inflate(&data[2..data.len() - 4]);
+        assert_eq!(data[data.len() - 4..], adler32(&rows).to_be_bytes());
+        let pixels = rows
+            .chunks(width * 3 + 1)
+            .flat_map(|row| {
+                assert_eq!(row[0], 0);
+                row[1..].chunks(3).map(|p| [p[0], p[1], p[2]])
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(pixels.len(), width * height);
+        (width, height, pixels)
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_deflate() {
+        for data in [
+            &b""[..],
+            b"a",
+            b"abcabcabcabcabcabcabcabc",
+            &[7; 1000],
+            &(0..=255).cycle().take(70000).collect::<Vec<u8>>(),
+        ] {
+            assert_eq!(inflate(&deflate(data)), data);
+        }
+        assert!(deflate(&[0; 10000]).len() < 100);
+    }
+}
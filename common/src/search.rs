@@ -0,0 +1,450 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// A path found by a search, from the start state to a goal inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub states: Vec<S>,
+    pub cost: usize,
+}
+
+/// Every optimal path to the goals reached at the lowest cost, kept as a
+/// predecessor graph so they can be counted or listed without duplicating work.
+#[derive(Debug, Clone)]
+pub struct AllPaths<S> {
+    start: S,
+    cost: usize,
+    goals: Vec<S>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> AllPaths<S> {
+    pub fn cost(&self) -> usize {
+        self.cost
+    }
+
+    /// The goal states reached at the optimal cost, in the order they were found.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// Every state lying on at least one optimal path.
+    pub fn states(&self) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack = self.goals.clone();
+        while let Some(state) = stack.pop() {
+            if seen.insert(state.clone()) {
+                stack.extend(self.predecessors_of(&state).iter().cloned());
+            }
+        }
+        seen
+    }
+
+    /// The number of distinct optimal paths, without listing them.
+    pub fn count(&self) -> usize {
+        let mut memo = HashMap::new();
+        self.goals
+            .iter()
+            .map(|goal| self.count_to(goal, &mut memo))
+            .sum()
+    }
+
+    /// Lists every optimal path, which can be exponentially many.
+    pub fn paths(&self) -> Vec<Vec<S>> {
+        self.goals
+            .iter()
+            .flat_map(|goal| self.paths_to(goal))
+            .collect()
+    }
+
+    fn predecessors_of(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    fn count_to(&self, state: &S, memo: &mut HashMap<S, usize>) -> usize {
+        if *state == self.start {
+            return 1;
+        }
+        if let Some(&count) = memo.get(state) {
+            return count;
+        }
+        let count = self
+            .predecessors_of(state)
+            .iter()
+            .map(|previous| self.count_to(previous, memo))
+            .sum();
+        memo.insert(state.clone(), count);
+        count
+    }
+
+    fn paths_to(&self, state: &S) -> Vec<Vec<S>> {
+        if *state == self.start {
+            return vec![vec![state.clone()]];
+        }
+        self.predecessors_of(state)
+            .iter()
+            .flat_map(|previous| self.paths_to(previous))
+            .map(|mut path| {
+                path.push(state.clone());
+                path
+            })
+            .collect()
+    }
+}
+
+struct Queued<S> {
+    priority: usize,
+    cost: usize,
+    state: S,
+}
+
+impl<S> PartialEq for Queued<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S> Eq for Queued<S> {}
+
+impl<S> Ord for Queued<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S> PartialOrd for Queued<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn rebuild<S: Clone + Eq + Hash>(parents: &HashMap<S, S>, goal: S, cost: usize) -> Path<S> {
+    let mut states = vec![goal];
+    while let Some(parent) = parents.get(states.last().unwrap()) {
+        states.push(parent.clone());
+    }
+    states.reverse();
+    Path { states, cost }
+}
+
+/// Breadth-first search for the path with the fewest steps to a goal.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, steps)) = queue.pop_front() {
+        if is_goal(&state) {
+            return Some(rebuild(&parents, state, steps));
+        }
+        for next in successors(&state) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), state.clone());
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// The number of steps from `start` to every reachable state.
+pub fn bfs_distances<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, steps)) = queue.pop_front() {
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(steps + 1);
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// Every path with the fewest steps to the closest goals.
+pub fn bfs_all<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<AllPaths<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    dijkstra_all(
+        start,
+        |state| successors(state).into_iter().map(|next| (next, 1)),
+        is_goal,
+    )
+}
+
+/// Lowest-cost path to a goal, where `successors` yields each next state with its step cost.
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// A* search; `heuristic` must never overestimate the remaining cost to a goal.
+pub fn astar<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut parents = HashMap::new();
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        cost: 0,
+        state: start,
+    }]);
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        if costs.get(&state).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        if is_goal(&state) {
+            return Some(rebuild(&parents, state, cost));
+        }
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            if costs.get(&next).is_none_or(|&best| cost < best) {
+                costs.insert(next.clone(), cost);
+                parents.insert(next.clone(), state.clone());
+                queue.push(Queued {
+                    priority: cost + heuristic(&next),
+                    cost,
+                    state: next,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// The lowest cost from `start` to every reachable state.
+pub fn dijkstra_distances<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Queued {
+        priority: 0,
+        cost: 0,
+        state: start,
+    }]);
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        if costs.get(&state).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            if costs.get(&next).is_none_or(|&best| cost < best) {
+                costs.insert(next.clone(), cost);
+                queue.push(Queued {
+                    priority: cost,
+                    cost,
+                    state: next,
+                });
+            }
+        }
+    }
+    costs
+}
+
+/// Dijkstra keeping every predecessor on an optimal path, so that all the
+/// lowest-cost paths to the cheapest goals can be recovered.
+pub fn dijkstra_all<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<AllPaths<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Queued {
+        priority: 0,
+        cost: 0,
+        state: start.clone(),
+    }]);
+    let mut best = None;
+    let mut goals = vec![];
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        if costs.get(&state).is_some_and(|&known| cost > known) {
+            continue;
+        }
+        if is_goal(&state) {
+            best = Some(cost);
+            goals.push(state);
+            continue;
+        }
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            match costs.get(&next) {
+                Some(&known) if cost > known => {}
+                Some(&known) if cost == known => {
+                    let previous = predecessors.entry(next).or_default();
+                    if !previous.contains(&state) {
+                        previous.push(state.clone());
+                    }
+                }
+                _ => {
+                    costs.insert(next.clone(), cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    queue.push(Queued {
+                        priority: cost,
+                        cost,
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+    best.map(|cost| AllPaths {
+        start,
+        cost,
+        goals,
+        predecessors,
+    })
+}
+
+#[cfg(test)]
+mod search_tests {
+    use super::*;
+
+    // A 3x3 open grid, numbered 0..9 in reading order.
+    fn grid_neighbours(&cell: &usize) -> Vec<usize> {
+        let (row, col) = (cell / 3, cell % 3);
+        let mut neighbours = vec![];
+        if row > 0 {
+            neighbours.push(cell - 3);
+        }
+        if col > 0 {
+            neighbours.push(cell - 1);
+        }
+        if col < 2 {
+            neighbours.push(cell + 1);
+        }
+        if row < 2 {
+            neighbours.push(cell + 3);
+        }
+        neighbours
+    }
+
+    // a -1-> b -1-> d, a -2-> c -0-> d, a -5-> d, d -1-> e
+    fn weighted(&node: &char) -> Vec<(char, usize)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 2), ('d', 5)],
+            'b' => vec![('d', 1)],
+            'c' => vec![('d', 0)],
+            'd' => vec![('e', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs(0, grid_neighbours, |&cell| cell == 8).unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.states.first(), Some(&0));
+        assert_eq!(path.states.last(), Some(&8));
+        assert_eq!(path.states.len(), 5);
+        assert_eq!(bfs(0, grid_neighbours, |&cell| cell == 9), None);
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        let distances = bfs_distances(4, grid_neighbours);
+        assert_eq!(distances.len(), 9);
+        assert_eq!(distances[&4], 0);
+        assert_eq!(distances[&1], 1);
+        assert_eq!(distances[&0], 2);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra('a', weighted, |&node| node == 'e').unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.states.len(), 4);
+        assert_eq!(dijkstra('b', weighted, |&node| node == 'a'), None);
+    }
+
+    #[test]
+    fn test_dijkstra_distances() {
+        let distances = dijkstra_distances('a', weighted);
+        assert_eq!(
+            distances,
+            HashMap::from([('a', 0), ('b', 1), ('c', 2), ('d', 2), ('e', 3)])
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let heuristic = |&cell: &usize| (2 - cell / 3) + (2 - cell % 3);
+        let step = |cell: &usize| grid_neighbours(cell).into_iter().map(|next| (next, 1));
+        let path = astar(0, step, heuristic, |&cell| cell == 8).unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.states.len(), 5);
+    }
+
+    #[test]
+    fn test_dijkstra_all() {
+        let all = dijkstra_all('a', weighted, |&node| node == 'e').unwrap();
+        assert_eq!(all.cost(), 3);
+        assert_eq!(all.goals(), &['e']);
+        assert_eq!(all.count(), 2);
+        let mut paths = all.paths();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![vec!['a', 'b', 'd', 'e'], vec!['a', 'c', 'd', 'e']]
+        );
+        assert_eq!(all.states(), HashSet::from(['a', 'b', 'c', 'd', 'e']));
+    }
+
+    #[test]
+    fn test_bfs_all() {
+        let all = bfs_all(0, grid_neighbours, |&cell| cell == 8).unwrap();
+        assert_eq!(all.cost(), 4);
+        assert_eq!(all.count(), 6);
+        assert_eq!(all.paths().len(), 6);
+        assert_eq!(all.states().len(), 9);
+
+        let corners = bfs_all(4, grid_neighbours, |&cell| cell % 2 == 0 && cell != 4).unwrap();
+        assert_eq!(corners.cost(), 2);
+        assert_eq!(corners.goals().len(), 4);
+        assert_eq!(corners.count(), 8);
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{
+    generate::Generated, observe::Observer, random::Rng, Example, Param, Params, ParseError,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    Coord(i64, i64),
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Coord(x, y) => write!(f, "{x},{y}"),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Number(value as i128)
+            }
+        })*
+    };
+}
+
+impl_from_number!(i32, i64, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<(usize, usize)> for Answer {
+    fn from((x, y): (usize, usize)) -> Self {
+        Answer::Coord(x as i64, y as i64)
+    }
+}
+
+/// An answer if there is one, such as a path the input may not have.
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+pub trait Solution {
+    type Input<'a>;
+
+    /// The worked examples of the puzzle text, see [`check_examples`](crate::check_examples).
+    const EXAMPLES: &'static [Example] = &[];
+
+    /// The constants of the puzzle that [`Solution::parse_with`] lets callers override.
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    /// Parses `input` for a puzzle whose constants are overridden by `params`. Only
+    /// days with such constants need more than [`Solution::parse`].
+    fn parse_with<'a>(input: &'a str, _params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer;
+
+    fn part2(_input: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+
+    /// Solves `part` like [`Solution::part1`] or [`Solution::part2`], showing each tick of
+    /// the simulation to `observer`. Parts that are not simulations stay unsolved.
+    fn simulate(_input: &Self::Input<'_>, _part: u8, _observer: &mut dyn Observer) -> Answer {
+        Answer::Unsolved
+    }
+
+    /// A valid input of `size`, such as the side of its grid or the number of its lines,
+    /// made from `rng` to measure how the solver scales. Days without a generator have none.
+    fn generate(_size: usize, _rng: &mut Rng) -> Option<Generated> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod solution_tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(42usize).to_string(), "42");
+        assert_eq!(Answer::from(-7i64).to_string(), "-7");
+        assert_eq!(Answer::from("ah,ap,ek").to_string(), "ah,ap,ek");
+        assert_eq!(Answer::from((41usize, 26usize)).to_string(), "41,26");
+        assert_eq!(Answer::Unsolved.to_string(), "-");
+        assert_eq!(Answer::from(Some(42usize)), Answer::Number(42));
+        assert_eq!(Answer::from(None::<usize>), Answer::Unsolved);
+    }
+}
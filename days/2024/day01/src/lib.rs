@@ -0,0 +1,139 @@
+use common::{
+    generate::Generated, parse::parse_at, random::Rng, Answer, Example, ParseError, Solution,
+};
+
+fn find_diff(list1: &[i32], list2: &[i32]) -> i32 {
+    let mut list1 = list1.to_vec();
+    list1.sort();
+    let mut list2 = list2.to_vec();
+    list2.sort();
+    list1
+        .iter()
+        .zip(list2)
+        .map(|(i1, i2)| (i2 - i1).abs())
+        .sum()
+}
+
+fn calculate_similarity(list1: &[i32], list2: &[i32]) -> i32 {
+    list1
+        .iter()
+        .map(|i1| list2.iter().filter(|&i2| i2 == i1).count() as i32 * i1)
+        .sum()
+}
+
+fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    let pairs = input
+        .trim()
+        .lines()
+        .map(|l| {
+            let mut fields = l.split_whitespace();
+            let mut number =
+                || parse_at::<i32>(input, fields.next().unwrap_or(&l[l.len()..]), "a number");
+            Ok((number()?, number()?))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    Ok(pairs.into_iter().unzip())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = (Vec<i32>, Vec<i32>);
+
+    const EXAMPLES: &'static [Example] =
+        &[
+            Example::new("example", include_str!("../examples/example.txt"))
+                .part1("11")
+                .part2("31"),
+        ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (list1, list2) = input;
+        find_diff(list1, list2).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (list1, list2) = input;
+        calculate_similarity(list1, list2).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let left = (0..size)
+            .map(|_| rng.between(10000, 99999))
+            .collect::<Vec<_>>();
+        let input = left
+            .iter()
+            .map(|location| {
+                // Some locations of the right list repeat those of the left one.
+                let right = match rng.chance(0.3) {
+                    true => *rng.pick(&left),
+                    false => rng.between(10000, 99999),
+                };
+                format!("{location}   {right}\n")
+            })
+            .collect();
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod day01_tests {
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day01, Lists);
+
+    #[derive(Debug, Clone)]
+    struct Lists(Vec<(u32, u32)>);
+
+    impl Case for Lists {
+        fn generate(rng: &mut Rng) -> Self {
+            let pairs = (0..rng.between(1, 12))
+                .map(|_| (rng.below(20) as u32, rng.below(20) as u32))
+                .collect();
+            Lists(pairs)
+        }
+
+        fn input(&self) -> String {
+            self.0
+                .iter()
+                .map(|(left, right)| format!("{left}   {right}\n"))
+                .collect()
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_list(&self.0)
+                .into_iter()
+                .filter(|pairs| !pairs.is_empty())
+                .map(Lists)
+                .collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let mut left = self.0.iter().map(|&(l, _)| l as i64).collect::<Vec<_>>();
+            let mut right = self.0.iter().map(|&(_, r)| r as i64).collect::<Vec<_>>();
+            match part {
+                1 => {
+                    left.sort();
+                    right.sort();
+                    let distances = left.iter().zip(&right).map(|(l, r)| (l - r).abs());
+                    distances.sum::<i64>().into()
+                }
+                _ => left
+                    .iter()
+                    .map(|l| l * right.iter().filter(|&r| r == l).count() as i64)
+                    .sum::<i64>()
+                    .into(),
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day01::Day01;
+
+fn main() {
+    let input = read_input(2024, 1);
+    let input = Day01::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day01::part1(&input));
+    println!("Part 2 = {}", Day01::part2(&input));
+}
@@ -0,0 +1,170 @@
+use common::{
+    generate::Generated,
+    parse::{lines, parse_at},
+    random::Rng,
+    Answer, Example, ParseError, Solution,
+};
+
+fn is_report_safe(report: &[usize]) -> bool {
+    report.windows(2).all(|level| {
+        level[0] < level[1] && (level[1] - level[0]) >= 1 && (level[1] - level[0]) <= 3
+    }) || report.windows(2).all(|level| {
+        level[0] > level[1] && (level[0] - level[1]) >= 1 && (level[0] - level[1]) <= 3
+    })
+}
+
+fn is_safe_removing(report: &[usize]) -> bool {
+    (0..report.len()).any(|i| {
+        let mut reduced = report.to_vec();
+        reduced.remove(i);
+        is_report_safe(&reduced)
+    })
+}
+
+fn parse_reports(input: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+    lines(input)
+        .map(|l| {
+            l.split_whitespace()
+                .map(|s| parse_at(input, s, "a level"))
+                .collect()
+        })
+        .collect()
+}
+
+fn count_safe(reports: &[Vec<usize>], can_remove: bool) -> usize {
+    reports
+        .iter()
+        .filter(|report| {
+            if can_remove {
+                is_safe_removing(report)
+            } else {
+                is_report_safe(report)
+            }
+        })
+        .count()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Vec<usize>>;
+
+    const EXAMPLES: &'static [Example] =
+        &[
+            Example::new("example", include_str!("../examples/example.txt"))
+                .part1("2")
+                .part2("4"),
+        ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_reports(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        count_safe(input, false).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        count_safe(input, true).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let input = (0..size)
+            .map(|_| {
+                let direction = if rng.chance(0.5) { 1 } else { -1 };
+                let mut level = rng.between(10, 90);
+                let mut levels = vec![level];
+                for _ in 0..rng.between(4, 7) {
+                    // Mostly safe steps, with the odd one too large or the wrong way.
+                    let step = match rng.chance(0.9) {
+                        true => rng.between(1, 3),
+                        false => rng.between(-2, 6),
+                    };
+                    level += direction * step;
+                    levels.push(level);
+                }
+                let levels = levels.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+                format!("{}\n", levels.join(" "))
+            })
+            .collect();
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod day02_tests {
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day02, Reports);
+
+    #[derive(Debug, Clone)]
+    struct Reports(Vec<Vec<i64>>);
+
+    fn is_safe(levels: &[i64]) -> bool {
+        let increasing = levels.windows(2).all(|w| (1..=3).contains(&(w[1] - w[0])));
+        let decreasing = levels.windows(2).all(|w| (1..=3).contains(&(w[0] - w[1])));
+        increasing || decreasing
+    }
+
+    impl Case for Reports {
+        fn generate(rng: &mut Rng) -> Self {
+            let reports = (0..rng.between(1, 8))
+                .map(|_| {
+                    let mut level = rng.between(1, 20);
+                    (0..rng.between(4, 8))
+                        .map(|_| {
+                            level = (level + rng.between(-4, 4)).max(1);
+                            level
+                        })
+                        .collect()
+                })
+                .collect();
+            Reports(reports)
+        }
+
+        fn input(&self) -> String {
+            self.0
+                .iter()
+                .map(|levels| {
+                    format!(
+                        "{}\n",
+                        levels
+                            .iter()
+                            .map(|l| l.to_string())
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    )
+                })
+                .collect()
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_list(&self.0)
+                .into_iter()
+                .filter(|reports| !reports.is_empty())
+                .map(Reports)
+                .collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let dampened = |levels: &Vec<i64>| {
+                (0..levels.len()).any(|skip| {
+                    let mut rest = levels.clone();
+                    rest.remove(skip);
+                    is_safe(&rest)
+                })
+            };
+            let safe = self
+                .0
+                .iter()
+                .filter(|levels| is_safe(levels) || (part == 2 && dampened(levels)));
+            safe.count().into()
+        }
+    }
+}
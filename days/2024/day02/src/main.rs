@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day02::Day02;
+
+fn main() {
+    let input = read_input(2024, 2);
+    let input = Day02::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day02::part1(&input));
+    println!("Part 2 = {}", Day02::part2(&input));
+}
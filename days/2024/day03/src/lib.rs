@@ -0,0 +1,208 @@
+use common::{generate::Generated, random::Rng, Answer, Example, ParseError, Solution};
+
+#[derive(Debug)]
+enum ParserState {
+    ReadO,
+    ReadApostrophe,
+    ReadT,
+    ReadOpenParenOrN(bool),
+    ReadClosedParenDoOrDont(bool),
+    DoneDoOrDont(bool),
+    ReadMOrD,
+    ReadU,
+    ReadL,
+    ReadOpenParen,
+    ReadFirstNum(String),
+    ReadSecondNum(i32, String),
+    DoneMul(i32),
+}
+
+fn transition(state: &ParserState, c: char) -> ParserState {
+    match (state, c) {
+        (ParserState::ReadMOrD, 'm') => ParserState::ReadU,
+        (ParserState::ReadMOrD, 'd') => ParserState::ReadO,
+        (ParserState::ReadU, 'u') => ParserState::ReadL,
+        (ParserState::ReadL, 'l') => ParserState::ReadOpenParen,
+        (ParserState::ReadOpenParen, '(') => ParserState::ReadFirstNum(String::new()),
+        (ParserState::ReadFirstNum(s), c) if c.is_ascii_digit() && s.len() < 3 => {
+            let mut s = s.clone();
+            s.push(c);
+            ParserState::ReadFirstNum(s)
+        }
+        (ParserState::ReadFirstNum(s), ',') if !s.is_empty() => {
+            ParserState::ReadSecondNum(s.parse::<i32>().unwrap(), String::new())
+        }
+        (ParserState::ReadSecondNum(first_num, s), c) if c.is_ascii_digit() && s.len() < 3 => {
+            let mut s = s.clone();
+            s.push(c);
+            ParserState::ReadSecondNum(*first_num, s)
+        }
+        (ParserState::ReadSecondNum(first_num, s), ')') if !s.is_empty() => {
+            ParserState::DoneMul(first_num * s.parse::<i32>().unwrap())
+        }
+        (ParserState::ReadO, 'o') => ParserState::ReadOpenParenOrN(true),
+        (ParserState::ReadOpenParenOrN(true), 'n') => ParserState::ReadApostrophe,
+        (ParserState::ReadOpenParenOrN(b), '(') => ParserState::ReadClosedParenDoOrDont(*b),
+        (ParserState::ReadApostrophe, '\'') => ParserState::ReadT,
+        (ParserState::ReadT, 't') => ParserState::ReadOpenParenOrN(false),
+        (ParserState::ReadClosedParenDoOrDont(b), ')') => ParserState::DoneDoOrDont(*b),
+        (ParserState::ReadMOrD, _) => ParserState::ReadMOrD,
+        // The character that broke an instruction may start the next one.
+        (_, c) => transition(&ParserState::ReadMOrD, c),
+    }
+}
+
+fn parse_input(input: &str, check_do: bool) -> i32 {
+    let (_state, mul_sum, _enabled) = input.chars().fold(
+        (ParserState::ReadMOrD, 0, true),
+        |(state, mul_sum, enabled), c| {
+            let new_state = transition(&state, c);
+            let (state, n, enabled) = match new_state {
+                ParserState::DoneMul(n) => (ParserState::ReadMOrD, n, enabled),
+                ParserState::DoneDoOrDont(b) => (ParserState::ReadMOrD, 0, !check_do || b),
+                _ => (new_state, 0, enabled),
+            };
+            let mul_sum = if enabled { mul_sum + n } else { mul_sum };
+            (state, mul_sum, enabled)
+        },
+    );
+    mul_sum
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = &'a str;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("example", include_str!("../examples/example.txt")).part1("161"),
+        Example::new("conditionals", include_str!("../examples/conditionals.txt")).part2("48"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        parse_input(input, false).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        parse_input(input, true).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let mut input = String::with_capacity(size + 16);
+        while input.len() < size {
+            let (x, y) = (rng.between(0, 999), rng.between(0, 999));
+            match rng.below(10) {
+                0..=2 => input.push_str(&format!("mul({x},{y})")),
+                3 => input.push_str(&format!("mul({x},{y}]")),
+                4 => input.push_str("do()"),
+                5 => input.push_str("don't()"),
+                _ => input.push(*rng.pick(&['@', '%', '[', ' ', '\'', 'm', ')', ',', 'x', '/'])),
+            }
+        }
+        input.push('\n');
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod day03_tests {
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests,
+        random::Rng,
+    };
+    use parameterized::parameterized;
+
+    use super::*;
+
+    differential_tests!(Day03, Memory);
+
+    #[parameterized(
+        input = { "mul(44,46)", "mul(123,4)", "mul(4*", "mul(6,9!", "?(12,34)", "mul ( 2 , 4 )" },
+        expected = { 2024, 492, 0, 0, 0, 0 }
+    )]
+    fn test_simple(input: &str, expected: i32) {
+        assert_eq!(parse_input(input, false), expected);
+    }
+
+    #[parameterized(
+        input = { "mul(1234,5)", "mul(5,1234)", "mul(,5)", "mul(5,)", "mulmul(2,3)", "mul(11,2mul(3,4)", "do(mul(2,3)" },
+        expected = { 0, 0, 0, 0, 6, 12, 6 }
+    )]
+    fn test_malformed(input: &str, expected: i32) {
+        assert_eq!(parse_input(input, false), expected);
+    }
+
+    #[test]
+    fn test_broken_dont() {
+        assert_eq!(parse_input("don'tmul(2,3)", true), 6);
+        assert_eq!(parse_input("don't(mul(2,3)", true), 6);
+    }
+
+    #[derive(Debug, Clone)]
+    struct Memory(Vec<String>);
+
+    /// The `mul(X,Y)` at the start of `text`, with numbers of 1 to 3 digits.
+    fn instruction(text: &str) -> Option<i64> {
+        let rest = text.strip_prefix("mul(")?;
+        let (x, rest) = rest.split_once(',')?;
+        let (y, _) = rest.split_once(')')?;
+        let number = |n: &str| (1..=3).contains(&n.len()) && n.bytes().all(|b| b.is_ascii_digit());
+        (number(x) && number(y)).then(|| x.parse::<i64>().unwrap() * y.parse::<i64>().unwrap())
+    }
+
+    impl Case for Memory {
+        fn generate(rng: &mut Rng) -> Self {
+            let tokens = (0..rng.between(1, 12))
+                .map(|_| {
+                    let (x, y) = (rng.between(0, 1200), rng.between(0, 999));
+                    match rng.below(9) {
+                        0..=2 => format!("mul({x},{y})"),
+                        3 => format!("mul({x},{y}]"),
+                        4 => format!("mul( {x},{y})"),
+                        5 => "do()".to_string(),
+                        6 => "don't()".to_string(),
+                        7 => rng
+                            .pick(&["mul(", "do(", "don't", ")", ",", "mul"])
+                            .to_string(),
+                        _ => rng
+                            .pick(&["x", "%", "&", "[", "!", "@", "^", " ", "then", "why()"])
+                            .to_string(),
+                    }
+                })
+                .collect();
+            Memory(tokens)
+        }
+
+        fn input(&self) -> String {
+            format!("{}\n", self.0.concat())
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_list(&self.0).into_iter().map(Memory).collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let memory = self.0.concat();
+            let mut enabled = true;
+            let mut sum = 0;
+            for start in 0..memory.len() {
+                let text = &memory[start..];
+                if text.starts_with("do()") {
+                    enabled = true;
+                } else if text.starts_with("don't()") {
+                    enabled = false;
+                } else if let Some(product) = instruction(text) {
+                    if enabled || part == 1 {
+                        sum += product;
+                    }
+                }
+            }
+            sum.into()
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day03::Day03;
+
+fn main() {
+    let input = read_input(2024, 3);
+    let input = Day03::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day03::part1(&input));
+    println!("Part 2 = {}", Day03::part2(&input));
+}
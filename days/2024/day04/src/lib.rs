@@ -0,0 +1,275 @@
+use common::{generate::Generated, grid::Grid, random::Rng, Answer, Example, ParseError, Solution};
+
+fn get_rows(grid: &Grid<char>) -> Vec<String> {
+    grid.iter_rows().map(|row| row.iter().collect()).collect()
+}
+
+fn get_columns(grid: &Grid<char>) -> Vec<String> {
+    grid.iter_cols().map(|col| col.collect()).collect()
+}
+
+fn get_diag_bl_tr(grid: &Grid<char>) -> Vec<String> {
+    grid.anti_diagonals().map(|diag| diag.collect()).collect()
+}
+
+fn get_diag_br_tl(grid: &Grid<char>) -> Vec<String> {
+    grid.diagonals().map(|diag| diag.rev().collect()).collect()
+}
+
+#[derive(Debug)]
+enum ParserState {
+    ReadX,
+    ReadM,
+    ReadA,
+    ReadS,
+    Done,
+}
+
+fn transition(state: ParserState, c: char) -> ParserState {
+    match (state, c) {
+        (_, 'X') => ParserState::ReadM,
+        (ParserState::ReadM, 'M') => ParserState::ReadA,
+        (ParserState::ReadA, 'A') => ParserState::ReadS,
+        (ParserState::ReadS, 'S') => ParserState::Done,
+        _ => ParserState::ReadX,
+    }
+}
+
+fn count_xmas_in_row(row: &str) -> usize {
+    let (_, count) = row.chars().fold(
+        (ParserState::ReadX, 0),
+        |(state, count), c| match transition(state, c) {
+            ParserState::Done => (ParserState::ReadX, count + 1),
+            new_state => (new_state, count),
+        },
+    );
+    count
+}
+
+fn count_xmas(grid: &Grid<char>) -> usize {
+    let rows = get_rows(grid);
+    let columns = get_columns(grid);
+    let diag_1 = get_diag_bl_tr(grid);
+    let diag_2 = get_diag_br_tl(grid);
+    let mut count = 0;
+    count += rows
+        .iter()
+        .map(|row| {
+            let rev = row.chars().rev().collect::<String>();
+            count_xmas_in_row(row) + count_xmas_in_row(&rev)
+        })
+        .sum::<usize>();
+    count += columns
+        .iter()
+        .map(|row| {
+            let rev = row.chars().rev().collect::<String>();
+            count_xmas_in_row(row) + count_xmas_in_row(&rev)
+        })
+        .sum::<usize>();
+    count += diag_1
+        .iter()
+        .map(|row| {
+            let rev = row.chars().rev().collect::<String>();
+            count_xmas_in_row(row) + count_xmas_in_row(&rev)
+        })
+        .sum::<usize>();
+    count += diag_2
+        .iter()
+        .map(|row| {
+            let rev = row.chars().rev().collect::<String>();
+            count_xmas_in_row(row) + count_xmas_in_row(&rev)
+        })
+        .sum::<usize>();
+    count
+}
+
+fn is_mas(grid: &Grid<char>, (row, col): (usize, usize), delta_col: isize) -> bool {
+    let ends = [
+        grid.offset((row, col), (-1, -delta_col)),
+        grid.offset((row, col), (1, delta_col)),
+    ];
+    matches!(
+        ends.map(|end| end.map(|pos| grid[pos])),
+        [Some('M'), Some('S')] | [Some('S'), Some('M')]
+    )
+}
+
+fn count_x_mas(grid: &Grid<char>) -> usize {
+    grid.iter()
+        .filter(|&(pos, &c)| c == 'A' && is_mas(grid, pos, 1) && is_mas(grid, pos, -1))
+        .count()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Grid<char>;
+
+    const EXAMPLES: &'static [Example] =
+        &[
+            Example::new("example", include_str!("../examples/example.txt"))
+                .part1("18")
+                .part2("9"),
+        ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::try_parse(input, Ok)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        count_xmas(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        count_x_mas(input).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let input = (0..size)
+            .map(|_| {
+                let row = (0..size).map(|_| *rng.pick(&['X', 'M', 'A', 'S']));
+                format!("{}\n", row.collect::<String>())
+            })
+            .collect();
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod day04_tests {
+    use common::{
+        differential::{render_grid, shrink_grid, Case},
+        differential_tests,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day04, Letters);
+
+    #[test]
+    fn test_get_rows() {
+        let input = r#"XMAS
+MASX
+SAXM"#;
+        assert_eq!(
+            get_rows(&Grid::parse(input, |c| c)),
+            vec!["XMAS".to_string(), "MASX".to_string(), "SAXM".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_get_columns() {
+        let input = r#"XMAS
+MASX
+SAXM"#;
+        assert_eq!(
+            get_columns(&Grid::parse(input, |c| c)),
+            vec![
+                "XMS".to_string(),
+                "MAA".to_string(),
+                "ASX".to_string(),
+                "SXM".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_diag_bl_tr() {
+        let input = r#"XMAS
+MASX
+SAXM"#;
+        assert_eq!(
+            get_diag_bl_tr(&Grid::parse(input, |c| c)),
+            vec![
+                "X".to_string(),
+                "MM".to_string(),
+                "SAA".to_string(),
+                "ASS".to_string(),
+                "XX".to_string(),
+                "M".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_diag_br_tl() {
+        let input = r#"XMAS
+MASX
+SAXM"#;
+        assert_eq!(
+            get_diag_br_tl(&Grid::parse(input, |c| c)),
+            vec![
+                "S".to_string(),
+                "XA".to_string(),
+                "MSM".to_string(),
+                "XAX".to_string(),
+                "AM".to_string(),
+                "S".to_string()
+            ]
+        );
+    }
+
+    #[derive(Debug, Clone)]
+    struct Letters(Vec<Vec<char>>);
+
+    impl Case for Letters {
+        fn generate(rng: &mut Rng) -> Self {
+            let (rows, cols) = (rng.between(1, 8), rng.between(1, 8));
+            let grid = (0..rows)
+                .map(|_| {
+                    (0..cols)
+                        .map(|_| *rng.pick(&['X', 'M', 'A', 'S']))
+                        .collect()
+                })
+                .collect();
+            Letters(grid)
+        }
+
+        fn input(&self) -> String {
+            render_grid(&self.0)
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_grid(&self.0).into_iter().map(Letters).collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let at = |row: i64, col: i64| {
+                usize::try_from(row)
+                    .ok()
+                    .and_then(|row| self.0.get(row))
+                    .and_then(|line| line.get(usize::try_from(col).ok()?))
+                    .copied()
+            };
+            let spells = |row: i64, col: i64, (dr, dc): (i64, i64), word: &str| {
+                word.chars()
+                    .enumerate()
+                    .all(|(i, c)| at(row + dr * i as i64, col + dc * i as i64) == Some(c))
+            };
+            let cells = (0..self.0.len() as i64)
+                .flat_map(|row| (0..self.0[0].len() as i64).map(move |col| (row, col)));
+            let found = match part {
+                1 => cells
+                    .map(|(row, col)| {
+                        (-1..=1)
+                            .flat_map(|dr| (-1..=1).map(move |dc| (dr, dc)))
+                            .filter(|&step| step != (0, 0) && spells(row, col, step, "XMAS"))
+                            .count()
+                    })
+                    .sum(),
+                _ => cells
+                    .filter(|&(row, col)| {
+                        let diagonal = |start: (i64, i64), step: (i64, i64)| {
+                            spells(start.0, start.1, step, "MAS")
+                                || spells(start.0, start.1, step, "SAM")
+                        };
+                        diagonal((row - 1, col - 1), (1, 1))
+                            && diagonal((row - 1, col + 1), (1, -1))
+                    })
+                    .count(),
+            };
+            found.into()
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day04::Day04;
+
+fn main() {
+    let input = read_input(2024, 4);
+    let input = Day04::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day04::part1(&input));
+    println!("Part 2 = {}", Day04::part2(&input));
+}
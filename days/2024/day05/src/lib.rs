@@ -1,6 +1,12 @@
-use common::read_input;
 use std::collections::{HashMap, HashSet, VecDeque};
 
+use common::{
+    generate::Generated,
+    parse::{lines, parse_at, parse_list, split_at, split_sections},
+    random::Rng,
+    Answer, Example, ParseError, Solution,
+};
+
 #[derive(Debug, PartialEq)]
 struct Update(Vec<usize>);
 
@@ -11,7 +17,7 @@ impl Update {
 
     fn is_valid(&self, ordering_rules: &HashMap<usize, Vec<usize>>) -> bool {
         self.0.iter().enumerate().all(|(idx, update)| {
-            if let Some(rules) = ordering_rules.get(&update) {
+            if let Some(rules) = ordering_rules.get(update) {
                 !rules.iter().any(|after| self.0[0..idx].contains(after))
             } else {
                 true
@@ -20,7 +26,7 @@ impl Update {
     }
 
     fn get_mid(&self) -> usize {
-        *self.0.iter().nth(self.0.len() / 2).unwrap()
+        *self.0.get(self.0.len() / 2).unwrap()
     }
 
     fn reorder(&self, ordering_rules: &HashMap<usize, Vec<usize>>) -> Self {
@@ -91,72 +97,31 @@ impl Update {
 }
 
 #[derive(Debug)]
-struct Pages {
+pub struct Pages {
     ordering_rules: HashMap<usize, Vec<usize>>,
     updates: Vec<Update>,
 }
 
-#[derive(Clone)]
-enum InputParserState {
-    Start,
-    ReadRule(usize, usize),
-    ReadEmptyLine,
-    ReadUpdates(Vec<usize>),
-    Done,
-}
+impl TryFrom<&str> for Pages {
+    type Error = ParseError;
 
-fn transition(state: &InputParserState, line: &str) -> InputParserState {
-    match (state, line) {
-        (InputParserState::Start, s) | (InputParserState::ReadRule(_, _), s)
-            if !s.trim().is_empty() =>
-        {
-            let (before, after) = s.split_once('|').unwrap();
-            InputParserState::ReadRule(before.parse().unwrap(), after.parse().unwrap())
-        }
-        (InputParserState::ReadRule(_, _), s) if s.trim().is_empty() => {
-            InputParserState::ReadEmptyLine
-        }
-        (InputParserState::ReadEmptyLine, s) | (InputParserState::ReadUpdates(_), s) => {
-            let updates = s
-                .split(',')
-                .map(|update| update.parse().unwrap())
-                .collect::<Vec<_>>();
-            InputParserState::ReadUpdates(updates)
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (rules, updates) = split_sections(value, value)?;
+        let mut ordering_rules: HashMap<usize, Vec<usize>> = HashMap::new();
+        for rule in lines(rules) {
+            let (before, after) = split_at(value, rule, "|")?;
+            ordering_rules
+                .entry(parse_at(value, before, "a page number")?)
+                .or_default()
+                .push(parse_at(value, after, "a page number")?);
         }
-        _ => InputParserState::Done,
-    }
-}
-
-impl From<&str> for Pages {
-    fn from(value: &str) -> Self {
-        let (_, ordering_rules, updates) = value.lines().fold(
-            (
-                InputParserState::Start,
-                HashMap::<usize, Vec<usize>>::new(),
-                vec![],
-            ),
-            |(state, mut rules, mut updates), l| {
-                let new_state = transition(&state, l);
-                match new_state {
-                    InputParserState::ReadRule(before, after) => {
-                        rules
-                            .entry(before)
-                            .and_modify(|v| v.push(after))
-                            .or_insert(vec![after]);
-                        (new_state, rules, updates)
-                    }
-                    InputParserState::ReadUpdates(ref v) => {
-                        updates.push(v.clone());
-                        (new_state.clone(), rules, updates)
-                    }
-                    _ => (new_state, rules, updates),
-                }
-            },
-        );
-        Pages {
+        let updates = lines(updates)
+            .map(|update| parse_list(value, update, ",", "a page number").map(Update::new))
+            .collect::<Result<_, _>>()?;
+        Ok(Pages {
             ordering_rules,
-            updates: updates.iter().map(|v| Update::new(v.to_vec())).collect(),
-        }
+            updates,
+        })
     }
 }
 
@@ -173,27 +138,74 @@ impl Pages {
         self.updates
             .iter()
             .filter(|update| !update.is_valid(&self.ordering_rules))
-            .map(|update| update)
             .map(|update| update.reorder(&self.ordering_rules))
-            .map(|update| update)
             .map(|update| update.get_mid())
             .sum()
     }
 }
 
-fn main() {
-    let input = read_input("day05.txt");
-    let pages = Pages::from(input.as_str());
-    println!("Part 1 = {}", pages.sum_mid());
-    println!("Part 2 = {}", pages.sum_mid_incorrect_order());
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Pages;
+
+    const EXAMPLES: &'static [Example] =
+        &[
+            Example::new("example", include_str!("../examples/example.txt"))
+                .part1("143")
+                .part2("123"),
+        ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Pages::try_from(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input.sum_mid().into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        input.sum_mid_incorrect_order().into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        // The rules order every pair of pages, as the updates of the puzzle need.
+        let mut pages = (10..100).collect::<Vec<_>>();
+        rng.shuffle(&mut pages);
+        let mut rules = (0..pages.len())
+            .flat_map(|before| (before + 1..pages.len()).map(move |after| (before, after)))
+            .map(|(before, after)| format!("{}|{}\n", pages[before], pages[after]))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut rules);
+        let updates = (0..size)
+            .map(|_| {
+                let mut update = pages.clone();
+                rng.shuffle(&mut update);
+                update.truncate(2 * rng.below(12) + 5);
+                if rng.chance(0.5) {
+                    update.sort_by_key(|page| pages.iter().position(|p| p == page));
+                }
+                let update = update.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+                format!("{}\n", update.join(","))
+            })
+            .collect::<String>();
+        Some(Generated::new(format!("{}\n{updates}", rules.concat())))
+    }
 }
 
 #[cfg(test)]
 mod day05_tests {
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests,
+        random::Rng,
+    };
     use parameterized::parameterized;
 
     use super::*;
 
+    differential_tests!(Day05, Manual);
+
     #[parameterized(
         input = { vec![75,47,61,53,29], vec![97,61,53,29,13], vec![75,29,13], vec![75,97,47,61,53], vec![61,13,29], vec![97,13,75,29,47] },
         expected = { true, true, true, false, false, false }
@@ -238,40 +250,6 @@ mod day05_tests {
         assert_eq!(update.is_valid(&rules), expected);
     }
 
-    #[test]
-    fn part1() {
-        let input = r#"47|53
-97|13
-97|61
-97|47
-75|29
-61|13
-75|53
-29|13
-97|29
-53|29
-61|53
-97|53
-61|29
-47|13
-75|47
-97|75
-47|61
-75|61
-47|29
-75|13
-53|13
-
-75,47,61,53,29
-97,61,53,29,13
-75,29,13
-75,97,47,61,53
-61,13,29
-97,13,75,29,47"#;
-        let pages = Pages::from(input);
-        assert_eq!(pages.sum_mid(), 143);
-    }
-
     #[parameterized(
         input = { vec![75,97,47,61,53], vec![61,13,29], vec![97,13,75,29,47] },
         expected = { vec![97,75,47,61,53], vec![61,29,13], vec![97,75,47,29,13] }
@@ -316,37 +294,90 @@ mod day05_tests {
         assert_eq!(update.reorder(&rules), Update(expected));
     }
 
-    #[test]
-    fn part2() {
-        let input = r#"47|53
-97|13
-97|61
-97|47
-75|29
-61|13
-75|53
-29|13
-97|29
-53|29
-61|53
-97|53
-61|29
-47|13
-75|47
-97|75
-47|61
-75|61
-47|29
-75|13
-53|13
-
-75,47,61,53,29
-97,61,53,29,13
-75,29,13
-75,97,47,61,53
-61,13,29
-97,13,75,29,47"#;
-        let pages = Pages::from(input);
-        assert_eq!(pages.sum_mid_incorrect_order(), 123);
+    #[derive(Debug, Clone)]
+    struct Manual {
+        rules: Vec<(u32, u32)>,
+        updates: Vec<Vec<u32>>,
+    }
+
+    impl Case for Manual {
+        fn generate(rng: &mut Rng) -> Self {
+            let mut pages = (10..100).collect::<Vec<u32>>();
+            rng.shuffle(&mut pages);
+            pages.truncate(rng.between(3, 8) as usize);
+            let mut rules = (0..pages.len())
+                .flat_map(|i| (i + 1..pages.len()).map(move |j| (i, j)))
+                .map(|(i, j)| (pages[i], pages[j]))
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut rules);
+            let updates = (0..rng.between(1, 6))
+                .map(|_| {
+                    let mut update = pages.clone();
+                    rng.shuffle(&mut update);
+                    let length = 2 * rng.below(pages.len().div_ceil(2)) + 1;
+                    update.truncate(length);
+                    update
+                })
+                .collect();
+            Manual { rules, updates }
+        }
+
+        fn input(&self) -> String {
+            let rules = self
+                .rules
+                .iter()
+                .map(|(before, after)| format!("{before}|{after}\n"))
+                .collect::<String>();
+            let updates = self
+                .updates
+                .iter()
+                .map(|update| {
+                    format!(
+                        "{}\n",
+                        update
+                            .iter()
+                            .map(|p| p.to_string())
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    )
+                })
+                .collect::<String>();
+            format!("{rules}\n{updates}")
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_list(&self.updates)
+                .into_iter()
+                .filter(|updates| !updates.is_empty())
+                .map(|updates| Manual {
+                    updates,
+                    ..self.clone()
+                })
+                .collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let before = |a: u32, b: u32| self.rules.contains(&(a, b));
+            let ordered = |update: &Vec<u32>| {
+                (0..update.len())
+                    .all(|i| (i + 1..update.len()).all(|j| !before(update[j], update[i])))
+            };
+            let sum = self
+                .updates
+                .iter()
+                .filter(|update| ordered(update) == (part == 1))
+                .map(|update| {
+                    let mut update = update.clone();
+                    // The rules order every pair, so there is always a swap to make.
+                    while let Some(i) =
+                        (1..update.len()).find(|&i| before(update[i], update[i - 1]))
+                    {
+                        update.swap(i - 1, i);
+                    }
+                    update[update.len() / 2]
+                })
+                .sum::<u32>();
+            sum.into()
+        }
     }
 }
@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day05::Day05;
+
+fn main() {
+    let input = read_input(2024, 5);
+    let input = Day05::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day05::part1(&input));
+    println!("Part 2 = {}", Day05::part2(&input));
+}
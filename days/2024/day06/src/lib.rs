@@ -0,0 +1,341 @@
+use std::{
+    collections::HashSet,
+    fmt::{self, Display, Formatter},
+};
+
+use common::{
+    generate::Generated,
+    geom::Direction,
+    grid::Grid,
+    observe::{Observer, View},
+    random::Rng,
+    Answer, Example, ParseError, Solution,
+};
+
+type Position = (usize, usize);
+
+#[derive(Debug, Clone)]
+enum Cell {
+    Floor,
+    Obstruction,
+}
+
+#[derive(Debug)]
+pub struct LabMap {
+    grid: Grid<Cell>,
+}
+
+impl LabMap {
+    fn cell_at(&self, position: Position) -> &Cell {
+        &self.grid[position]
+    }
+
+    fn block_cell_at(&self, position: Position) -> Self {
+        let mut grid = self.grid.clone();
+        grid[position] = Cell::Obstruction;
+        Self { grid }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Guard {
+    position: Position,
+    direction: Direction,
+}
+
+impl Guard {
+    fn step(&self, map: &LabMap) -> Option<Self> {
+        let next_position = map.grid.step(self.position, self.direction)?;
+        match map.cell_at(next_position) {
+            Cell::Floor => Some(Self {
+                position: next_position,
+                direction: self.direction,
+            }),
+            Cell::Obstruction => Some(Self {
+                position: self.position,
+                direction: self.direction.turn_right(),
+            }),
+        }
+    }
+
+    fn walk(&self, map: &LabMap) -> Vec<Position> {
+        self.walk_observed(map, &mut ())
+    }
+
+    fn walk_observed<O: Observer + ?Sized>(&self, map: &LabMap, observer: &mut O) -> Vec<Position> {
+        let mut positions = vec![self.position];
+        let mut visited = HashSet::from([self.position]);
+        let mut guards = HashSet::from([self.clone()]);
+        let mut current = self.clone();
+        observer.observe(&Patrol {
+            map,
+            guard: &current,
+            visited: &visited,
+        });
+        while let Some(guard) = current.step(map) {
+            current = guard.clone();
+            if visited.insert(guard.position) {
+                positions.push(guard.position);
+            }
+            if !guards.insert(guard) {
+                return vec![];
+            }
+            observer.observe(&Patrol {
+                map,
+                guard: &current,
+                visited: &visited,
+            });
+        }
+        positions
+    }
+
+    fn count_loops(&self, map: &LabMap) -> usize {
+        let path = self.walk(map);
+        path.iter()
+            .filter_map(|(row, col)| {
+                if (row, col) != (&self.position.0, &self.position.1)
+                    && matches!(map.cell_at((*row, *col)), Cell::Floor)
+                {
+                    let new_map = map.block_cell_at((*row, *col));
+                    if !self.walk(&new_map).is_empty() {
+                        None
+                    } else {
+                        Some(1)
+                    }
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+}
+
+/// The lab as the guard walks it, with the positions visited so far marked `X`.
+struct Patrol<'a> {
+    map: &'a LabMap,
+    guard: &'a Guard,
+    visited: &'a HashSet<Position>,
+}
+
+impl Display for Patrol<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let lab = (0..self.map.grid.rows())
+            .map(|row| {
+                (0..self.map.grid.cols())
+                    .map(|col| match self.map.cell_at((row, col)) {
+                        _ if self.guard.position == (row, col) => self.guard.direction.arrow(),
+                        Cell::Obstruction => '#',
+                        Cell::Floor if self.visited.contains(&(row, col)) => 'X',
+                        Cell::Floor => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        write!(f, "{lab}")
+    }
+}
+
+impl View for Patrol<'_> {}
+
+fn parse_input(input: &str) -> Result<(LabMap, Guard), ParseError> {
+    let map = Grid::try_parse(input, |c| match c {
+        '.' | '#' | '^' => Ok(c),
+        c => Err(ParseError::new("`.`, `#` or `^`", &c.to_string())),
+    })?;
+    let position = map
+        .find(&'^')
+        .ok_or_else(|| ParseError::at(input, &input[input.len()..], "a guard `^`"))?;
+    let grid = map.map(|&c| match c {
+        '#' => Cell::Obstruction,
+        _ => Cell::Floor,
+    });
+    Ok((
+        LabMap { grid },
+        Guard {
+            position,
+            direction: Direction::North,
+        },
+    ))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = (LabMap, Guard);
+
+    const EXAMPLES: &'static [Example] =
+        &[
+            Example::new("example", include_str!("../examples/example.txt"))
+                .part1("41")
+                .part2("6"),
+        ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (map, guard) = input;
+        guard.walk(map).len().into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (map, guard) = input;
+        guard.count_loops(map).into()
+    }
+
+    fn simulate(input: &Self::Input<'_>, part: u8, observer: &mut dyn Observer) -> Answer {
+        let (map, guard) = input;
+        match part {
+            1 => guard.walk_observed(map, observer).len().into(),
+            _ => Answer::Unsolved,
+        }
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let size = size.max(1);
+        let mut lab = (0..size)
+            .map(|_| {
+                let row = (0..size).map(|_| if rng.chance(0.04) { '#' } else { '.' });
+                row.collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        // The guard of the puzzle leaves the lab, so a guard walking in a loop starts
+        // again elsewhere.
+        loop {
+            let (row, col) = (rng.below(size), rng.below(size));
+            let previous = std::mem::replace(&mut lab[row][col], '^');
+            let input = lab
+                .iter()
+                .map(|row| format!("{}\n", row.iter().collect::<String>()))
+                .collect::<String>();
+            let (map, guard) = parse_input(&input).unwrap();
+            if !guard.walk(&map).is_empty() {
+                return Some(Generated::new(input));
+            }
+            lab[row][col] = previous;
+        }
+    }
+}
+
+#[cfg(test)]
+mod day06_tests {
+    use std::collections::HashSet;
+
+    use common::{
+        differential::{render_grid, shrink_grid, Case},
+        differential_tests, example_input,
+        observe::FrameCollector,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day06, Lab);
+
+    #[test]
+    fn test_simulate() {
+        let example = example_input::<Day06>("example");
+        let input = Day06::parse(example).unwrap();
+        let mut frames = FrameCollector::default();
+        assert_eq!(Day06::simulate(&input, 1, &mut frames), Answer::Number(41));
+        let frames = frames.into_frames();
+        assert_eq!(frames[0].text, example.trim_end());
+        assert_eq!(
+            frames[1].text.lines().take(6).collect::<Vec<_>>(),
+            vec![
+                "....#.....",
+                ".........#",
+                "..........",
+                "..#.......",
+                ".......#..",
+                "....^.....",
+            ]
+        );
+        assert!(frames
+            .last()
+            .unwrap()
+            .text
+            .starts_with("....#.....\n....XXXXX#"));
+    }
+
+    #[derive(Debug, Clone)]
+    struct Lab(Vec<Vec<char>>);
+
+    impl Lab {
+        /// The cells the guard visits, or `None` if the guard walks in a loop.
+        fn patrol(&self) -> Option<HashSet<(i64, i64)>> {
+            let grid = &self.0;
+            let row = grid.iter().position(|row| row.contains(&'^'))?;
+            let col = grid[row].iter().position(|&c| c == '^')?;
+            let (mut position, mut heading) = ((row as i64, col as i64), (-1, 0));
+            let mut states = HashSet::new();
+            while states.insert((position, heading)) {
+                let next = (position.0 + heading.0, position.1 + heading.1);
+                let Some(&cell) = usize::try_from(next.0)
+                    .ok()
+                    .and_then(|row| grid.get(row))
+                    .and_then(|line| line.get(usize::try_from(next.1).ok()?))
+                else {
+                    return Some(states.into_iter().map(|(position, _)| position).collect());
+                };
+                match cell {
+                    '#' => heading = (heading.1, -heading.0),
+                    _ => position = next,
+                }
+            }
+            None
+        }
+    }
+
+    impl Case for Lab {
+        fn generate(rng: &mut Rng) -> Self {
+            loop {
+                let (rows, cols) = (rng.between(1, 8) as usize, rng.between(1, 8) as usize);
+                let mut grid = (0..rows)
+                    .map(|_| {
+                        (0..cols)
+                            .map(|_| if rng.chance(0.2) { '#' } else { '.' })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>();
+                grid[rng.below(rows)][rng.below(cols)] = '^';
+                let lab = Lab(grid);
+                if lab.patrol().is_some() {
+                    return lab;
+                }
+            }
+        }
+
+        fn input(&self) -> String {
+            render_grid(&self.0)
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_grid(&self.0)
+                .into_iter()
+                .map(Lab)
+                .filter(|lab| lab.patrol().is_some())
+                .collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let visited = self.patrol().unwrap();
+            match part {
+                1 => visited.len().into(),
+                _ => (0..self.0.len())
+                    .flat_map(|row| (0..self.0[row].len()).map(move |col| (row, col)))
+                    .filter(|&(row, col)| {
+                        let mut blocked = self.clone();
+                        blocked.0[row][col] == '.' && {
+                            blocked.0[row][col] = '#';
+                            blocked.patrol().is_none()
+                        }
+                    })
+                    .count()
+                    .into(),
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day06::Day06;
+
+fn main() {
+    let input = read_input(2024, 6);
+    let input = Day06::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day06::part1(&input));
+    println!("Part 2 = {}", Day06::part2(&input));
+}
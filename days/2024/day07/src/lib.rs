@@ -0,0 +1,340 @@
+use std::str::FromStr;
+
+use common::{
+    bigint::{checked, Arithmetic, BigUint, Integer, Overflow, ARITHMETIC},
+    generate::Generated,
+    parse::{key_value, parse_at, parse_lines},
+    random::Rng,
+    Answer, Example, Param, Params, ParseError, Solution,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct Operation<N> {
+    total: N,
+    operands: Vec<N>,
+}
+
+impl<N: Integer> FromStr for Operation<N> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (total, operands) = key_value(s, s, ":")?;
+        let total = parse_at(s, total, "a test value")?;
+        let operands = operands
+            .split_whitespace()
+            .map(|n| parse_at(s, n, "an operand"))
+            .collect::<Result<Vec<_>, _>>()?;
+        if operands.is_empty() {
+            return Err(ParseError::at(s, &s[s.len()..], "an operand"));
+        }
+        Ok(Self { total, operands })
+    }
+}
+
+impl<N: Integer> Operation<N> {
+    fn apply<T>(&self, op: T) -> Result<Self, Overflow>
+    where
+        T: Fn(N, N) -> Result<N, Overflow>,
+    {
+        let (operand1, operand2) = (self.operands[0].clone(), self.operands[1].clone());
+        let new_operand = op(operand1, operand2)?;
+        let mut operands = vec![new_operand];
+        operands.extend_from_slice(&self.operands[2..]);
+        Ok(Self {
+            total: self.total.clone(),
+            operands,
+        })
+    }
+}
+
+fn validate_operation<N, T>(
+    operation: &Operation<N>,
+    operators: &[T],
+) -> Result<Option<N>, Overflow>
+where
+    N: Integer,
+    T: Fn(N, N) -> Result<N, Overflow>,
+{
+    if operation.operands.len() == 1 {
+        let valid = *operation.operands.first().unwrap() == operation.total;
+        return Ok(valid.then(|| operation.total.clone()));
+    }
+
+    operators
+        .iter()
+        .map(|op| {
+            let new_operation = operation.apply(op)?;
+            validate_operation(&new_operation, operators)
+        })
+        .find_map(Result::transpose)
+        .transpose()
+}
+
+fn parse_operations<N: Integer>(input: &str) -> Result<Vec<Operation<N>>, ParseError> {
+    parse_lines(input, input)
+}
+
+fn sum_of_valid<N, T>(
+    operations: &[Operation<N>],
+    operators: &[T],
+    arithmetic: Arithmetic,
+) -> Result<N, Overflow>
+where
+    N: Integer,
+    T: Fn(N, N) -> Result<N, Overflow>,
+{
+    operations.iter().try_fold(N::from(0), |sum, op| {
+        match validate_operation(op, operators)? {
+            Some(total) => sum.plus(&total, arithmetic),
+            None => Ok(sum),
+        }
+    })
+}
+
+/// The digits of `b` written after those of `a`.
+fn concatenate<N: Integer>(a: N, b: N, arithmetic: Arithmetic) -> Result<N, Overflow> {
+    let joined = format!("{a}{b}").parse().ok();
+    match arithmetic {
+        Arithmetic::Machine => Ok(joined.unwrap()),
+        _ => checked(joined, "a concatenation"),
+    }
+}
+
+fn calibrate<N: Integer>(
+    operations: &[Operation<N>],
+    concatenation: bool,
+    arithmetic: Arithmetic,
+) -> Answer {
+    let add = |a: N, b: N| a.plus(&b, arithmetic);
+    let mul = |a: N, b: N| a.times(&b, arithmetic);
+    let concat = |a: N, b: N| concatenate(a, b, arithmetic);
+    let mut operators: Vec<&dyn Fn(N, N) -> Result<N, Overflow>> = vec![&add, &mul];
+    if concatenation {
+        operators.push(&concat);
+    }
+    sum_of_valid(operations, &operators, arithmetic).into()
+}
+
+/// The equations with machine integers, or with big ones for [`Arithmetic::Big`].
+pub enum Equations {
+    Machine(Vec<Operation<u64>>),
+    Big(Vec<Operation<BigUint>>),
+}
+
+pub struct Calibration {
+    equations: Equations,
+    arithmetic: Arithmetic,
+}
+
+impl Calibration {
+    fn total(&self, concatenation: bool) -> Answer {
+        match &self.equations {
+            Equations::Machine(operations) => calibrate(operations, concatenation, self.arithmetic),
+            Equations::Big(operations) => calibrate(operations, concatenation, self.arithmetic),
+        }
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Calibration;
+
+    const EXAMPLES: &'static [Example] =
+        &[
+            Example::new("example", include_str!("../examples/example.txt"))
+                .part1("3749")
+                .part2("11387"),
+        ];
+
+    const PARAMS: &'static [Param] = &[ARITHMETIC];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let arithmetic = Arithmetic::from_params(params);
+        let equations = match arithmetic {
+            Arithmetic::Big => Equations::Big(parse_operations(input)?),
+            _ => Equations::Machine(parse_operations(input)?),
+        };
+        Ok(Calibration {
+            equations,
+            arithmetic,
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input.total(false)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        input.total(true)
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let input = (0..size)
+            .map(|_| {
+                let operands = (0..rng.between(3, 9))
+                    .map(|_| rng.between(1, 99) as u64)
+                    .collect::<Vec<_>>();
+                // Half the equations are made true by random operators, when they fit.
+                let made =
+                    operands[1..]
+                        .iter()
+                        .try_fold(operands[0], |total, &n| match rng.below(3) {
+                            0 => total.checked_add(n),
+                            1 => total.checked_mul(n),
+                            _ => format!("{total}{n}").parse().ok(),
+                        });
+                let total = match made.filter(|_| rng.chance(0.5)) {
+                    Some(total) => total,
+                    None => rng.between(1, 1 << 40) as u64,
+                };
+                let operands = operands.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+                format!("{total}: {}\n", operands.join(" "))
+            })
+            .collect();
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod day07_tests {
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests,
+        random::Rng,
+    };
+    use parameterized::parameterized;
+
+    use super::*;
+
+    differential_tests!(Day07, Equations);
+
+    #[parameterized(
+        input = { "190: 10 19", "3267: 81 40 27", "83: 17 5" },
+        expected = { Operation { total: 190, operands: vec![10, 19] }, Operation { total: 3267, operands: vec![81, 40, 27] }, Operation { total: 83, operands: vec![17, 5] } }
+    )]
+    fn test_parse_input(input: &str, expected: Operation<u64>) {
+        assert_eq!(input.parse(), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_no_operands() {
+        assert_eq!(
+            Day07::parse("190: 10 19\n5:\n").err().unwrap().to_string(),
+            "line 2, column 3: expected an operand, found end of input"
+        );
+    }
+
+    #[parameterized(
+        input = { "190: 10 19", "3267: 81 40 27", "83: 17 5", "156: 15 6", "7290: 6 8 6 15", "161011: 16 10 13", "192: 17 8 14", "21037: 9 7 18 13", "292: 11 6 16 20"},
+        expected = { Some(190), Some(3267), None, None, None, None, None, None, Some(292) }
+    )]
+    fn test_validate_operation(input: &str, expected: Option<u64>) {
+        let operators = vec![|a: u64, b| Ok(a + b), |a, b| Ok(a * b)];
+        assert_eq!(
+            validate_operation(&input.parse().unwrap(), &operators),
+            Ok(expected)
+        );
+    }
+
+    #[parameterized(
+        input = { "156: 15 6", "7290: 6 8 6 15", "192: 17 8 14" },
+        expected = { 156, 7290, 192 }
+    )]
+    fn test_with_concatenation(input: &str, expected: u64) {
+        let operators = vec![|a: u64, b| Ok(a + b), |a, b| Ok(a * b), |a, b| {
+            Ok(format!("{a}{b}").parse().unwrap())
+        }];
+        assert_eq!(
+            validate_operation(&input.parse().unwrap(), &operators),
+            Ok(Some(expected))
+        );
+    }
+
+    #[test]
+    fn test_big_arithmetic() {
+        let input = "100000000010000000000: 1000000000 10000000000\n5: 99999999999 99999999999 5";
+        assert!(Day07::parse(input).is_err());
+        let params = Params::from_pairs(&[("arithmetic", 2)]);
+        let input = Day07::parse_with(input, &params).unwrap();
+        assert_eq!(Day07::part1(&input), Answer::Number(0));
+        assert_eq!(Day07::part2(&input), Answer::Number(100000000010000000000));
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let params = Params::from_pairs(&[("arithmetic", 1)]);
+        let input = Day07::parse_with("5: 99999999999 99999999999 5", &params).unwrap();
+        assert_eq!(Day07::part1(&input), Answer::Unsolved);
+    }
+
+    #[derive(Debug, Clone)]
+    struct Equations(Vec<(u64, Vec<u64>)>);
+
+    /// Every value the operators make of `operands` from left to right.
+    fn results(operands: &[u64], concatenation: bool) -> Vec<u64> {
+        let (&last, rest) = operands.split_last().unwrap();
+        if rest.is_empty() {
+            return vec![last];
+        }
+        results(rest, concatenation)
+            .into_iter()
+            .flat_map(|value| {
+                let mut values = vec![value + last, value * last];
+                if concatenation {
+                    values.push(format!("{value}{last}").parse().unwrap());
+                }
+                values
+            })
+            .collect()
+    }
+
+    impl Case for Equations {
+        fn generate(rng: &mut Rng) -> Self {
+            let equations = (0..rng.between(1, 6))
+                .map(|_| {
+                    let operands = (0..rng.between(1, 5))
+                        .map(|_| rng.between(1, 20) as u64)
+                        .collect::<Vec<_>>();
+                    let total = match rng.chance(0.5) {
+                        true => *rng.pick(&results(&operands, true)),
+                        false => rng.between(1, 500) as u64,
+                    };
+                    (total, operands)
+                })
+                .collect();
+            Equations(equations)
+        }
+
+        fn input(&self) -> String {
+            self.0
+                .iter()
+                .map(|(total, operands)| {
+                    let operands = operands.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+                    format!("{total}: {}\n", operands.join(" "))
+                })
+                .collect()
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_list(&self.0)
+                .into_iter()
+                .filter(|equations| !equations.is_empty())
+                .map(Equations)
+                .collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            self.0
+                .iter()
+                .filter(|(total, operands)| results(operands, part == 2).contains(total))
+                .map(|(total, _)| total)
+                .sum::<u64>()
+                .into()
+        }
+    }
+}
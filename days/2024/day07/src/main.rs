@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day07::Day07;
+
+fn main() {
+    let input = read_input(2024, 7);
+    let input = Day07::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day07::part1(&input));
+    println!("Part 2 = {}", Day07::part2(&input));
+}
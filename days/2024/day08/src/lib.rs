@@ -0,0 +1,330 @@
+use common::{generate::Generated, grid::Grid, random::Rng, Answer, Example, ParseError, Solution};
+
+fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a,
+        b => gcd(b, a % b),
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Antenna {
+    row: usize,
+    col: usize,
+    frequency: char,
+}
+
+impl PartialEq for Antenna {
+    fn eq(&self, other: &Self) -> bool {
+        self.row == other.row && self.col == other.col
+    }
+}
+
+impl Antenna {
+    fn new(row: usize, col: usize, frequency: char) -> Self {
+        Antenna {
+            row,
+            col,
+            frequency,
+        }
+    }
+
+    fn antinode(
+        &self,
+        other: &Antenna,
+        with_resonance: bool,
+        rows: usize,
+        cols: usize,
+    ) -> Vec<(usize, usize)> {
+        let mut delta_col = other.col as isize - self.col as isize;
+        let mut delta_row = other.row as isize - self.row as isize;
+        if with_resonance {
+            // Resonance reaches every cell in line, also those between the antennas.
+            let divisor = gcd(delta_row.unsigned_abs(), delta_col.unsigned_abs()) as isize;
+            delta_row /= divisor;
+            delta_col /= divisor;
+        }
+        let (start, origin) = if with_resonance {
+            (0, self)
+        } else {
+            (1, other)
+        };
+        (start..)
+            .map(|i| (i * delta_row, i * delta_col))
+            .map(|(delta_row, delta_col)| {
+                (
+                    origin.row as isize + delta_row,
+                    origin.col as isize + delta_col,
+                )
+            })
+            .enumerate()
+            .take_while(|(i, (antinode_row, antinode_col))| {
+                let in_bounds = *antinode_col >= 0
+                    && *antinode_col < cols as isize
+                    && *antinode_row >= 0
+                    && *antinode_row < rows as isize;
+                if with_resonance {
+                    in_bounds
+                } else {
+                    in_bounds && *i == 0
+                }
+            })
+            .map(|(_, (antinode_row, antinode_col))| (antinode_row as usize, antinode_col as usize))
+            .collect::<Vec<(usize, usize)>>()
+    }
+}
+
+#[derive(Debug)]
+pub struct Roof {
+    antennas: Vec<Antenna>,
+    rows: usize,
+    cols: usize,
+}
+
+impl TryFrom<&str> for Roof {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let map = Grid::try_parse(value, |c| match c {
+            '.' => Ok(c),
+            c if c.is_ascii_alphanumeric() => Ok(c),
+            c => Err(ParseError::new("`.` or an antenna", &c.to_string())),
+        })?;
+        let antennas = map
+            .iter()
+            .filter(|(_, &c)| c != '.')
+            .map(|((row, col), &frequency)| Antenna::new(row, col, frequency))
+            .collect();
+        Ok(Roof {
+            antennas,
+            rows: map.rows(),
+            cols: map.cols(),
+        })
+    }
+}
+
+impl Roof {
+    fn get_pairs(&self) -> Vec<(Antenna, Antenna)> {
+        self.antennas
+            .iter()
+            .flat_map(|a| {
+                vec![a.clone(); self.antennas.len()]
+                    .into_iter()
+                    .zip(self.antennas.clone())
+                    .collect::<Vec<(Antenna, Antenna)>>()
+            })
+            .filter(|(a0, a1)| a0.frequency == a1.frequency && *a0 != *a1)
+            .map(|(a0, a1)| (a0.clone(), a1))
+            .collect()
+    }
+
+    fn find_antinodes(&self, with_resonance: bool) -> Vec<(usize, usize)> {
+        let mut antinodes = self
+            .get_pairs()
+            .iter()
+            .flat_map(|(antenna1, antenna2)| {
+                antenna1.antinode(antenna2, with_resonance, self.rows, self.cols)
+            })
+            .collect::<Vec<_>>();
+        antinodes.sort();
+        antinodes.dedup();
+        antinodes
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Roof;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("example", include_str!("../examples/example.txt"))
+            .part1("14")
+            .part2("34"),
+        Example::new("harmonics", include_str!("../examples/harmonics.txt")).part2("9"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Roof::try_from(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input.find_antinodes(false).len().into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        input.find_antinodes(true).len().into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let frequencies = ('0'..='9')
+            .chain('a'..='z')
+            .chain('A'..='Z')
+            .collect::<Vec<_>>();
+        let mut city = vec![vec!['.'; size]; size];
+        // About as many antennas as the puzzle puts in its city of 50 by 50.
+        for _ in 0..4 * size {
+            city[rng.below(size)][rng.below(size)] = *rng.pick(&frequencies);
+        }
+        let input = city
+            .iter()
+            .map(|row| format!("{}\n", row.iter().collect::<String>()))
+            .collect();
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod day08_tests {
+    use common::{
+        differential::{render_grid, shrink_grid, Case},
+        differential_tests,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day08, Antennas);
+
+    #[test]
+    fn test_antinode() {
+        let antenna1 = Antenna::new(3, 4, 'a');
+        let antenna2 = Antenna::new(5, 5, 'a');
+        assert_eq!(
+            &(7, 6),
+            antenna1.antinode(&antenna2, false, 10, 10).first().unwrap()
+        );
+        assert_eq!(
+            &(1, 3),
+            antenna2.antinode(&antenna1, false, 10, 10).first().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_antinode_2() {
+        let antenna1 = Antenna::new(3, 4, 'a');
+        let antenna2 = Antenna::new(5, 5, 'a');
+        let antenna3 = Antenna::new(4, 8, 'a');
+        assert_eq!(
+            &(7, 6),
+            antenna1.antinode(&antenna2, false, 10, 10).first().unwrap()
+        );
+        assert_eq!(
+            &(1, 3),
+            antenna2.antinode(&antenna1, false, 10, 10).first().unwrap()
+        );
+        assert_eq!(
+            &(2, 0),
+            antenna3.antinode(&antenna1, false, 10, 10).first().unwrap()
+        );
+        assert_eq!(
+            &(6, 2),
+            antenna3.antinode(&antenna2, false, 10, 10).first().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_resonance_between() {
+        let antenna1 = Antenna::new(0, 0, 'a');
+        let antenna2 = Antenna::new(2, 4, 'a');
+        assert_eq!(
+            antenna1.antinode(&antenna2, true, 5, 5),
+            vec![(0, 0), (1, 2), (2, 4)]
+        );
+        assert_eq!(
+            antenna2.antinode(&antenna1, true, 5, 5),
+            vec![(2, 4), (1, 2), (0, 0)]
+        );
+        let roof = Day08::parse("0.0\n").unwrap();
+        assert_eq!(Day08::part2(&roof), Answer::Number(3));
+    }
+
+    #[test]
+    fn test_get_pairs() {
+        let antenna1 = Antenna::new(3, 4, 'a');
+        let antenna2 = Antenna::new(5, 5, 'a');
+        let antenna3 = Antenna::new(4, 8, 'a');
+        let roof = Roof {
+            antennas: vec![antenna1.clone(), antenna2.clone(), antenna3.clone()],
+            rows: 10,
+            cols: 10,
+        };
+        let pairs = roof.get_pairs();
+        assert_eq!(pairs.len(), 6);
+        assert!(pairs.contains(&(antenna1.clone(), antenna2.clone())));
+        assert!(pairs.contains(&(antenna1.clone(), antenna3.clone())));
+        assert!(pairs.contains(&(antenna2.clone(), antenna1.clone())));
+        assert!(pairs.contains(&(antenna2.clone(), antenna3.clone())));
+        assert!(pairs.contains(&(antenna3.clone(), antenna1.clone())));
+        assert!(pairs.contains(&(antenna3.clone(), antenna2.clone())));
+    }
+
+    #[test]
+    fn test_find_antinodes() {
+        let antenna1 = Antenna::new(3, 4, 'a');
+        let antenna2 = Antenna::new(5, 5, 'a');
+        let antenna3 = Antenna::new(4, 8, 'a');
+        let roof = Roof {
+            antennas: vec![antenna1, antenna2, antenna3],
+            rows: 10,
+            cols: 10,
+        };
+        let antinodes = roof.find_antinodes(false);
+        assert_eq!(antinodes.len(), 4);
+        assert!(antinodes.contains(&(7, 6)));
+        assert!(antinodes.contains(&(1, 3)));
+        assert!(antinodes.contains(&(2, 0)));
+        assert!(antinodes.contains(&(6, 2)));
+    }
+
+    #[derive(Debug, Clone)]
+    struct Antennas(Vec<Vec<char>>);
+
+    impl Case for Antennas {
+        fn generate(rng: &mut Rng) -> Self {
+            let (rows, cols) = (rng.between(1, 10), rng.between(1, 10));
+            let grid = (0..rows)
+                .map(|_| {
+                    (0..cols)
+                        .map(|_| match rng.chance(0.15) {
+                            true => *rng.pick(&['a', 'A', '0']),
+                            false => '.',
+                        })
+                        .collect()
+                })
+                .collect();
+            Antennas(grid)
+        }
+
+        fn input(&self) -> String {
+            render_grid(&self.0)
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_grid(&self.0).into_iter().map(Antennas).collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let cells = (0..self.0.len() as i64)
+                .flat_map(|row| (0..self.0[0].len() as i64).map(move |col| (row, col)))
+                .collect::<Vec<_>>();
+            let at = |(row, col): (i64, i64)| self.0[row as usize][col as usize];
+            let antennas = cells.iter().filter(|&&cell| at(cell) != '.');
+            let pairs = antennas
+                .clone()
+                .flat_map(|&a| antennas.clone().map(move |&b| (a, b)))
+                .filter(|&(a, b)| a != b && at(a) == at(b))
+                .collect::<Vec<_>>();
+            cells
+                .iter()
+                .filter(|&&(row, col)| {
+                    pairs.iter().any(|&((r1, c1), (r2, c2))| match part {
+                        1 => (row, col) == (2 * r2 - r1, 2 * c2 - c1),
+                        _ => (row - r1) * (c2 - c1) == (col - c1) * (r2 - r1),
+                    })
+                })
+                .count()
+                .into()
+        }
+    }
+}
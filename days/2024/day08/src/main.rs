@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day08::Day08;
+
+fn main() {
+    let input = read_input(2024, 8);
+    let input = Day08::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day08::part1(&input));
+    println!("Part 2 = {}", Day08::part2(&input));
+}
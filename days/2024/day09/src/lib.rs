@@ -0,0 +1,412 @@
+use std::fmt::{self, Display, Formatter};
+
+use common::{generate::Generated, random::Rng, Answer, Example, ParseError, Solution};
+
+#[derive(Debug, Clone)]
+enum Space {
+    File(usize),
+    Empty,
+}
+
+impl Display for Space {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Space::File(id) => write!(f, "{id}"),
+            Space::Empty => write!(f, "."),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Block {
+    kind: Space,
+    size: usize,
+}
+
+impl Display for Block {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind.to_string().repeat(self.size))
+    }
+}
+
+impl Block {
+    fn new(kind: Space, size: usize) -> Self {
+        Self { kind, size }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DiskMap {
+    disk: Vec<Block>,
+}
+
+impl Display for DiskMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.disk.iter().try_for_each(|b| write!(f, "{b}"))
+    }
+}
+
+impl TryFrom<&str> for DiskMap {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let digits = value.trim();
+        let (mut disk, _) = digits.char_indices().enumerate().try_fold(
+            (Vec::<Block>::new(), 0),
+            |(mut disk, mut count), (idx, (offset, c))| {
+                let size = c
+                    .to_digit(10)
+                    .ok_or_else(|| ParseError::at(value, &digits[offset..], "a digit"))?
+                    as usize;
+                if idx % 2 == 0 {
+                    disk.push(Block::new(Space::File(count), size));
+                    count += 1;
+                } else {
+                    disk.push(Block::new(Space::Empty, size));
+                }
+                Ok::<_, ParseError>((disk, count))
+            },
+        )?;
+        match disk.last() {
+            None => return Err(ParseError::at(value, digits, "a disk map")),
+            Some(block) if matches!(block.kind, Space::File(_)) => {
+                disk.push(Block::new(Space::Empty, 0))
+            }
+            Some(_) => {}
+        }
+        Ok(Self { disk })
+    }
+}
+
+impl DiskMap {
+    fn move_data(&mut self, from: usize, to: usize) {
+        let mut file = self.disk.remove(from);
+        let space = self.disk.remove(to);
+        if space.size < file.size {
+            file.size -= space.size;
+            self.disk
+                .insert(to, Block::new(file.kind.clone(), space.size));
+            self.disk.insert(from, file.clone());
+            self.disk
+                .insert(from + 1, Block::new(Space::Empty, space.size));
+        } else {
+            self.disk.insert(to, Block::new(file.kind, file.size));
+            self.disk
+                .insert(to + 1, Block::new(Space::Empty, space.size - file.size));
+            self.disk.insert(from, Block::new(Space::Empty, file.size));
+        };
+    }
+
+    fn next_file_to_move(&self, defrag: bool) -> Option<usize> {
+        if defrag {
+            self.disk
+                .iter()
+                .enumerate()
+                .rev()
+                .find(|(file_idx, b)| match b {
+                    Block {
+                        kind: Space::File(_),
+                        size: s,
+                    } => self
+                        .disk
+                        .iter()
+                        .enumerate()
+                        .find(|(space_idx, b)| match b {
+                            Block {
+                                kind: Space::Empty,
+                                size: sf,
+                            } if *sf >= *s => space_idx < file_idx,
+                            _ => false,
+                        })
+                        .is_some(),
+                    _ => false,
+                })
+                .map(|(idx, _)| idx)
+        } else {
+            self.disk
+                .iter()
+                .enumerate()
+                .rev()
+                .find(|(_, b)| {
+                    matches!(
+                        b,
+                        Block {
+                            kind: Space::File(_),
+                            size: _
+                        }
+                    )
+                })
+                .map(|(idx, _)| idx)
+        }
+    }
+
+    fn next_space(&self, size: usize, defrag: bool) -> Option<usize> {
+        if defrag {
+            (0..self.disk.len()).find(|idx| {
+                matches!(
+                    self.disk.get(*idx),
+                    Some(Block {
+                        kind: Space::Empty,
+                        size: s,
+                    }) if *s >= size
+                )
+            })
+        } else {
+            (0..self.disk.len()).find(|idx| {
+                !matches!(
+                    self.disk.get(*idx),
+                    Some(Block {
+                        kind: Space::File(_),
+                        size: _
+                    })
+                )
+            })
+        }
+    }
+
+    fn compact(&mut self) {
+        let mut from = self.next_file_to_move(false);
+        if from.is_none() {
+            return;
+        }
+        let mut to = self.next_space(self.disk.get(from.unwrap()).unwrap().size, false);
+        while from.is_some() && to.is_some() {
+            let from_idx = from.unwrap();
+            let to_idx = to.unwrap();
+            if from_idx < to_idx {
+                return;
+            }
+            self.move_data(from_idx, to_idx);
+            from = self.next_file_to_move(false);
+            if from.is_none() {
+                return;
+            }
+            to = self.next_space(self.disk.get(from.unwrap()).unwrap().size, false);
+        }
+    }
+
+    fn defrag(&mut self) {
+        (1..self.disk.len() - 1).rev().for_each(|idx| {
+            if let Block {
+                kind: Space::File(_),
+                size: s,
+            } = self.disk.get(idx).unwrap()
+            {
+                if let Some(to) = self.next_space(*s, true) {
+                    if to < idx {
+                        self.move_data(idx, to);
+                    }
+                }
+            }
+        });
+    }
+
+    fn checksum(&self) -> usize {
+        let mut count = 0;
+        self.disk
+            .iter()
+            .filter_map(|block| match block.kind {
+                Space::File(id) => {
+                    let sum = (0..block.size)
+                        .map(|_| {
+                            let val = count * id;
+                            count += 1;
+                            val
+                        })
+                        .sum::<usize>();
+                    Some(sum)
+                }
+                Space::Empty => {
+                    count += block.size;
+                    None
+                }
+            })
+            .sum()
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = DiskMap;
+
+    const EXAMPLES: &'static [Example] =
+        &[
+            Example::new("example", include_str!("../examples/example.txt"))
+                .part1("1928")
+                .part2("2858"),
+        ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        DiskMap::try_from(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let mut disk_map = input.clone();
+        disk_map.compact();
+        disk_map.checksum().into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let mut disk_map = input.clone();
+        disk_map.defrag();
+        disk_map.checksum().into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        // The map starts and ends with a file, so it has an odd number of digits.
+        let digits = (0..size.max(1) | 1)
+            .map(|index| match index % 2 {
+                0 => char::from(b'1' + rng.below(9) as u8),
+                _ => char::from(b'0' + rng.below(10) as u8),
+            })
+            .collect::<String>();
+        Some(Generated::new(format!("{digits}\n")))
+    }
+}
+
+#[cfg(test)]
+mod day09_tests {
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests,
+        random::Rng,
+    };
+    use parameterized::parameterized;
+
+    use super::*;
+
+    differential_tests!(Day09, Disk);
+
+    #[parameterized(
+        input = { "12345", "2333133121414131402" },
+        expected = { "0..111....22222", "00...111...2...333.44.5555.6666.777.888899" }
+    )]
+    fn test_parse_input(input: &str, expected: &str) {
+        let disk_map = DiskMap::try_from(input).unwrap();
+        assert_eq!(disk_map.to_string(), expected);
+    }
+
+    #[parameterized(
+        input = { "12345", "2333133121414131402" },
+        expected = { "022111222......", "0099811188827773336446555566.............." }
+    )]
+    fn test_compact(input: &str, expected: &str) {
+        let mut disk_map = DiskMap::try_from(input).unwrap();
+        disk_map.compact();
+        assert_eq!(disk_map.to_string(), expected);
+    }
+
+    #[parameterized(
+        input = { "12345", "123456" },
+        expected = { Some(4), Some(4) }
+    )]
+    fn test_find_next_file(input: &str, expected: Option<usize>) {
+        let disk_map = DiskMap::try_from(input).unwrap();
+        assert_eq!(disk_map.next_file_to_move(false), expected);
+    }
+
+    #[test]
+    fn test_find_next_file_defrag() {
+        let disk_map = DiskMap::try_from("1351346").unwrap();
+        assert_eq!(disk_map.next_file_to_move(true), Some(4));
+    }
+
+    #[parameterized(
+        input = { "12345", "123456" },
+        expected = { Some(1), Some(1) }
+    )]
+    fn test_next_space(input: &str, expected: Option<usize>) {
+        let disk_map = DiskMap::try_from(input).unwrap();
+        assert_eq!(disk_map.next_space(5, false), expected);
+    }
+
+    #[parameterized(
+        input = { "12345", "123456" },
+        expected = { Some(3), Some(3) }
+    )]
+    fn test_next_space_defrag(input: &str, expected: Option<usize>) {
+        let disk_map = DiskMap::try_from(input).unwrap();
+        assert_eq!(disk_map.next_space(3, true), expected);
+    }
+
+    #[test]
+    fn test_defrag() {
+        let input = "2333133121414131402";
+        let mut disk_map = DiskMap::try_from(input).unwrap();
+        disk_map.defrag();
+        assert_eq!(
+            disk_map.to_string(),
+            "00992111777.44.333....5555.6666.....8888.."
+        )
+    }
+
+    #[derive(Debug, Clone)]
+    struct Disk(Vec<(u8, u8)>);
+
+    impl Case for Disk {
+        fn generate(rng: &mut Rng) -> Self {
+            let files = (0..rng.between(1, 12))
+                .map(|_| (rng.between(1, 9) as u8, rng.between(0, 9) as u8))
+                .collect();
+            Disk(files)
+        }
+
+        fn input(&self) -> String {
+            let digits = self
+                .0
+                .iter()
+                .flat_map(|&(file, space)| [file, space])
+                .map(|n| char::from(b'0' + n))
+                .collect::<String>();
+            format!("{}\n", &digits[..digits.len() - 1])
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_list(&self.0)
+                .into_iter()
+                .filter(|files| !files.is_empty())
+                .map(Disk)
+                .collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let mut blocks = self
+                .0
+                .iter()
+                .enumerate()
+                .flat_map(|(id, &(file, space))| {
+                    let file = std::iter::repeat_n(Some(id), file as usize);
+                    file.chain(std::iter::repeat_n(None, space as usize))
+                })
+                .collect::<Vec<_>>();
+            if part == 1 {
+                while let Some(free) = blocks.iter().position(|block| block.is_none()) {
+                    let last = blocks.iter().rposition(|block| block.is_some()).unwrap();
+                    if last < free {
+                        break;
+                    }
+                    blocks.swap(free, last);
+                }
+            } else {
+                for id in (0..self.0.len()).rev() {
+                    let start = blocks.iter().position(|&block| block == Some(id)).unwrap();
+                    let size = self.0[id].0 as usize;
+                    let free = (0..start)
+                        .find(|&free| blocks[free..free + size].iter().all(Option::is_none));
+                    if let Some(free) = free {
+                        for offset in 0..size {
+                            blocks.swap(free + offset, start + offset);
+                        }
+                    }
+                }
+            }
+            blocks
+                .iter()
+                .enumerate()
+                .map(|(position, block)| position * block.unwrap_or(0))
+                .sum::<usize>()
+                .into()
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day09::Day09;
+
+fn main() {
+    let input = read_input(2024, 9);
+    let input = Day09::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day09::part1(&input));
+    println!("Part 2 = {}", Day09::part2(&input));
+}
@@ -0,0 +1,281 @@
+use common::{
+    generate::Generated,
+    grid::Grid,
+    random::Rng,
+    search::{self, AllPaths},
+    Answer, Example, ParseError, Solution,
+};
+
+#[derive(Debug)]
+pub struct TopographicMap {
+    heights: Grid<usize>,
+}
+
+impl TryFrom<&str> for TopographicMap {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let heights = Grid::try_parse(value, |c| {
+            c.to_digit(10)
+                .map(|height| height as usize)
+                .ok_or_else(|| ParseError::new("a height digit", &c.to_string()))
+        })?;
+        Ok(Self { heights })
+    }
+}
+
+impl TopographicMap {
+    fn height_of(&self, spot: (usize, usize)) -> usize {
+        self.heights[spot]
+    }
+
+    fn viable_neighbors(&self, spot: (usize, usize)) -> Vec<(usize, usize)> {
+        self.heights
+            .neighbours4(spot)
+            .filter(|&neighbor| self.height_of(neighbor) == self.height_of(spot) + 1)
+            .collect()
+    }
+
+    fn trail_heads(&self) -> Vec<(usize, usize)> {
+        self.heights
+            .iter()
+            .filter(|(_, &height)| height == 0)
+            .map(|(spot, _)| spot)
+            .collect()
+    }
+
+    fn trails(&self, trail_head: (usize, usize)) -> Option<AllPaths<(usize, usize)>> {
+        search::bfs_all(
+            trail_head,
+            |&spot| self.viable_neighbors(spot),
+            |&spot| self.height_of(spot) == 9,
+        )
+    }
+
+    fn walk(&self, trail_head: (usize, usize)) -> Vec<(usize, usize)> {
+        let mut summits = self
+            .trails(trail_head)
+            .map_or(vec![], |trails| trails.goals().to_vec());
+        summits.sort();
+        summits
+    }
+
+    fn get_trail_head_score(&self, trail_head: (usize, usize)) -> usize {
+        self.walk(trail_head).len()
+    }
+
+    fn get_trail_head_ranking(&self, trail_head: (usize, usize)) -> usize {
+        self.trails(trail_head).map_or(0, |trails| trails.count())
+    }
+
+    fn sum_trail_head_scores(&self) -> usize {
+        self.trail_heads()
+            .iter()
+            .map(|trail_head| self.get_trail_head_score(*trail_head))
+            .sum()
+    }
+
+    fn sum_trail_head_rankings(&self) -> usize {
+        self.trail_heads()
+            .iter()
+            .map(|trail_head| self.get_trail_head_ranking(*trail_head))
+            .sum()
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = TopographicMap;
+
+    const EXAMPLES: &'static [Example] =
+        &[
+            Example::new("example", include_str!("../examples/example.txt"))
+                .part1("36")
+                .part2("81"),
+        ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        TopographicMap::try_from(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input.sum_trail_head_scores().into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        input.sum_trail_head_rankings().into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        // Hills of height 9 slope down one step away from their top, by breadth first
+        // search from every top at once.
+        let mut heights = vec![vec![None; size]; size];
+        let mut frontier = (0..size * size / 40 + 1)
+            .map(|_| (rng.below(size), rng.below(size)))
+            .collect::<Vec<_>>();
+        for height in (0..=9).rev() {
+            let mut next = vec![];
+            for (row, col) in frontier {
+                if heights[row][col].is_some() {
+                    continue;
+                }
+                heights[row][col] = Some(height);
+                next.extend(
+                    [(0, 1), (2, 1), (1, 0), (1, 2)]
+                        .into_iter()
+                        .map(|(dr, dc)| ((row + dr).wrapping_sub(1), (col + dc).wrapping_sub(1)))
+                        .filter(|&(r, c)| r < size && c < size),
+                );
+            }
+            frontier = next;
+        }
+        let input = heights
+            .iter()
+            .map(|row| {
+                let row = row
+                    .iter()
+                    .map(|height| char::from(b'0' + height.unwrap_or(0)));
+                format!("{}\n", row.collect::<String>())
+            })
+            .collect();
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod day10_tests {
+    use std::collections::HashSet;
+
+    use common::{
+        differential::{render_grid, shrink_grid, Case},
+        differential_tests,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day10, Trails);
+
+    #[test]
+    fn test_walk() {
+        let input = r#"9990999
+9991999
+9992999
+6543456
+7111117
+8111118
+9111119"#;
+        let topographic_map = TopographicMap::try_from(input).unwrap();
+        assert_eq!(topographic_map.walk((0, 3)), vec![(6, 0), (6, 6)]);
+    }
+
+    #[test]
+    fn test_get_trail_head_score() {
+        let input = r#"9190919
+9991598
+9992997
+6543456
+7651987
+8761111
+9871999"#;
+        let topographic_map = TopographicMap::try_from(input).unwrap();
+        assert_eq!(topographic_map.get_trail_head_score((0, 3)), 4);
+    }
+
+    #[test]
+    fn test_get_multiple_trail_head_scores() {
+        let input = r#"1091911
+2991819
+3999799
+4567654
+9918193
+9919192
+9991901"#;
+        let topographic_map = TopographicMap::try_from(input).unwrap();
+        assert_eq!(topographic_map.get_trail_head_score((0, 1)), 1);
+        assert_eq!(topographic_map.get_trail_head_score((6, 5)), 2);
+    }
+
+    #[test]
+    fn test_get_trail_ranking() {
+        let input = r#"9999909
+9943219
+9959929
+9965439
+9979949
+9187659
+9191111"#;
+        let topographic_map = TopographicMap::try_from(input).unwrap();
+        assert_eq!(topographic_map.get_trail_head_ranking((0, 5)), 3);
+    }
+
+    #[derive(Debug, Clone)]
+    struct Trails(Vec<Vec<char>>);
+
+    /// The nines reached from (`row`, `col`) climbing one step at a time, once per trail.
+    fn trail_ends(grid: &[Vec<char>], row: usize, col: usize) -> Vec<(usize, usize)> {
+        if grid[row][col] == '9' {
+            return vec![(row, col)];
+        }
+        let next = char::from(grid[row][col] as u8 + 1);
+        [(0, 1), (2, 1), (1, 0), (1, 2)]
+            .into_iter()
+            .filter_map(|(dr, dc)| Some(((row + dr).checked_sub(1)?, (col + dc).checked_sub(1)?)))
+            .filter(|&(r, c)| grid.get(r).and_then(|line| line.get(c)) == Some(&next))
+            .flat_map(|(r, c)| trail_ends(grid, r, c))
+            .collect()
+    }
+
+    impl Case for Trails {
+        fn generate(rng: &mut Rng) -> Self {
+            let (rows, cols) = (rng.between(1, 8) as usize, rng.between(1, 8) as usize);
+            let mut grid = (0..rows)
+                .map(|_| {
+                    (0..cols)
+                        .map(|_| char::from(b'0' + rng.below(10) as u8))
+                        .collect()
+                })
+                .collect::<Vec<Vec<_>>>();
+            // Random digits seldom climb, so paint a few walks from 0 to 9.
+            for _ in 0..rng.between(0, 4) {
+                let (mut row, mut col) = (rng.below(rows), rng.below(cols));
+                for height in b'0'..=b'9' {
+                    grid[row][col] = char::from(height);
+                    match rng.below(4) {
+                        0 if row > 0 => row -= 1,
+                        1 if row + 1 < rows => row += 1,
+                        2 if col > 0 => col -= 1,
+                        3 if col + 1 < cols => col += 1,
+                        _ => break,
+                    }
+                }
+            }
+            Trails(grid)
+        }
+
+        fn input(&self) -> String {
+            render_grid(&self.0)
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_grid(&self.0).into_iter().map(Trails).collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let grid = &self.0;
+            (0..grid.len())
+                .flat_map(|row| (0..grid[row].len()).map(move |col| (row, col)))
+                .filter(|&(row, col)| grid[row][col] == '0')
+                .map(|(row, col)| {
+                    let ends = trail_ends(grid, row, col);
+                    match part {
+                        1 => ends.into_iter().collect::<HashSet<_>>().len(),
+                        _ => ends.len(),
+                    }
+                })
+                .sum::<usize>()
+                .into()
+        }
+    }
+}
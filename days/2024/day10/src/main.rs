@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day10::Day10;
+
+fn main() {
+    let input = read_input(2024, 10);
+    let input = Day10::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day10::part1(&input));
+    println!("Part 2 = {}", Day10::part2(&input));
+}
@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+
+use common::{
+    bigint::{Arithmetic, BigUint, Integer, Overflow, ARITHMETIC},
+    generate::Generated,
+    parse::parse_at,
+    random::Rng,
+    Answer, Example, Param, Params, ParseError, Solution,
+};
+
+/// The stones by the number engraved on them, with how many of each there are.
+#[derive(Debug, Clone)]
+pub struct Stones<N> {
+    pebbles: HashMap<N, N>,
+}
+
+impl<N: Integer> TryFrom<&str> for Stones<N> {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let pebbles = value
+            .split_whitespace()
+            .try_fold(HashMap::new(), |mut pebbles, n| {
+                let count = pebbles
+                    .entry(parse_at(value, n, "a stone number")?)
+                    .or_insert(N::from(0));
+                *count = count
+                    .plus(&N::from(1), Arithmetic::Checked)
+                    .map_err(|_| ParseError::at(value, n, "fewer copies of the stone"))?;
+                Ok::<_, ParseError>(pebbles)
+            })?;
+        Ok(Self { pebbles })
+    }
+}
+
+impl<N: Integer> Stones<N> {
+    fn blink(self, n: usize, arithmetic: Arithmetic) -> Result<Stones<N>, Overflow> {
+        if n == 0 {
+            return Ok(self);
+        }
+        let mut new_pebbles = HashMap::<N, N>::new();
+        let mut add = |k: N, num: &N| {
+            let total = match new_pebbles.get(&k) {
+                Some(v) => v.plus(num, arithmetic)?,
+                None => num.clone(),
+            };
+            new_pebbles.insert(k, total);
+            Ok(())
+        };
+        self.pebbles.iter().try_for_each(|(k, num)| match k {
+            k if *k == N::from(0) => add(N::from(1), num),
+            k if format!("{k}").len() % 2 == 0 => {
+                let as_string = format!("{k}");
+                let (first_half, second_half) = as_string.split_at(as_string.len() / 2);
+                add(Self::half(first_half), num)?;
+                add(Self::half(second_half), num)
+            }
+            k => add(k.times(&N::from(2024), arithmetic)?, num),
+        })?;
+        Self {
+            pebbles: new_pebbles,
+        }
+        .blink(n - 1, arithmetic)
+    }
+
+    fn half(digits: &str) -> N {
+        match digits.parse() {
+            Ok(half) => half,
+            Err(_) => unreachable!("half of a number is a number"),
+        }
+    }
+
+    fn blink_for(self, n: usize, arithmetic: Arithmetic) -> Result<Self, Overflow> {
+        self.blink(n, arithmetic)
+    }
+
+    fn count(&self, arithmetic: Arithmetic) -> Result<N, Overflow> {
+        self.pebbles
+            .values()
+            .try_fold(N::from(0), |total, num| total.plus(num, arithmetic))
+    }
+}
+
+/// The stones with machine integers, or with big ones for [`Arithmetic::Big`].
+#[derive(Debug)]
+enum Line {
+    Machine(Stones<u64>),
+    Big(Stones<BigUint>),
+}
+
+#[derive(Debug)]
+pub struct Arrangement {
+    stones: Line,
+    blinks: [usize; 2],
+    arithmetic: Arithmetic,
+}
+
+impl Arrangement {
+    fn count_after(&self, blinks: usize) -> Answer {
+        match &self.stones {
+            Line::Machine(stones) => stones
+                .clone()
+                .blink_for(blinks, self.arithmetic)
+                .and_then(|stones| stones.count(self.arithmetic))
+                .into(),
+            Line::Big(stones) => stones
+                .clone()
+                .blink_for(blinks, self.arithmetic)
+                .and_then(|stones| stones.count(self.arithmetic))
+                .into(),
+        }
+    }
+}
+
+const SHORT_BLINKS: Param = Param::new("blinks1", 25, "times the stones change in part 1");
+const LONG_BLINKS: Param = Param::new("blinks2", 75, "times the stones change in part 2");
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Arrangement;
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new("example", include_str!("../examples/example.txt")).part1("55312")];
+
+    const PARAMS: &'static [Param] = &[ARITHMETIC, SHORT_BLINKS, LONG_BLINKS];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let arithmetic = Arithmetic::from_params(params);
+        let stones = match arithmetic {
+            Arithmetic::Big => Line::Big(Stones::try_from(input)?),
+            _ => Line::Machine(Stones::try_from(input)?),
+        };
+        Ok(Arrangement {
+            stones,
+            blinks: [SHORT_BLINKS, LONG_BLINKS].map(|param| params.value(&param) as usize),
+            arithmetic,
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input.count_after(input.blinks[0])
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        input.count_after(input.blinks[1])
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let stones = (0..size.max(1))
+            .map(|_| rng.between(0, 9_999_999).to_string())
+            .collect::<Vec<_>>();
+        Some(Generated::new(format!("{}\n", stones.join(" "))))
+    }
+}
+
+#[cfg(test)]
+mod day11_tests {
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests,
+        random::Rng,
+        Params,
+    };
+    use parameterized::parameterized;
+
+    use super::*;
+
+    differential_tests!(Day11, Pebbles);
+
+    #[parameterized(
+        steps = { 1, 2, 3, 4, 5, 6, 25 },
+        expected = { 3, 4, 5, 9, 13, 22, 55312 }
+    )]
+    fn part1(steps: u64, expected: u64) {
+        let input = "125 17";
+        let stones = Stones::<u64>::try_from(input).unwrap();
+        let stones = stones
+            .blink_for(steps as usize, Arithmetic::Machine)
+            .unwrap();
+        assert_eq!(stones.pebbles.values().sum::<u64>(), expected);
+    }
+
+    const HUGE_STONE: &str = "9000000000000000000";
+
+    #[test]
+    fn test_big_arithmetic() {
+        let params = Params::from_pairs(&[("arithmetic", 2), ("blinks1", 5), ("blinks2", 30)]);
+        let input = Day11::parse_with(HUGE_STONE, &params).unwrap();
+        assert_eq!(Day11::part1(&input), Answer::Number(3));
+        assert_eq!(Day11::part2(&input), Answer::Number(144954));
+        let params = Params::from_pairs(&[("arithmetic", 2)]);
+        let input = Day11::parse_with("125 17", &params).unwrap();
+        assert_eq!(Day11::part1(&input), Answer::Number(55312));
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let params = Params::from_pairs(&[("arithmetic", 1)]);
+        let input = Day11::parse_with(HUGE_STONE, &params).unwrap();
+        assert_eq!(Day11::part1(&input), Answer::Unsolved);
+    }
+
+    #[derive(Debug, Clone)]
+    struct Pebbles {
+        stones: Vec<u64>,
+        blinks: (u64, u64),
+        arithmetic: u64,
+    }
+
+    impl Case for Pebbles {
+        fn generate(rng: &mut Rng) -> Self {
+            let stones = (0..rng.between(1, 6))
+                .map(|_| match rng.chance(0.5) {
+                    true => rng.between(0, 20) as u64,
+                    false => rng.between(0, 1_000_000) as u64,
+                })
+                .collect();
+            Pebbles {
+                stones,
+                blinks: (rng.between(0, 6) as u64, rng.between(0, 12) as u64),
+                arithmetic: rng.between(0, 2) as u64,
+            }
+        }
+
+        fn input(&self) -> String {
+            let stones = self
+                .stones
+                .iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>();
+            format!("{}\n", stones.join(" "))
+        }
+
+        fn params(&self) -> Params {
+            Params::from_pairs(&[
+                ("blinks1", self.blinks.0),
+                ("blinks2", self.blinks.1),
+                ("arithmetic", self.arithmetic),
+            ])
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            let mut smaller = shrink_list(&self.stones)
+                .into_iter()
+                .filter(|stones| !stones.is_empty())
+                .map(|stones| Pebbles {
+                    stones,
+                    ..self.clone()
+                })
+                .collect::<Vec<_>>();
+            if self.blinks.0 > 0 {
+                smaller.push(Pebbles {
+                    blinks: (self.blinks.0 - 1, self.blinks.1),
+                    ..self.clone()
+                });
+            }
+            if self.blinks.1 > 0 {
+                smaller.push(Pebbles {
+                    blinks: (self.blinks.0, self.blinks.1 - 1),
+                    ..self.clone()
+                });
+            }
+            smaller
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let blinks = if part == 1 {
+                self.blinks.0
+            } else {
+                self.blinks.1
+            };
+            let mut stones = self.stones.clone();
+            for _ in 0..blinks {
+                stones = stones
+                    .iter()
+                    .flat_map(|&stone| {
+                        let digits = stone.to_string();
+                        if stone == 0 {
+                            vec![1]
+                        } else if digits.len() % 2 == 0 {
+                            let (left, right) = digits.split_at(digits.len() / 2);
+                            vec![left.parse().unwrap(), right.parse().unwrap()]
+                        } else {
+                            vec![stone * 2024]
+                        }
+                    })
+                    .collect();
+            }
+            stones.len().into()
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day11::Day11;
+
+fn main() {
+    let input = read_input(2024, 11);
+    let input = Day11::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day11::part1(&input));
+    println!("Part 2 = {}", Day11::part2(&input));
+}
@@ -1,4 +1,4 @@
-use common::read_input;
+use common::{generate::Generated, grid::Grid, random::Rng, Answer, Example, ParseError, Solution};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 struct Region {
@@ -26,8 +26,8 @@ impl Region {
         }
     }
 
-    fn from(garden: &[&[char]], start: (usize, usize), as_sides: bool) -> Self {
-        let plant = garden[start.0][start.1];
+    fn from(garden: &Grid<char>, start: (usize, usize), as_sides: bool) -> Self {
+        let plant = garden[start];
         let mut queue = vec![start];
         let mut visited = vec![start];
         while !queue.is_empty() {
@@ -47,12 +47,9 @@ impl Region {
     }
 }
 
-fn fences(
-    plots: &Vec<(usize, usize)>,
-) -> (
-    Vec<((isize, isize), (isize, isize))>,
-    Vec<((isize, isize), (isize, isize))>,
-) {
+type Fence = ((isize, isize), (isize, isize));
+
+fn fences(plots: &[(usize, usize)]) -> (Vec<Fence>, Vec<Fence>) {
     plots.iter().fold(
         (vec![], vec![]),
         |(mut v_fences, mut h_fences), (plot_row, plot_col)| {
@@ -79,7 +76,7 @@ fn fences(
     )
 }
 
-fn fences_v_sides(fences: &mut Vec<((isize, isize), (isize, isize))>) -> usize {
+fn fences_v_sides(fences: &mut Vec<Fence>) -> usize {
     let mut sides = 0;
 
     while !fences.is_empty() {
@@ -91,7 +88,7 @@ fn fences_v_sides(fences: &mut Vec<((isize, isize), (isize, isize))>) -> usize {
                 other_outer.1 == current_outer.1 && other_inner.1 == current_inner.1
             })
             .collect::<Vec<_>>();
-        same_axis.sort_by(|(a, _), (b, _)| a.0.cmp(&b.0));
+        same_axis.sort_by_key(|(a, _)| a.0);
 
         let min_index = if current_outer.0 > 0 {
             (0..current_outer.0)
@@ -121,7 +118,7 @@ fn fences_v_sides(fences: &mut Vec<((isize, isize), (isize, isize))>) -> usize {
     sides
 }
 
-fn fences_h_sides(fences: &mut Vec<((isize, isize), (isize, isize))>) -> usize {
+fn fences_h_sides(fences: &mut Vec<Fence>) -> usize {
     let mut sides = 0;
 
     while !fences.is_empty() {
@@ -133,7 +130,7 @@ fn fences_h_sides(fences: &mut Vec<((isize, isize), (isize, isize))>) -> usize {
                 other_outer.0 == current_outer.0 && other_inner.0 == current_inner.0
             })
             .collect::<Vec<_>>();
-        same_axis.sort_by(|(a, _), (b, _)| a.1.cmp(&b.1));
+        same_axis.sort_by_key(|(a, _)| a.1);
 
         let min_index = if current_outer.1 > 0 {
             (0..current_outer.1)
@@ -163,10 +160,7 @@ fn fences_h_sides(fences: &mut Vec<((isize, isize), (isize, isize))>) -> usize {
     sides
 }
 
-fn count_sides(
-    mut v_fences: Vec<((isize, isize), (isize, isize))>,
-    mut h_fences: Vec<((isize, isize), (isize, isize))>,
-) -> usize {
+fn count_sides(mut v_fences: Vec<Fence>, mut h_fences: Vec<Fence>) -> usize {
     let vertical_sides = fences_v_sides(&mut v_fences);
 
     let horizontal_sides = fences_h_sides(&mut h_fences);
@@ -174,35 +168,11 @@ fn count_sides(
     vertical_sides + horizontal_sides
 }
 
-fn neighbors(garden: &[&[char]], (plot_row, plot_col): (usize, usize)) -> Vec<(usize, usize)> {
-    let rows = garden.len();
-    let cols = garden[0].len();
-    let plant = garden[plot_row][plot_col];
-    let mut deltas: Vec<(isize, isize)> = vec![];
-    if plot_row > 0 {
-        deltas.push((-1, 0));
-    };
-    if plot_row < rows - 1 {
-        deltas.push((1, 0));
-    };
-    if plot_col > 0 {
-        deltas.push((0, -1));
-    };
-    if plot_col < cols - 1 {
-        deltas.push((0, 1));
-    };
-    let valid_neighbors =
-        deltas
-            .iter()
-            .fold(vec![], |mut valid_neighbors, (delta_row, delta_col)| {
-                let neighbor_row = (plot_row as isize + delta_row) as usize;
-                let neighbor_col = (plot_col as isize + delta_col) as usize;
-                if garden[neighbor_row][neighbor_col] == plant {
-                    valid_neighbors.push((neighbor_row, neighbor_col));
-                };
-                valid_neighbors
-            });
-    valid_neighbors
+fn neighbors(garden: &Grid<char>, plot: (usize, usize)) -> Vec<(usize, usize)> {
+    garden
+        .neighbours4(plot)
+        .filter(|&neighbor| garden[neighbor] == garden[plot])
+        .collect()
 }
 
 #[derive(Debug)]
@@ -211,22 +181,10 @@ struct Garden {
 }
 
 impl Garden {
-    fn from(value: &str, as_sides: bool) -> Self {
-        let plots = value
-            .trim()
-            .lines()
-            .map(|l| l.trim().chars().map(|c| c).collect::<Vec<char>>())
-            .collect::<Vec<Vec<char>>>();
-        let plots: Vec<&[char]> = plots.iter().map(|plot| plot.as_slice()).collect();
+    fn new(plots: &Grid<char>, as_sides: bool) -> Self {
         let mut regions = plots
-            .iter()
-            .enumerate()
-            .flat_map(|(row, r)| {
-                r.iter()
-                    .enumerate()
-                    .map(|(col, _)| Region::from(&plots, (row, col), as_sides))
-                    .collect::<Vec<Region>>()
-            })
+            .positions()
+            .map(|plot| Region::from(plots, plot, as_sides))
             .collect::<Vec<Region>>();
         regions.sort();
         regions.dedup();
@@ -238,27 +196,93 @@ impl Garden {
     }
 }
 
-fn main() {
-    let input = read_input("day12.txt");
-    let garden = Garden::from(input.as_str(), false);
-    println!("Part 1 = {}", garden.fence_price());
-    let garden = Garden::from(input.as_str(), true);
-    println!("Part 2 = {}", garden.fence_price());
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Grid<char>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("small", include_str!("../examples/small.txt"))
+            .part1("140")
+            .part2("80"),
+        Example::new("enclosed", include_str!("../examples/enclosed.txt"))
+            .part1("772")
+            .part2("436"),
+        Example::new("e-shaped", include_str!("../examples/e-shaped.txt")).part2("236"),
+        Example::new("diagonal", include_str!("../examples/diagonal.txt")).part2("368"),
+        Example::new("larger", include_str!("../examples/larger.txt"))
+            .part1("1930")
+            .part2("1206"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::try_parse(input, Ok)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        Garden::new(input, false).fence_price().into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        Garden::new(input, true).fence_price().into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        // Plants spread from random seeds to the plots nearest them, which makes regions
+        // of all shapes.
+        let mut garden = vec![vec![None; size]; size];
+        let mut frontier = (0..size * size / 30 + 1)
+            .map(|_| {
+                let plant = char::from(b'A' + rng.below(26) as u8);
+                (rng.below(size), rng.below(size), plant)
+            })
+            .collect::<Vec<_>>();
+        while !frontier.is_empty() {
+            rng.shuffle(&mut frontier);
+            let mut next = vec![];
+            for (row, col, plant) in frontier {
+                if garden[row][col].is_some() {
+                    continue;
+                }
+                garden[row][col] = Some(plant);
+                next.extend(
+                    [(0, 1), (2, 1), (1, 0), (1, 2)]
+                        .into_iter()
+                        .map(|(dr, dc)| ((row + dr).wrapping_sub(1), (col + dc).wrapping_sub(1)))
+                        .filter(|&(r, c)| r < size && c < size && garden[r][c].is_none())
+                        .map(|(r, c)| (r, c, plant)),
+                );
+            }
+            frontier = next;
+        }
+        let input = garden
+            .iter()
+            .map(|row| format!("{}\n", row.iter().flatten().collect::<String>()))
+            .collect();
+        Some(Generated::new(input))
+    }
 }
 
 #[cfg(test)]
 mod day12_tests {
+    use common::{
+        differential::{render_grid, shrink_grid, Case},
+        differential_tests, example_input,
+        random::Rng,
+    };
     use parameterized::parameterized;
 
     use super::*;
 
+    differential_tests!(Day12, Plots);
+
     #[test]
     fn test_parse_input_1() {
         let input = r#"AA
 AA"#;
-        let garden = Garden::from(input, false);
+        let garden = Garden::new(&Grid::parse(input, |c| c), false);
         assert_eq!(garden.regions.len(), 1);
-        let region = garden.regions.get(0).unwrap();
+        let region = garden.regions.first().unwrap();
         assert_eq!(region.perimeter, 8);
         assert_eq!(region.area, 4);
     }
@@ -267,9 +291,9 @@ AA"#;
     fn test_parse_input_2() {
         let input = r#"AAAA
 BBBB"#;
-        let garden = Garden::from(input, false);
+        let garden = Garden::new(&Grid::parse(input, |c| c), false);
         assert_eq!(garden.regions.len(), 2);
-        let region1 = garden.regions.get(0).unwrap();
+        let region1 = garden.regions.first().unwrap();
         assert_eq!(region1.perimeter, 10);
         assert_eq!(region1.area, 4);
         let region2 = garden.regions.get(1).unwrap();
@@ -279,11 +303,8 @@ BBBB"#;
 
     #[test]
     fn test_parse_input_3() {
-        let input = r#"AAAA
-BBCD
-BBCC
-EEEC"#;
-        let garden = Garden::from(input, false);
+        let input = example_input::<Day12>("small");
+        let garden = Garden::new(&Grid::parse(input, |c| c), false);
         assert_eq!(garden.regions.len(), 5);
     }
 
@@ -296,43 +317,6 @@ EEEC"#;
         assert_eq!(region.fence_price(), expected);
     }
 
-    #[test]
-    fn test_fence_price_1() {
-        let input = r#"AAAA
-BBCD
-BBCC
-EEEC"#;
-        let garden = Garden::from(input, false);
-        assert_eq!(garden.fence_price(), 140);
-    }
-
-    #[test]
-    fn test_fence_price_2() {
-        let input = r#"OOOOO
-OXOXO
-OOOOO
-OXOXO
-OOOOO"#;
-        let garden = Garden::from(input, false);
-        assert_eq!(garden.fence_price(), 772);
-    }
-
-    #[test]
-    fn part1() {
-        let input = r#"RRRRIICCFF
-RRRRIICCCF
-VVRRRCCFFF
-VVRCCCJFFF
-VVVVCJJCFE
-VVIVCCJJEE
-VVIIICJJEE
-MIIIIIJJEE
-MIIISIJEEE
-MMMISSJEEE"#;
-        let garden = Garden::from(input, false);
-        assert_eq!(garden.fence_price(), 1930);
-    }
-
     #[test]
     fn test_fences() {
         let input = vec![(0, 0), (0, 1)];
@@ -380,63 +364,81 @@ MMMISSJEEE"#;
         assert_eq!(h_sides, expected_h);
     }
 
-    #[test]
-    fn test_fence_price_as_sides_1() {
-        let input = r#"AAAA
-BBCD
-BBCC
-EEEC"#;
-        let garden = Garden::from(input, true);
-        assert_eq!(garden.fence_price(), 80);
-    }
-
-    #[test]
-    fn test_fence_price_as_sides_2() {
-        let input = r#"EEEEE
-EXXXX
-EEEEE
-EXXXX
-EEEEE"#;
-        let garden = Garden::from(input, true);
-        assert_eq!(garden.fence_price(), 236);
-    }
+    #[derive(Debug, Clone)]
+    struct Plots(Vec<Vec<char>>);
+
+    impl Case for Plots {
+        fn generate(rng: &mut Rng) -> Self {
+            let (rows, cols) = (rng.between(1, 7) as usize, rng.between(1, 7) as usize);
+            let plants = &['A', 'B', 'C'][..rng.between(1, 3) as usize];
+            let garden = (0..rows)
+                .map(|_| (0..cols).map(|_| *rng.pick(plants)).collect())
+                .collect();
+            Plots(garden)
+        }
 
-    #[test]
-    fn test_fence_price_as_sides_3() {
-        let input = r#"OOOOO
-OXOXO
-OOOOO
-OXOXO
-OOOOO"#;
-        let garden = Garden::from(input, true);
-        assert_eq!(garden.fence_price(), 436);
-    }
+        fn input(&self) -> String {
+            render_grid(&self.0)
+        }
 
-    #[test]
-    fn test_fence_price_as_sides_4() {
-        let input = r#"AAAAAA
-AAABBA
-AAABBA
-ABBAAA
-ABBAAA
-AAAAAA"#;
-        let garden = Garden::from(input, true);
-        assert_eq!(garden.fence_price(), 368);
-    }
+        fn shrink(&self) -> Vec<Self> {
+            shrink_grid(&self.0).into_iter().map(Plots).collect()
+        }
 
-    #[test]
-    fn test_part2() {
-        let input = r#"RRRRIICCFF
-RRRRIICCCF
-VVRRRCCFFF
-VVRCCCJFFF
-VVVVCJJCFE
-VVIVCCJJEE
-VVIIICJJEE
-MIIIIIJJEE
-MIIISIJEEE
-MMMISSJEEE"#;
-        let garden = Garden::from(input, true);
-        assert_eq!(garden.fence_price(), 1206);
+        fn reference(&self, part: u8) -> Answer {
+            let garden = &self.0;
+            let (rows, cols) = (garden.len() as i64, garden[0].len() as i64);
+            let plant = |row: i64, col: i64| {
+                ((0..rows).contains(&row) && (0..cols).contains(&col))
+                    .then(|| garden[row as usize][col as usize])
+            };
+            let mut region = vec![vec![usize::MAX; cols as usize]; rows as usize];
+            let mut regions = 0;
+            for (row, col) in (0..rows).flat_map(|row| (0..cols).map(move |col| (row, col))) {
+                if region[row as usize][col as usize] != usize::MAX {
+                    continue;
+                }
+                let mut stack = vec![(row, col)];
+                while let Some((r, c)) = stack.pop() {
+                    if plant(r, c) != plant(row, col)
+                        || region[r as usize][c as usize] != usize::MAX
+                    {
+                        continue;
+                    }
+                    region[r as usize][c as usize] = regions;
+                    stack.extend([(r - 1, c), (r + 1, c), (r, c - 1), (r, c + 1)]);
+                }
+                regions += 1;
+            }
+            let same = |row: i64, col: i64, other: (i64, i64)| {
+                plant(other.0, other.1).is_some()
+                    && region[row as usize][col as usize]
+                        == region[other.0 as usize][other.1 as usize]
+            };
+            let fenced = |row: i64, col: i64, (dr, dc): (i64, i64)| {
+                plant(row, col).is_some() && !same(row, col, (row + dr, col + dc))
+            };
+            let mut area = vec![0; regions];
+            let mut fences = vec![0; regions];
+            for (row, col) in (0..rows).flat_map(|row| (0..cols).map(move |col| (row, col))) {
+                let id = region[row as usize][col as usize];
+                area[id] += 1;
+                for direction in [(-1_i64, 0_i64), (1, 0), (0, -1), (0, 1)] {
+                    // A side is counted once, at the fence whose neighbour along it has none.
+                    let (along_row, along_col) = (row - direction.1.abs(), col - direction.0.abs());
+                    let continued = part == 2
+                        && plant(along_row, along_col).is_some()
+                        && same(row, col, (along_row, along_col))
+                        && fenced(along_row, along_col, direction);
+                    if fenced(row, col, direction) && !continued {
+                        fences[id] += 1;
+                    }
+                }
+            }
+            (0..regions)
+                .map(|id| area[id] * fences[id])
+                .sum::<usize>()
+                .into()
+        }
     }
 }
@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day12::Day12;
+
+fn main() {
+    let input = read_input(2024, 12);
+    let input = Day12::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day12::part1(&input));
+    println!("Part 2 = {}", Day12::part2(&input));
+}
@@ -0,0 +1,471 @@
+use std::ops::{Deref, DerefMut};
+
+use common::{
+    bigint::{Arithmetic, BigInt, Integer, Overflow, ARITHMETIC},
+    generate::Generated,
+    parse::{integers_n, labelled_block, sections},
+    random::Rng,
+    Answer, Example, Param, Params, ParseError, Solution,
+};
+
+#[derive(Debug, Clone)]
+struct Matrix2<N> {
+    m: [[N; 2]; 2],
+}
+
+impl<N> TryFrom<Vec<Vec<N>>> for Matrix2<N> {
+    type Error = ParseError;
+
+    fn try_from(value: Vec<Vec<N>>) -> Result<Self, Self::Error> {
+        let rows = value
+            .into_iter()
+            .map(|row| {
+                <[N; 2]>::try_from(row).map_err(|row| {
+                    ParseError::new("a row of 2 numbers", &format!("{} numbers", row.len()))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let m = <[[N; 2]; 2]>::try_from(rows).map_err(|rows| {
+            ParseError::new("a matrix of 2 rows", &format!("{} rows", rows.len()))
+        })?;
+        Ok(Self { m })
+    }
+}
+
+impl<N> Deref for Matrix2<N> {
+    type Target = [[N; 2]; 2];
+
+    fn deref(&self) -> &Self::Target {
+        &self.m
+    }
+}
+
+impl<N> DerefMut for Matrix2<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.m
+    }
+}
+
+impl<N: Integer> Matrix2<N> {
+    fn determinant(&self, arithmetic: Arithmetic) -> Result<N, Overflow> {
+        let diagonal = self[0][0].times(&self[1][1], arithmetic)?;
+        let antidiagonal = self[0][1].times(&self[1][0], arithmetic)?;
+        diagonal.minus(&antidiagonal, arithmetic)
+    }
+
+    fn replace_col(&self, col: usize, values: [N; 2]) -> Self {
+        let mut new_m = self.clone();
+        values
+            .into_iter()
+            .enumerate()
+            .for_each(|(idx, v)| new_m[idx][col] = v);
+        new_m
+    }
+}
+
+struct Equation<N> {
+    m: Matrix2<N>,
+    c: [N; 2],
+}
+
+impl<N: Integer> Equation<N> {
+    fn new(m: Matrix2<N>, c: [N; 2]) -> Self {
+        Self { m, c }
+    }
+
+    fn int_solve(&self, arithmetic: Arithmetic) -> Result<Option<(N, N)>, Overflow> {
+        let zero = N::from(0);
+        let det = self.m.determinant(arithmetic)?;
+        if det == zero {
+            return Ok(None);
+        }
+
+        let det_0 = self
+            .m
+            .replace_col(0, self.c.clone())
+            .determinant(arithmetic)?;
+        let det_1 = self
+            .m
+            .replace_col(1, self.c.clone())
+            .determinant(arithmetic)?;
+        if det_0.clone() % det.clone() != zero {
+            return Ok(None);
+        }
+        if det_1.clone() % det.clone() != zero {
+            return Ok(None);
+        }
+        let x = det_0 / det.clone();
+        if x < zero {
+            return Ok(None);
+        }
+        let y = det_1 / det;
+        if y < zero {
+            return Ok(None);
+        }
+        Ok(Some((x, y)))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ClawMachine<N> {
+    button_a: (N, N),
+    button_b: (N, N),
+    prize: (N, N),
+}
+
+impl<N: Integer> TryFrom<&str> for ClawMachine<N> {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let [button_a, button_b, prize] =
+            labelled_block(value, value, ["Button A:", "Button B:", "Prize:"])?
+                .map(|line| integers_n(value, line).map(|[x, y]| (x, y)));
+        Ok(Self {
+            button_a: button_a?,
+            button_b: button_b?,
+            prize: prize?,
+        })
+    }
+}
+
+impl<N: Integer> ClawMachine<N> {
+    fn with_delta(&self, delta: &N, arithmetic: Arithmetic) -> Result<Self, Overflow> {
+        Ok(Self {
+            prize: (
+                self.prize.0.plus(delta, arithmetic)?,
+                self.prize.1.plus(delta, arithmetic)?,
+            ),
+            ..self.clone()
+        })
+    }
+
+    fn price_for_prize(&self, arithmetic: Arithmetic) -> Result<Option<N>, Overflow> {
+        let coeff = Matrix2 {
+            m: [
+                [self.button_a.0.clone(), self.button_b.0.clone()],
+                [self.button_a.1.clone(), self.button_b.1.clone()],
+            ],
+        };
+        let constants = [self.prize.0.clone(), self.prize.1.clone()];
+        let eq = Equation::new(coeff, constants);
+        if let Some((a_times, b_times)) = eq.int_solve(arithmetic)? {
+            let price = a_times
+                .times(&N::from(3), arithmetic)?
+                .plus(&b_times, arithmetic)?;
+            Ok(Some(price))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+pub struct Arcade<N> {
+    claw_machines: Vec<ClawMachine<N>>,
+}
+
+impl<N: Integer> TryFrom<&str> for Arcade<N> {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let claw_machines = sections(value)
+            .map(|machine| ClawMachine::try_from(machine).map_err(|e| e.within(value, machine)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { claw_machines })
+    }
+}
+
+impl<N: Integer> Arcade<N> {
+    fn with_delta(&self, delta: &N, arithmetic: Arithmetic) -> Result<Self, Overflow> {
+        let claw_machines = self
+            .claw_machines
+            .iter()
+            .map(|machine| machine.with_delta(delta, arithmetic))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { claw_machines })
+    }
+
+    fn find_min_price(&self, arithmetic: Arithmetic) -> Result<N, Overflow> {
+        self.claw_machines
+            .iter()
+            .try_fold(N::from(0), |total, machine| {
+                match machine.price_for_prize(arithmetic)? {
+                    Some(price) => total.plus(&price, arithmetic),
+                    None => Ok(total),
+                }
+            })
+    }
+}
+
+/// The claw machines with machine integers, or with big ones for [`Arithmetic::Big`].
+pub enum Arcades {
+    Machine(Arcade<i64>),
+    Big(Arcade<BigInt>),
+}
+
+pub struct Calibration {
+    arcade: Arcades,
+    delta: i64,
+    arithmetic: Arithmetic,
+}
+
+impl Calibration {
+    fn min_price(&self, delta: i64) -> Answer {
+        let arithmetic = self.arithmetic;
+        match &self.arcade {
+            Arcades::Machine(arcade) => arcade
+                .with_delta(&delta, arithmetic)
+                .and_then(|arcade| arcade.find_min_price(arithmetic))
+                .into(),
+            Arcades::Big(arcade) => arcade
+                .with_delta(&BigInt::from(delta), arithmetic)
+                .and_then(|arcade| arcade.find_min_price(arithmetic))
+                .into(),
+        }
+    }
+}
+
+const DELTA: Param = Param::new(
+    "delta",
+    10000000000000,
+    "distance part 2 adds to each coordinate of the prizes",
+);
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Calibration;
+
+    const EXAMPLES: &'static [Example] =
+        &[
+            Example::new("example", include_str!("../examples/example.txt"))
+                .part1("480")
+                .part2("875318608908"),
+        ];
+
+    const PARAMS: &'static [Param] = &[ARITHMETIC, DELTA];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let arithmetic = Arithmetic::from_params(params);
+        let arcade = match arithmetic {
+            Arithmetic::Big => Arcades::Big(Arcade::try_from(input)?),
+            _ => Arcades::Machine(Arcade::try_from(input)?),
+        };
+        Ok(Calibration {
+            arcade,
+            delta: params.value(&DELTA) as i64,
+            arithmetic,
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input.min_price(0)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        input.min_price(input.delta)
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let machines = (0..size)
+            .map(|_| {
+                // Buttons in line would have many ways to the prize, which the puzzle
+                // never has.
+                let (a, b) = loop {
+                    let a = (rng.between(10, 99), rng.between(10, 99));
+                    let b = (rng.between(10, 99), rng.between(10, 99));
+                    if a.0 * b.1 != a.1 * b.0 {
+                        break (a, b);
+                    }
+                };
+                let prize = match rng.chance(0.5) {
+                    true => {
+                        let (a_times, b_times) = (rng.between(0, 100), rng.between(0, 100));
+                        (a.0 * a_times + b.0 * b_times, a.1 * a_times + b.1 * b_times)
+                    }
+                    false => (rng.between(1000, 20000), rng.between(1000, 20000)),
+                };
+                format!(
+                    "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                    a.0, a.1, b.0, b.1, prize.0, prize.1
+                )
+            })
+            .collect::<Vec<_>>();
+        Some(Generated::new(machines.join("\n")))
+    }
+}
+
+#[cfg(test)]
+mod day13_tests {
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests,
+        random::Rng,
+    };
+    use parameterized::parameterized;
+
+    use super::*;
+
+    differential_tests!(Day13, Machines);
+
+    #[test]
+    fn test_matrix() {
+        let m = Matrix2::<i64>::try_from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(m[0][0], 1);
+        assert_eq!(m[0][1], 2);
+        assert_eq!(m[1][0], 3);
+        assert_eq!(m[1][1], 4);
+        let error = Matrix2::<i64>::try_from(vec![vec![1, 2]]).unwrap_err();
+        assert_eq!(error.expected(), "a matrix of 2 rows");
+        let error = Matrix2::<i64>::try_from(vec![vec![1, 2], vec![3]]).unwrap_err();
+        assert_eq!(error.expected(), "a row of 2 numbers");
+    }
+
+    #[test]
+    fn test_determinant() {
+        let m = Matrix2::<i64>::try_from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(m.determinant(Arithmetic::Machine), Ok(-2));
+    }
+
+    #[test]
+    fn test_replace_col() {
+        let m = Matrix2::<i64>::try_from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(m[0][0], 1);
+        assert_eq!(m[0][1], 2);
+        assert_eq!(m[1][0], 3);
+        assert_eq!(m[1][1], 4);
+        let m2 = m.replace_col(0, [5, 6]);
+        assert_eq!(m2[0][0], 5);
+        assert_eq!(m2[0][1], 2);
+        assert_eq!(m2[1][0], 6);
+        assert_eq!(m2[1][1], 4);
+        let m2 = m.replace_col(1, [5, 6]);
+        assert_eq!(m2[0][0], 1);
+        assert_eq!(m2[0][1], 5);
+        assert_eq!(m2[1][0], 3);
+        assert_eq!(m2[1][1], 6);
+    }
+
+    #[parameterized(
+        m = { vec![vec![-5, -3],vec![-7,5]], vec![vec![1, -5],vec![3, 5]] },
+        c = { vec![-29, -13], vec![5, -17] },
+        expected = { Some((4, 3)), None }
+    )]
+    fn test_solve_equation(m: Vec<Vec<i64>>, c: Vec<i64>, expected: Option<(i64, i64)>) {
+        let eq = Equation::new(m.try_into().unwrap(), c.try_into().unwrap());
+        assert_eq!(eq.int_solve(Arithmetic::Machine), Ok(expected));
+    }
+
+    const FAR_PRIZE: &str = "Button A: X+1000000007, Y+3
+Button B: X+2, Y+1000000009
+Prize: X=9000000000, Y=12000000000";
+
+    #[test]
+    fn test_big_arithmetic() {
+        let params = Params::from_pairs(&[("arithmetic", 2), ("delta", 1000000000000000000)]);
+        let input = Day13::parse_with(FAR_PRIZE, &params).unwrap();
+        assert_eq!(Day13::part1(&input), Answer::Number(0));
+        assert_eq!(Day13::part2(&input), Answer::Number(4000000000));
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let params = Params::from_pairs(&[("arithmetic", 1), ("delta", 1000000000000000000)]);
+        let input = Day13::parse_with(FAR_PRIZE, &params).unwrap();
+        assert_eq!(Day13::part2(&input), Answer::Unsolved);
+    }
+
+    #[derive(Debug, Clone)]
+    struct Machines {
+        machines: Vec<[(i64, i64); 3]>,
+        delta: i64,
+        arithmetic: u64,
+    }
+
+    impl Case for Machines {
+        fn generate(rng: &mut Rng) -> Self {
+            let machines = (0..rng.between(1, 4))
+                .map(|_| loop {
+                    let a = (rng.between(1, 20), rng.between(1, 20));
+                    let b = (rng.between(1, 20), rng.between(1, 20));
+                    // Buttons in line have many ways to the prize, which the puzzle never asks.
+                    if a.0 * b.1 == a.1 * b.0 {
+                        continue;
+                    }
+                    let prize = match rng.chance(0.5) {
+                        true => {
+                            let (a_times, b_times) = (rng.between(0, 100), rng.between(0, 100));
+                            (a.0 * a_times + b.0 * b_times, a.1 * a_times + b.1 * b_times)
+                        }
+                        false => (rng.between(0, 3000), rng.between(0, 3000)),
+                    };
+                    break [a, b, prize];
+                })
+                .collect();
+            Machines {
+                machines,
+                delta: rng.between(0, 60),
+                arithmetic: rng.between(0, 2) as u64,
+            }
+        }
+
+        fn input(&self) -> String {
+            let machines = self
+                .machines
+                .iter()
+                .map(|[a, b, prize]| {
+                    format!(
+                        "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                        a.0, a.1, b.0, b.1, prize.0, prize.1
+                    )
+                })
+                .collect::<Vec<_>>();
+            machines.join("\n")
+        }
+
+        fn params(&self) -> Params {
+            Params::from_pairs(&[
+                ("delta", self.delta as u64),
+                ("arithmetic", self.arithmetic),
+            ])
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            let mut smaller = shrink_list(&self.machines)
+                .into_iter()
+                .filter(|machines| !machines.is_empty())
+                .map(|machines| Machines {
+                    machines,
+                    ..self.clone()
+                })
+                .collect::<Vec<_>>();
+            if self.delta > 0 {
+                smaller.push(Machines {
+                    delta: 0,
+                    ..self.clone()
+                });
+            }
+            smaller
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let delta = if part == 1 { 0 } else { self.delta };
+            self.machines
+                .iter()
+                .filter_map(|[a, b, prize]| {
+                    let prize = (prize.0 + delta, prize.1 + delta);
+                    (0..=prize.0 / a.0)
+                        .filter(|a_times| (prize.0 - a.0 * a_times) % b.0 == 0)
+                        .map(|a_times| (a_times, (prize.0 - a.0 * a_times) / b.0))
+                        .filter(|(a_times, b_times)| a.1 * a_times + b.1 * b_times == prize.1)
+                        .map(|(a_times, b_times)| 3 * a_times + b_times)
+                        .min()
+                })
+                .sum::<i64>()
+                .into()
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day13::Day13;
+
+fn main() {
+    let input = read_input(2024, 13);
+    let input = Day13::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day13::part1(&input));
+    println!("Part 2 = {}", Day13::part2(&input));
+}
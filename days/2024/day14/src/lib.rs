@@ -0,0 +1,440 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use common::{
+    generate::Generated,
+    observe::{Observer, View},
+    parse::{integers_n, labelled, parse_lines, split_at},
+    random::Rng,
+    Answer, Example, Param, Params, ParseError, Solution,
+};
+
+#[derive(Debug, Clone)]
+struct Robot {
+    position: (usize, usize),
+    x_vel: isize,
+    y_vel: isize,
+}
+
+fn parse_vector<T: FromStr>(text: &str, field: &str, label: &str) -> Result<(T, T), ParseError> {
+    let [x, y] = integers_n(text, labelled(text, field, label)?)?;
+    Ok((x, y))
+}
+
+impl FromStr for Robot {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (position, velocity) = split_at(s, s, " ")?;
+        let position = parse_vector(s, position, "p=")?;
+        let (x_vel, y_vel) = parse_vector(s, velocity, "v=")?;
+        Ok(Self {
+            position,
+            x_vel,
+            y_vel,
+        })
+    }
+}
+
+impl Robot {
+    fn step(&self, times: usize, cols: usize, rows: usize) -> Self {
+        let (x_steps, y_steps) = (0..times).fold((0, 0), |(x_steps, y_steps), _| {
+            (x_steps + self.x_vel, y_steps + self.y_vel)
+        });
+        let position_x = self.position.0 as isize + x_steps;
+        let position_x = if position_x > 0 {
+            position_x as usize % cols
+        } else {
+            let delta_x = position_x.unsigned_abs() % cols;
+            (cols - delta_x) % cols
+        };
+        let position_y = self.position.1 as isize + y_steps;
+        let position_y = if position_y > 0 {
+            position_y as usize % rows
+        } else {
+            let delta_y = position_y.unsigned_abs() % rows;
+            (rows - delta_y) % rows
+        };
+        Self {
+            position: (position_x, position_y),
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Restroom {
+    robots: Vec<Robot>,
+    rows: usize,
+    cols: usize,
+}
+
+impl Display for Restroom {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let map = (0..self.rows)
+            .map(|y| {
+                (0..self.cols)
+                    .map(|x| match self.robots_at(x, y) {
+                        0 => ".".to_string(),
+                        n => format!("{n}"),
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        write!(f, "{map}")
+    }
+}
+
+impl View for Restroom {}
+
+impl Restroom {
+    fn new(input: &str, rows: usize, cols: usize) -> Result<Self, ParseError> {
+        let robots = parse_lines(input, input)?;
+        Ok(Self { robots, rows, cols })
+    }
+
+    fn steps(&self, times: usize) -> Self {
+        let robots = self
+            .robots
+            .iter()
+            .map(|r| r.step(times, self.cols, self.rows))
+            .collect();
+        Self {
+            robots,
+            ..self.clone()
+        }
+    }
+
+    /// Moves the robots one second at a time, showing each second to `observer`.
+    fn steps_observed<O: Observer + ?Sized>(&self, times: usize, observer: &mut O) -> Self {
+        let mut restroom = self.clone();
+        observer.observe(&restroom);
+        for _ in 0..times {
+            restroom = restroom.steps(1);
+            observer.observe(&restroom);
+        }
+        restroom
+    }
+
+    fn robots_at(&self, x: usize, y: usize) -> usize {
+        self.robots.iter().filter(|r| r.position == (x, y)).count()
+    }
+
+    fn find_tree(&self) -> usize {
+        (0..self.rows * self.cols)
+            .min_by_key(|&times| self.steps(times).safety_factor())
+            .unwrap()
+    }
+
+    fn safety_factor(&self) -> usize {
+        let mid_x = self.cols / 2;
+        let mid_y = self.rows / 2;
+        let quadrants = self.robots.iter().fold([0; 4], |mut quadrants, robot| {
+            let (x, y) = robot.position;
+            if x != mid_x && y != mid_y {
+                quadrants[usize::from(x > mid_x) + 2 * usize::from(y > mid_y)] += 1;
+            }
+            quadrants
+        });
+        quadrants.iter().product()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Lobby {
+    restroom: Restroom,
+    seconds: usize,
+}
+
+const WIDTH: Param =
+    Param::new("width", 101, "tiles of the space from left to right").range(1, 10_000);
+const HEIGHT: Param =
+    Param::new("height", 103, "tiles of the space from top to bottom").range(1, 10_000);
+const SECONDS: Param = Param::new("seconds", 100, "seconds the robots move in part 1");
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Lobby;
+
+    const EXAMPLES: &'static [Example] =
+        &[
+            Example::new("example", include_str!("../examples/example.txt"))
+                .params(&[("width", 11), ("height", 7)])
+                .part1("12"),
+        ];
+
+    const PARAMS: &'static [Param] = &[WIDTH, HEIGHT, SECONDS];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let rows = params.value(&HEIGHT) as usize;
+        let cols = params.value(&WIDTH) as usize;
+        Ok(Lobby {
+            restroom: Restroom::new(input, rows, cols)?,
+            seconds: params.value(&SECONDS) as usize,
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input.restroom.steps(input.seconds).safety_factor().into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        input.restroom.find_tree().into()
+    }
+
+    fn simulate(input: &Self::Input<'_>, part: u8, observer: &mut dyn Observer) -> Answer {
+        match part {
+            1 => input
+                .restroom
+                .steps_observed(input.seconds, observer)
+                .safety_factor()
+                .into(),
+            _ => Answer::Unsolved,
+        }
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let (width, height) = (WIDTH.default as i64, HEIGHT.default as i64);
+        // A third of the robots meet in a triangle in the top left quadrant at some
+        // second, which part 2 finds.
+        let second = rng.between(0, width * height - 1);
+        let side = ((size / 3) as f64).sqrt().clamp(1.0, (width / 2) as f64) as i64;
+        let input = (0..size)
+            .map(|index| {
+                let velocity = (
+                    rng.between(-width + 1, width - 1),
+                    rng.between(-height + 1, height - 1),
+                );
+                let position = match index % 3 {
+                    0 => {
+                        let row = rng.between(0, side - 1);
+                        (side + rng.between(-row, row), row + 1)
+                    }
+                    _ => (rng.between(0, width - 1), rng.between(0, height - 1)),
+                };
+                let start = (
+                    (position.0 - velocity.0 * second).rem_euclid(width),
+                    (position.1 - velocity.1 * second).rem_euclid(height),
+                );
+                format!(
+                    "p={},{} v={},{}\n",
+                    start.0, start.1, velocity.0, velocity.1
+                )
+            })
+            .collect();
+        Some(Generated::new(input))
+    }
+}
+
+#[cfg(test)]
+mod day14_tests {
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests, example_input,
+        observe::FrameCollector,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day14, Robots);
+
+    #[test]
+    fn test_steps() {
+        let input = "p=2,4 v=2,-3";
+        let mut restroom = Restroom::new(input, 7, 11).unwrap();
+        assert_eq!(
+            restroom.to_string(),
+            r#"...........
+...........
+...........
+...........
+..1........
+...........
+..........."#
+        );
+        restroom = restroom.steps(1);
+        assert_eq!(
+            restroom.to_string(),
+            r#"...........
+....1......
+...........
+...........
+...........
+...........
+..........."#
+        );
+        restroom = restroom.steps(1);
+        assert_eq!(
+            restroom.to_string(),
+            r#"...........
+...........
+...........
+...........
+...........
+......1....
+..........."#
+        );
+        restroom = restroom.steps(1);
+        assert_eq!(
+            restroom.to_string(),
+            r#"...........
+...........
+........1..
+...........
+...........
+...........
+..........."#
+        );
+        restroom = restroom.steps(1);
+        assert_eq!(
+            restroom.to_string(),
+            r#"...........
+...........
+...........
+...........
+...........
+...........
+..........1"#
+        );
+        restroom = restroom.steps(1);
+        assert_eq!(
+            restroom.to_string(),
+            r#"...........
+...........
+...........
+.1.........
+...........
+...........
+..........."#
+        );
+    }
+
+    #[test]
+    fn test_to_string() {
+        let input = example_input::<Day14>("example");
+        let restroom = Restroom::new(input, 7, 11).unwrap();
+        assert_eq!(
+            restroom.to_string(),
+            r#"1.12.......
+...........
+...........
+......11.11
+1.1........
+.........1.
+.......1..."#
+        );
+    }
+
+    #[test]
+    fn test_multiple_steps() {
+        let input = example_input::<Day14>("example");
+        let mut restroom = Restroom::new(input, 7, 11).unwrap();
+        restroom = restroom.steps(100);
+        assert_eq!(
+            restroom.to_string(),
+            r#"......2..1.
+...........
+1..........
+.11........
+.....1.....
+...12......
+.1....1...."#
+        );
+    }
+
+    #[test]
+    fn test_simulate() {
+        let example = Day14::EXAMPLES[0];
+        let input = Day14::parse_with(example.input, &example.to_params()).unwrap();
+        let mut frames = FrameCollector::default();
+        assert_eq!(Day14::simulate(&input, 1, &mut frames), Answer::Number(12));
+        assert_eq!(frames.frames().len(), 101);
+        assert_eq!(
+            frames.frames()[100].text,
+            input.restroom.steps(100).to_string()
+        );
+        assert_eq!(Day14::simulate(&input, 2, &mut frames), Answer::Unsolved);
+    }
+
+    #[derive(Debug, Clone)]
+    struct Robots {
+        robots: Vec<[i64; 4]>,
+        size: (i64, i64),
+        seconds: i64,
+    }
+
+    impl Case for Robots {
+        fn generate(rng: &mut Rng) -> Self {
+            // The middle row and column split the space, so it is odd on both sides.
+            let size = (2 * rng.between(1, 7) + 1, 2 * rng.between(1, 7) + 1);
+            let robots = (0..rng.between(1, 12))
+                .map(|_| {
+                    [
+                        rng.between(0, size.0 - 1),
+                        rng.between(0, size.1 - 1),
+                        rng.between(-size.0, size.0),
+                        rng.between(-size.1, size.1),
+                    ]
+                })
+                .collect();
+            Robots {
+                robots,
+                size,
+                seconds: rng.between(0, 200),
+            }
+        }
+
+        fn input(&self) -> String {
+            self.robots
+                .iter()
+                .map(|[x, y, vx, vy]| format!("p={x},{y} v={vx},{vy}\n"))
+                .collect()
+        }
+
+        fn params(&self) -> Params {
+            Params::from_pairs(&[
+                ("width", self.size.0 as u64),
+                ("height", self.size.1 as u64),
+                ("seconds", self.seconds as u64),
+            ])
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_list(&self.robots)
+                .into_iter()
+                .map(|robots| Robots {
+                    robots,
+                    ..self.clone()
+                })
+                .collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            if part == 2 {
+                return Answer::Unsolved;
+            }
+            let (width, height) = self.size;
+            let mut quadrants = [0; 4];
+            for [x, y, vx, vy] in &self.robots {
+                let (mut x, mut y) = (*x, *y);
+                for _ in 0..self.seconds {
+                    x = (x + vx).rem_euclid(width);
+                    y = (y + vy).rem_euclid(height);
+                }
+                if x != width / 2 && y != height / 2 {
+                    quadrants[2 * usize::from(x > width / 2) + usize::from(y > height / 2)] += 1;
+                }
+            }
+            quadrants.iter().product::<i64>().into()
+        }
+    }
+}
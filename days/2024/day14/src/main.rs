@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day14::Day14;
+
+fn main() {
+    let input = read_input(2024, 14);
+    let input = Day14::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day14::part1(&input));
+    println!("Part 2 = {}", Day14::part2(&input));
+}
@@ -0,0 +1,876 @@
+use std::{
+    cell::RefCell,
+    fmt::{self, Display, Formatter},
+};
+
+use common::{
+    generate::Generated,
+    geom::{Direction, Point},
+    grid::Grid,
+    observe::{Observer, View},
+    parse::split_sections,
+    random::Rng,
+    Answer, Example, ParseError, Solution,
+};
+
+#[derive(Debug, Clone)]
+enum Tile {
+    Wall,
+    Floor,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Tile::Wall => write!(f, "#"),
+            Tile::Floor => write!(f, "."),
+        }
+    }
+}
+
+impl Tile {
+    fn is_walkable(&self) -> bool {
+        match self {
+            Tile::Wall => false,
+            Tile::Floor => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct WarehouseBox {
+    col: usize,
+    row: usize,
+    glyph: String,
+    scaled: bool,
+}
+
+impl WarehouseBox {
+    fn to_string(&self, col: usize) -> String {
+        if self.col == col {
+            self.glyph.chars().nth(0).unwrap().to_string()
+        } else {
+            self.glyph.chars().nth(1).unwrap().to_string()
+        }
+    }
+
+    fn new(col: usize, row: usize, scaled: bool) -> Self {
+        let glyph = match scaled {
+            true => "[]".to_string(),
+            false => "O".to_string(),
+        };
+        Self {
+            col,
+            row,
+            scaled,
+            glyph,
+        }
+    }
+
+    fn gps(&self) -> usize {
+        self.row * 100 + self.col
+    }
+
+    fn is_in(&self, col: usize, row: usize) -> bool {
+        let same_col = if self.scaled {
+            self.col == col || self.col + 1 == col
+        } else {
+            self.col == col
+        };
+        same_col && self.row == row
+    }
+
+    fn will_hit(&self, col: usize, row: usize, movement: &Direction) -> bool {
+        let same_col = if self.scaled {
+            match movement {
+                Direction::North | Direction::South => {
+                    self.col == col || self.col + 1 == col || self.col == col + 1
+                }
+                Direction::East | Direction::West => {
+                    self.col == col || self.col + 1 == col || self.col == col + 1
+                }
+            }
+        } else {
+            self.col == col
+        };
+        same_col && self.row == row
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    map: Grid<Tile>,
+    boxes: Vec<RefCell<WarehouseBox>>,
+    robot: Robot,
+    scaled: bool,
+}
+
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let map = self
+            .map
+            .iter_rows()
+            .enumerate()
+            .map(|(row, r)| {
+                r.iter()
+                    .enumerate()
+                    .map(|(col, tile)| {
+                        if col == self.robot.col && row == self.robot.row {
+                            self.robot.to_string()
+                        } else {
+                            self.boxes
+                                .iter()
+                                .find_map(|b| {
+                                    if b.borrow().is_in(col, row) {
+                                        Some(b.borrow().to_string(col))
+                                    } else {
+                                        None
+                                    }
+                                })
+                                .unwrap_or(tile.to_string())
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        write!(f, "{map}")
+    }
+}
+
+impl Warehouse {
+    fn parse(value: &str, scaled: bool) -> Result<Self, ParseError> {
+        let (warehouse, movements) = split_sections(value, value)?;
+        let movements = movements
+            .char_indices()
+            .filter(|(_, c)| !c.is_whitespace())
+            .map(|(i, c)| {
+                Direction::from_arrow(c)
+                    .ok_or_else(|| ParseError::at(value, &movements[i..], "a movement arrow"))
+            })
+            .collect::<Result<_, _>>()?;
+        let layout = Grid::try_parse(warehouse, |c| match c {
+            '#' | '.' | 'O' | '@' => Ok(c),
+            c => Err(ParseError::new("`#`, `.`, `O` or `@`", &c.to_string())),
+        })
+        .map_err(|e| e.within(value, warehouse))?;
+        // The robot and the boxes only stay on the map if walls surround it.
+        let rows = warehouse.lines().map(str::trim).collect::<Vec<_>>();
+        for (row, line) in rows.iter().enumerate() {
+            let on_border =
+                |col| row == 0 || row == rows.len() - 1 || col == 0 || col == line.len() - 1;
+            if let Some((col, _)) = line
+                .char_indices()
+                .find(|&(col, c)| c != '#' && on_border(col))
+            {
+                return Err(ParseError::at(
+                    value,
+                    &line[col..col + 1],
+                    "a wall `#` around the warehouse",
+                ));
+            }
+        }
+        let (row, col) = layout
+            .find(&'@')
+            .ok_or_else(|| ParseError::at(value, warehouse, "a warehouse with a robot `@`"))?;
+        let num = if scaled { 2 } else { 1 };
+        let robot = Robot::new(col * num, row, movements);
+        let mut boxes = vec![];
+        let map = Grid::from_rows(
+            layout
+                .iter_rows()
+                .enumerate()
+                .map(|(row, cells)| {
+                    cells
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(col, &c)| {
+                            if c == 'O' {
+                                boxes.push(RefCell::new(WarehouseBox::new(col * num, row, scaled)));
+                            }
+                            match c {
+                                '#' => vec![Tile::Wall; num],
+                                _ => vec![Tile::Floor; num],
+                            }
+                        })
+                        .collect()
+                })
+                .collect(),
+        );
+        Ok(Self {
+            map,
+            boxes,
+            robot,
+            scaled,
+        })
+    }
+
+    fn tile_at(&self, col: usize, row: usize) -> &Tile {
+        &self.map[(row, col)]
+    }
+
+    fn can_move_box(&self, wh_box: &RefCell<WarehouseBox>, movement: &Direction) -> bool {
+        let (prev_col, prev_row) = (wh_box.borrow().col, wh_box.borrow().row);
+        let (next_col, next_row) =
+            next_position(*movement, (wh_box.borrow().col, wh_box.borrow().row));
+        let walkable = match self.scaled {
+            true => {
+                self.tile_at(next_col, next_row).is_walkable()
+                    && self.tile_at(next_col + 1, next_row).is_walkable()
+            }
+            false => self.tile_at(next_col, next_row).is_walkable(),
+        };
+        walkable
+            && self
+                .boxes
+                .iter()
+                .filter(|&b| b.borrow().col != prev_col || b.borrow().row != prev_row)
+                .filter(|&b| b.borrow().will_hit(next_col, next_row, movement))
+                .all(|b| self.can_move_box(b, movement))
+    }
+
+    fn move_box(
+        &self,
+        wh_box: &RefCell<WarehouseBox>,
+        movement: &Direction,
+    ) -> Option<(usize, usize)> {
+        let (prev_col, prev_row) = (wh_box.borrow().col, wh_box.borrow().row);
+        let (next_col, next_row) =
+            next_position(*movement, (wh_box.borrow().col, wh_box.borrow().row));
+        let walkable = match self.scaled {
+            true => {
+                self.tile_at(next_col, next_row).is_walkable()
+                    && self.tile_at(next_col + 1, next_row).is_walkable()
+            }
+            false => self.tile_at(next_col, next_row).is_walkable(),
+        };
+        if walkable
+            && self
+                .boxes
+                .iter()
+                .filter(|&b| b.borrow().col != prev_col || b.borrow().row != prev_row)
+                .filter(|&b| b.borrow().will_hit(next_col, next_row, movement))
+                .all(|b| self.can_move_box(b, movement))
+        {
+            self.boxes
+                .iter()
+                .filter(|&b| b.borrow().col != prev_col || b.borrow().row != prev_row)
+                .filter(|&b| b.borrow().will_hit(next_col, next_row, movement))
+                .for_each(|next_box| {
+                    self.move_box(next_box, movement);
+                });
+
+            wh_box.borrow_mut().col = next_col;
+            wh_box.borrow_mut().row = next_row;
+            Some((prev_col, prev_row))
+        } else {
+            None
+        }
+    }
+
+    fn step(mut self, movement: Direction) -> Self {
+        let (next_col, next_row) = next_position(movement, (self.robot.col, self.robot.row));
+        if let Some(next_box) = self
+            .boxes
+            .iter()
+            .find(|&b| b.borrow().is_in(next_col, next_row))
+        {
+            if let Some((_new_col, new_row)) = self.move_box(next_box, &movement) {
+                self.robot.col = (self.robot.col as isize + movement.delta().col) as usize;
+                self.robot.row = new_row;
+            }
+        } else {
+            if self.tile_at(next_col, next_row).is_walkable() {
+                self.robot.col = (self.robot.col as isize + movement.delta().col) as usize;
+                self.robot.row = next_row;
+            }
+        };
+        self
+    }
+
+    fn walk<O: Observer + ?Sized>(mut self, observer: &mut O) -> Self {
+        observer.observe(&Shift {
+            warehouse: &self,
+            movement: None,
+        });
+        while !self.robot.movements.is_empty() {
+            let next_movement = self.robot.movements.remove(0);
+            self = self.step(next_movement);
+            observer.observe(&Shift {
+                warehouse: &self,
+                movement: Some(next_movement),
+            });
+        }
+        self
+    }
+
+    fn gps(&self) -> usize {
+        self.boxes.iter().map(|b| b.borrow().gps()).sum()
+    }
+}
+
+/// The warehouse after the robot tried `movement`, drawn by [`Warehouse`].
+struct Shift<'a> {
+    warehouse: &'a Warehouse,
+    movement: Option<Direction>,
+}
+
+impl Display for Shift<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.warehouse)
+    }
+}
+
+impl View for Shift<'_> {
+    fn caption(&self) -> Option<String> {
+        self.movement
+            .map(|movement| format!("move {}", movement.arrow()))
+    }
+}
+
+fn next_position(movement: Direction, (col, row): (usize, usize)) -> (usize, usize) {
+    let next = Point::from((row, col)) + movement.delta();
+    (next.col as usize, next.row as usize)
+}
+
+#[derive(Debug, Clone)]
+struct Robot {
+    col: usize,
+    row: usize,
+    movements: Vec<Direction>,
+}
+
+impl Display for Robot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "@")
+    }
+}
+
+impl Robot {
+    fn new(col: usize, row: usize, movements: Vec<Direction>) -> Self {
+        Self {
+            row,
+            col,
+            movements,
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = (Warehouse, Warehouse);
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("small", include_str!("../examples/small.txt")).part1("2028"),
+        Example::new("larger", include_str!("../examples/larger.txt"))
+            .part1("10092")
+            .part2("9021"),
+        Example::new("wide", include_str!("../examples/wide.txt")),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok((
+            Warehouse::parse(input, false)?,
+            Warehouse::parse(input, true)?,
+        ))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input.0.clone().walk(&mut ()).gps().into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        input.1.clone().walk(&mut ()).gps().into()
+    }
+
+    fn simulate(input: &Self::Input<'_>, part: u8, observer: &mut dyn Observer) -> Answer {
+        match part {
+            1 => input.0.clone().walk(observer).gps().into(),
+            2 => input.1.clone().walk(observer).gps().into(),
+            _ => Answer::Unsolved,
+        }
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let size = size.max(3);
+        let mut warehouse = (0..size)
+            .map(|row| {
+                (0..size)
+                    .map(|col| match rng.below(20) {
+                        _ if row == 0 || col == 0 || row == size - 1 || col == size - 1 => '#',
+                        0 => '#',
+                        1..=4 => 'O',
+                        _ => '.',
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        warehouse[rng.below(size - 2) + 1][rng.below(size - 2) + 1] = '@';
+        let warehouse = warehouse
+            .iter()
+            .map(|row| format!("{}\n", row.iter().collect::<String>()))
+            .collect::<String>();
+        // As many moves for each tile as the puzzle has, in lines of a thousand.
+        let moves = (0..8 * size * size)
+            .map(|_| *rng.pick(&['<', '>', '^', 'v']))
+            .collect::<Vec<_>>();
+        let moves = moves
+            .chunks(1000)
+            .map(|line| format!("{}\n", line.iter().collect::<String>()))
+            .collect::<String>();
+        Some(Generated::new(format!("{warehouse}\n{moves}")))
+    }
+}
+
+#[cfg(test)]
+mod day15_tests {
+    use std::collections::HashSet;
+
+    use common::{
+        differential::{render_grid, shrink_list, Case},
+        differential_tests, example_input,
+        observe::FrameCollector,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day15, Storage);
+
+    #[test]
+    fn test_parse_and_to_string() {
+        let input = example_input::<Day15>("small");
+        let (input_warehouse, _) = input.split_once("\n\n").unwrap();
+        let warehouse = Warehouse::parse(input, false).unwrap();
+        assert_eq!(warehouse.to_string(), input_warehouse);
+    }
+
+    #[test]
+    fn test_parse_without_walls() {
+        assert_eq!(
+            Warehouse::parse("@\n\n>", false).unwrap_err().to_string(),
+            "line 1, column 1: expected a wall `#` around the warehouse, found `@`"
+        );
+        assert_eq!(
+            Warehouse::parse("###\n#@.\n###\n\n>", false)
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 3: expected a wall `#` around the warehouse, found `.`"
+        );
+    }
+
+    #[test]
+    fn test_step() {
+        let input = example_input::<Day15>("small");
+        let warehouse = Warehouse::parse(input, false).unwrap();
+        let warehouse = warehouse.step(Direction::West);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########"#
+        );
+        let warehouse = warehouse.step(Direction::North);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"########
+#.@O.O.#
+##..O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########"#
+        );
+        let warehouse = warehouse.step(Direction::North);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"########
+#.@O.O.#
+##..O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########"#
+        );
+        let warehouse = warehouse.step(Direction::East);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"########
+#..@OO.#
+##..O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########"#
+        );
+        let warehouse = warehouse.step(Direction::East);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"########
+#...@OO#
+##..O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########"#
+        );
+    }
+
+    #[test]
+    fn test_walk() {
+        let input = example_input::<Day15>("small");
+        let warehouse = Warehouse::parse(input, false).unwrap().walk(&mut ());
+        assert_eq!(
+            warehouse.to_string(),
+            r#"########
+#....OO#
+##.....#
+#.....O#
+#.#O@..#
+#...O..#
+#...O..#
+########"#
+        );
+    }
+
+    #[test]
+    fn test_simulate() {
+        let input = Day15::parse(example_input::<Day15>("small")).unwrap();
+        let mut frames = FrameCollector::default();
+        assert_eq!(
+            Day15::simulate(&input, 1, &mut frames),
+            Answer::Number(2028)
+        );
+        let frames = frames.into_frames();
+        assert_eq!(frames.len(), 16);
+        assert_eq!(frames[0].caption, None);
+        assert_eq!(frames[0].text, input.0.to_string());
+        assert_eq!(frames[1].caption, Some("move <".to_string()));
+        assert_eq!(frames[15].caption, Some("move <".to_string()));
+        assert_eq!(frames[15].text, input.0.clone().walk(&mut ()).to_string());
+    }
+
+    #[test]
+    fn test_walk_2() {
+        let input = example_input::<Day15>("larger");
+        let warehouse = Warehouse::parse(input, false).unwrap().walk(&mut ());
+        assert_eq!(
+            warehouse.to_string(),
+            r#"##########
+#.O.O.OOO#
+#........#
+#OO......#
+#OO@.....#
+#O#.....O#
+#O.....OO#
+#O.....OO#
+#OO....OO#
+##########"#
+        );
+    }
+
+    #[test]
+    fn test_to_string_scaled() {
+        let input = example_input::<Day15>("wide");
+        let warehouse = Warehouse::parse(input, true).unwrap();
+        assert_eq!(
+            warehouse.to_string(),
+            r#"##############
+##......##..##
+##..........##
+##....[][]@.##
+##....[]....##
+##..........##
+##############"#
+        );
+    }
+
+    #[test]
+    fn test_step_scaled() {
+        let input = example_input::<Day15>("wide");
+        let mut warehouse = Warehouse::parse(input, true).unwrap();
+        warehouse = warehouse.step(Direction::West);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"##############
+##......##..##
+##..........##
+##...[][]@..##
+##....[]....##
+##..........##
+##############"#
+        );
+        warehouse = warehouse.step(Direction::South);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"##############
+##......##..##
+##..........##
+##...[][]...##
+##....[].@..##
+##..........##
+##############"#
+        );
+        warehouse = warehouse.step(Direction::South);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"##############
+##......##..##
+##..........##
+##...[][]...##
+##....[]....##
+##.......@..##
+##############"#
+        );
+        warehouse = warehouse.step(Direction::West);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"##############
+##......##..##
+##..........##
+##...[][]...##
+##....[]....##
+##......@...##
+##############"#
+        );
+        warehouse = warehouse.step(Direction::West);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"##############
+##......##..##
+##..........##
+##...[][]...##
+##....[]....##
+##.....@....##
+##############"#
+        );
+        warehouse = warehouse.step(Direction::North);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"##############
+##......##..##
+##...[][]...##
+##....[]....##
+##.....@....##
+##..........##
+##############"#
+        );
+        warehouse = warehouse.step(Direction::North);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"##############
+##......##..##
+##...[][]...##
+##....[]....##
+##.....@....##
+##..........##
+##############"#
+        );
+        warehouse = warehouse.step(Direction::West);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"##############
+##......##..##
+##...[][]...##
+##....[]....##
+##....@.....##
+##..........##
+##############"#
+        );
+        warehouse = warehouse.step(Direction::West);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"##############
+##......##..##
+##...[][]...##
+##....[]....##
+##...@......##
+##..........##
+##############"#
+        );
+        warehouse = warehouse.step(Direction::North);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"##############
+##......##..##
+##...[][]...##
+##...@[]....##
+##..........##
+##..........##
+##############"#
+        );
+        warehouse = warehouse.step(Direction::North);
+        assert_eq!(
+            warehouse.to_string(),
+            r#"##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############"#
+        );
+    }
+
+    #[test]
+    fn part2() {
+        let input = example_input::<Day15>("larger");
+        let mut warehouse = Warehouse::parse(input, true).unwrap();
+        assert_eq!(
+            warehouse.to_string(),
+            r#"####################
+##....[]....[]..[]##
+##............[]..##
+##..[][]....[]..[]##
+##....[]@.....[]..##
+##[]##....[]......##
+##[]....[]....[]..##
+##..[][]..[]..[][]##
+##........[]......##
+####################"#
+        );
+        warehouse = warehouse.walk(&mut ());
+        assert_eq!(
+            warehouse.to_string(),
+            r#"####################
+##[].......[].[][]##
+##[]...........[].##
+##[]........[][][]##
+##[]......[]....[]##
+##..##......[]....##
+##..[]............##
+##..@......[].[][]##
+##......[][]..[]..##
+####################"#
+        );
+        assert_eq!(warehouse.gps(), 9021);
+    }
+
+    #[derive(Debug, Clone)]
+    struct Storage {
+        grid: Vec<Vec<char>>,
+        moves: Vec<char>,
+    }
+
+    /// The grid after the robot `@` tries to move by `delta`, pushing every box cell in
+    /// its way, both halves of the wide ones, unless one of them would hit a wall.
+    fn push(grid: &[Vec<char>], delta: (i64, i64)) -> Vec<Vec<char>> {
+        let row = grid.iter().position(|line| line.contains(&'@')).unwrap();
+        let col = grid[row].iter().position(|&c| c == '@').unwrap();
+        let mut moved = vec![(row as i64, col as i64)];
+        let mut seen = HashSet::from([moved[0]]);
+        let mut index = 0;
+        while let Some(&(row, col)) = moved.get(index) {
+            let next = (row + delta.0, col + delta.1);
+            let halves = match grid[next.0 as usize][next.1 as usize] {
+                '#' => return grid.to_vec(),
+                'O' => vec![next],
+                '[' => vec![next, (next.0, next.1 + 1)],
+                ']' => vec![next, (next.0, next.1 - 1)],
+                _ => vec![],
+            };
+            moved.extend(halves.into_iter().filter(|&cell| seen.insert(cell)));
+            index += 1;
+        }
+        let mut pushed = grid.to_vec();
+        for &(row, col) in &moved {
+            pushed[row as usize][col as usize] = '.';
+        }
+        for &(row, col) in &moved {
+            pushed[(row + delta.0) as usize][(col + delta.1) as usize] =
+                grid[row as usize][col as usize];
+        }
+        pushed
+    }
+
+    impl Case for Storage {
+        fn generate(rng: &mut Rng) -> Self {
+            let (rows, cols) = (rng.between(3, 9) as usize, rng.between(3, 9) as usize);
+            let mut grid = (0..rows)
+                .map(|row| {
+                    (0..cols)
+                        .map(|col| match rng.below(5) {
+                            _ if row == 0 || col == 0 || row == rows - 1 || col == cols - 1 => '#',
+                            0 => '#',
+                            1 | 2 => 'O',
+                            _ => '.',
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            grid[rng.between(1, rows as i64 - 2) as usize]
+                [rng.between(1, cols as i64 - 2) as usize] = '@';
+            let moves = (0..rng.between(1, 30))
+                .map(|_| *rng.pick(&['^', 'v', '<', '>']))
+                .collect();
+            Storage { grid, moves }
+        }
+
+        fn input(&self) -> String {
+            format!(
+                "{}\n{}\n",
+                render_grid(&self.grid),
+                self.moves.iter().collect::<String>()
+            )
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_list(&self.moves)
+                .into_iter()
+                .filter(|moves| !moves.is_empty())
+                .map(|moves| Storage {
+                    moves,
+                    ..self.clone()
+                })
+                .collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let mut grid = match part {
+                1 => self.grid.clone(),
+                _ => self
+                    .grid
+                    .iter()
+                    .map(|line| {
+                        line.iter()
+                            .flat_map(|&c| match c {
+                                'O' => ['[', ']'],
+                                '@' => ['@', '.'],
+                                c => [c, c],
+                            })
+                            .collect()
+                    })
+                    .collect(),
+            };
+            for arrow in &self.moves {
+                let delta = match arrow {
+                    '^' => (-1, 0),
+                    'v' => (1, 0),
+                    '<' => (0, -1),
+                    _ => (0, 1),
+                };
+                grid = push(&grid, delta);
+            }
+            grid.iter()
+                .enumerate()
+                .flat_map(|(row, line)| {
+                    line.iter()
+                        .enumerate()
+                        .filter(|&(_, &c)| c == 'O' || c == '[')
+                        .map(move |(col, _)| 100 * row + col)
+                })
+                .sum::<usize>()
+                .into()
+        }
+    }
+}
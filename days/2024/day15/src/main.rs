@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day15::Day15;
+
+fn main() {
+    let input = read_input(2024, 15);
+    let input = Day15::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day15::part1(&input));
+    println!("Part 2 = {}", Day15::part2(&input));
+}
@@ -0,0 +1,331 @@
+use std::collections::HashSet;
+
+use common::{
+    differential::render_grid,
+    generate::{self, Generated},
+    geom::Direction,
+    grid::Grid,
+    random::Rng,
+    search::{self, AllPaths},
+    Answer, Example, ParseError, Solution,
+};
+
+const TURN_COST: usize = 1000;
+const MOVE_COST: usize = 1;
+
+#[derive(Debug, PartialEq)]
+enum Tile {
+    Floor,
+    Wall,
+    Start,
+    End,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Floor),
+            '#' => Ok(Self::Wall),
+            'S' => Ok(Self::Start),
+            'E' => Ok(Self::End),
+            c => Err(ParseError::new("`.`, `#`, `S` or `E`", &c.to_string())),
+        }
+    }
+}
+
+impl Tile {
+    fn is_walkable(&self) -> bool {
+        match self {
+            Tile::Floor | Tile::Start | Tile::End => true,
+            Tile::Wall => false,
+        }
+    }
+}
+
+type State = ((usize, usize), Direction);
+
+#[derive(Debug)]
+pub struct Maze {
+    tiles: Grid<Tile>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+impl TryFrom<&str> for Maze {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let tiles = Grid::try_parse(value, Tile::try_from)?;
+        let find = |marker: Tile, expected: &str| {
+            tiles
+                .find(&marker)
+                .ok_or_else(|| ParseError::at(value, &value[value.len()..], expected))
+        };
+        let start = find(Tile::Start, "a start tile `S`")?;
+        let end = find(Tile::End, "an end tile `E`")?;
+        Ok(Self { tiles, start, end })
+    }
+}
+
+impl Maze {
+    /// The best paths to the end, if the maze has any.
+    fn best_paths(&self) -> Option<AllPaths<State>> {
+        search::dijkstra_all(
+            (self.start, Direction::East),
+            |&state| self.moves(state),
+            |&(position, _)| position == self.end,
+        )
+    }
+
+    fn find_lowest_score(&self) -> Option<usize> {
+        self.best_paths().map(|paths| paths.cost())
+    }
+
+    fn find_seats(&self) -> Option<usize> {
+        let paths = self.best_paths()?;
+        let seats = paths
+            .states()
+            .into_iter()
+            .map(|(position, _)| position)
+            .collect::<HashSet<_>>();
+        Some(seats.len())
+    }
+
+    fn moves(&self, (position, direction): State) -> Vec<(State, usize)> {
+        let mut moves = vec![
+            ((position, direction.turn_left()), TURN_COST),
+            ((position, direction.turn_right()), TURN_COST),
+        ];
+        if let Some(next) = self.tiles.step(position, direction) {
+            if self.tiles[next].is_walkable() {
+                moves.push(((next, direction), MOVE_COST));
+            }
+        }
+        moves
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Maze;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("example", include_str!("../examples/example.txt"))
+            .part1("7036")
+            .part2("45"),
+        Example::new("second", include_str!("../examples/second.txt"))
+            .part1("11048")
+            .part2("64"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Maze::try_from(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input.find_lowest_score().into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        input.find_seats().into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let mut maze = generate::maze(size, rng);
+        let side = maze.len();
+        // Knocking down some walls gives the reindeer more than one way.
+        for _ in 0..side * side / 20 {
+            let (row, col) = (rng.below(side - 2) + 1, rng.below(side - 2) + 1);
+            if (row + col) % 2 == 1 {
+                maze[row][col] = '.';
+            }
+        }
+        maze[side - 2][1] = 'S';
+        maze[1][side - 2] = 'E';
+        Some(Generated::new(render_grid(&maze)))
+    }
+}
+
+#[cfg(test)]
+mod day16_tests {
+    use std::collections::HashMap;
+
+    use common::{
+        differential::{render_grid, Case},
+        differential_tests,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day16, Labyrinth);
+
+    #[test]
+    fn test_no_path() {
+        let maze = Day16::parse("#####\n#S#E#\n#####\n").unwrap();
+        assert_eq!(Day16::part1(&maze), Answer::Unsolved);
+        assert_eq!(Day16::part2(&maze), Answer::Unsolved);
+    }
+
+    #[derive(Debug, Clone)]
+    struct Labyrinth(Vec<Vec<char>>);
+
+    const STEPS: [(i64, i64); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+    impl Labyrinth {
+        fn find(&self, marker: char) -> Option<(i64, i64)> {
+            (0..self.0.len()).find_map(|row| {
+                let col = self.0[row].iter().position(|&c| c == marker)?;
+                Some((row as i64, col as i64))
+            })
+        }
+
+        fn open(&self, (row, col): (i64, i64)) -> bool {
+            self.0[row as usize][col as usize] != '#'
+        }
+
+        fn reachable(&self) -> bool {
+            let (Some(_), Some(end)) = (self.find('S'), self.find('E')) else {
+                return false;
+            };
+            let scores = self.scores();
+            (0..4).any(|facing| scores.contains_key(&(end.0, end.1, facing)))
+        }
+
+        /// The moves into (`row`, `col`, `facing`) with their cost.
+        fn moves_into(
+            &self,
+            (row, col, facing): (i64, i64, usize),
+        ) -> Vec<((i64, i64, usize), i64)> {
+            let (dr, dc) = STEPS[facing];
+            let mut moves = vec![
+                ((row, col, (facing + 1) % 4), 1000),
+                ((row, col, (facing + 3) % 4), 1000),
+            ];
+            if self.open((row - dr, col - dc)) {
+                moves.push(((row - dr, col - dc, facing), 1));
+            }
+            moves
+        }
+
+        /// The lowest score of every tile and facing, relaxed until none improves.
+        fn scores(&self) -> HashMap<(i64, i64, usize), i64> {
+            let start = self.find('S').unwrap();
+            let mut scores = HashMap::from([((start.0, start.1, 0), 0)]);
+            let states = (0..self.0.len() as i64)
+                .flat_map(|row| (0..self.0[0].len() as i64).map(move |col| (row, col)))
+                .filter(|&tile| self.open(tile))
+                .flat_map(|(row, col)| (0..4).map(move |facing| (row, col, facing)))
+                .collect::<Vec<_>>();
+            let mut changed = true;
+            while changed {
+                changed = false;
+                for &state in &states {
+                    for (previous, cost) in self.moves_into(state) {
+                        let Some(score) = scores.get(&previous).map(|score| score + cost) else {
+                            continue;
+                        };
+                        if scores.get(&state).is_none_or(|&current| score < current) {
+                            scores.insert(state, score);
+                            changed = true;
+                        }
+                    }
+                }
+            }
+            scores
+        }
+    }
+
+    impl Case for Labyrinth {
+        fn generate(rng: &mut Rng) -> Self {
+            loop {
+                let (rows, cols) = (rng.between(3, 8) as usize, rng.between(3, 8) as usize);
+                let mut maze = (0..rows)
+                    .map(|row| {
+                        (0..cols)
+                            .map(|col| {
+                                let border =
+                                    row == 0 || col == 0 || row == rows - 1 || col == cols - 1;
+                                if border || rng.chance(0.3) {
+                                    '#'
+                                } else {
+                                    '.'
+                                }
+                            })
+                            .collect()
+                    })
+                    .collect::<Vec<Vec<_>>>();
+                maze[rows - 2][1] = 'S';
+                maze[1][cols - 2] = 'E';
+                let maze = Labyrinth(maze);
+                if maze.reachable() {
+                    return maze;
+                }
+            }
+        }
+
+        fn input(&self) -> String {
+            render_grid(&self.0)
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            let (rows, cols) = (self.0.len(), self.0[0].len());
+            let without_rows = (1..rows - 1).map(|skip| {
+                let maze = self.0.iter().enumerate().filter(|&(row, _)| row != skip);
+                maze.map(|(_, line)| line.clone()).collect::<Vec<_>>()
+            });
+            let without_cols = (1..cols - 1).map(|skip| {
+                let maze = self.0.iter().map(|line| {
+                    let line = line.iter().enumerate().filter(|&(col, _)| col != skip);
+                    line.map(|(_, &c)| c).collect()
+                });
+                maze.collect::<Vec<_>>()
+            });
+            without_rows
+                .chain(without_cols)
+                .map(Labyrinth)
+                .filter(|maze| maze.reachable())
+                .collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let scores = self.scores();
+            let end = self.find('E').unwrap();
+            let ends = (0..4)
+                .filter_map(|facing| {
+                    Some((
+                        (end.0, end.1, facing),
+                        *scores.get(&(end.0, end.1, facing))?,
+                    ))
+                })
+                .collect::<Vec<_>>();
+            let best = ends.iter().map(|&(_, score)| score).min().unwrap();
+            if part == 1 {
+                return best.into();
+            }
+            let mut on_best = ends
+                .into_iter()
+                .filter(|&(_, score)| score == best)
+                .map(|(state, _)| state)
+                .collect::<Vec<_>>();
+            let mut seen = on_best.iter().copied().collect::<HashSet<_>>();
+            while let Some(state) = on_best.pop() {
+                for (previous, cost) in self.moves_into(state) {
+                    if scores.get(&previous).map(|score| score + cost) == Some(scores[&state])
+                        && seen.insert(previous)
+                    {
+                        on_best.push(previous);
+                    }
+                }
+            }
+            seen.iter()
+                .map(|&(row, col, _)| (row, col))
+                .collect::<HashSet<_>>()
+                .len()
+                .into()
+        }
+    }
+}
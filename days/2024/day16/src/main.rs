@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day16::Day16;
+
+fn main() {
+    let input = read_input(2024, 16);
+    let input = Day16::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day16::part1(&input));
+    println!("Part 2 = {}", Day16::part2(&input));
+}
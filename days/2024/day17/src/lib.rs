@@ -0,0 +1,755 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    fmt::{self, Display, Formatter, Write},
+    ops::BitXor,
+};
+
+use common::{
+    bigint::{checked, Arithmetic, BigUint, Integer, Overflow, ARITHMETIC},
+    generate::Generated,
+    observe::{Observer, View},
+    parse::{labelled_block, list, parse_at},
+    random::Rng,
+    Answer, Example, Param, Params, ParseError, Solution,
+};
+
+/// The values the registers of the computer can hold.
+pub trait Register: Integer + BitXor<Output = Self> {
+    fn from_operand(operand: usize) -> Self;
+
+    /// The value divided by 2 to the power of `exponent`, rounded down.
+    fn halve(&self, exponent: &Self, arithmetic: Arithmetic) -> Result<Self, Overflow>;
+}
+
+impl Register for usize {
+    fn from_operand(operand: usize) -> Self {
+        operand
+    }
+
+    fn halve(&self, exponent: &Self, arithmetic: Arithmetic) -> Result<Self, Overflow> {
+        match arithmetic {
+            Arithmetic::Machine => Ok(self / 2_usize.pow(*exponent as u32)),
+            _ => {
+                let power = u32::try_from(*exponent)
+                    .ok()
+                    .and_then(|exponent| 2_usize.checked_pow(exponent));
+                Ok(self / checked(power, "a power of two")?)
+            }
+        }
+    }
+}
+
+impl Register for BigUint {
+    fn from_operand(operand: usize) -> Self {
+        BigUint::from(operand)
+    }
+
+    fn halve(&self, exponent: &Self, _: Arithmetic) -> Result<Self, Overflow> {
+        Ok(match usize::try_from(exponent) {
+            Ok(exponent) => self >> exponent,
+            Err(_) => BigUint::zero(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Literal(usize),
+    Combo(usize),
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Literal(operand) => write!(f, "{operand}"),
+            Operand::Combo(operand) => write!(f, "{operand}"),
+        }
+    }
+}
+
+impl Operand {
+    /// The operand as written in the program.
+    fn literal(&self) -> usize {
+        match self {
+            Operand::Literal(n) | Operand::Combo(n) => *n,
+        }
+    }
+
+    fn value<N: Register>(&self, registers: &HashMap<char, N>) -> N {
+        match self {
+            Operand::Literal(n) => N::from_operand(*n),
+            Operand::Combo(n) => match n {
+                n if *n <= 3 => N::from_operand(*n),
+                4 => registers[&'a'].clone(),
+                5 => registers[&'b'].clone(),
+                6 => registers[&'c'].clone(),
+                n => unreachable!("combo operand {n} is rejected by the parser"),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Instruction {
+    Adv(Operand),
+    Bxl(Operand),
+    Bst(Operand),
+    Jnz(Operand),
+    Bxc(Operand),
+    Out(Operand),
+    Bdv(Operand),
+    Cdv(Operand),
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Adv(operand) => write!(f, "0,{operand}"),
+            Instruction::Bxl(operand) => write!(f, "1,{operand}"),
+            Instruction::Bst(operand) => write!(f, "2,{operand}"),
+            Instruction::Jnz(operand) => write!(f, "3,{operand}"),
+            Instruction::Bxc(operand) => write!(f, "4,{operand}"),
+            Instruction::Out(operand) => write!(f, "5,{operand}"),
+            Instruction::Bdv(operand) => write!(f, "6,{operand}"),
+            Instruction::Cdv(operand) => write!(f, "7,{operand}"),
+        }
+    }
+}
+
+impl TryFrom<(usize, usize)> for Instruction {
+    type Error = ParseError;
+
+    fn try_from((opcode, operand): (usize, usize)) -> Result<Self, Self::Error> {
+        Ok(match opcode {
+            0 => Self::Adv(Operand::Combo(operand)),
+            1 => Self::Bxl(Operand::Literal(operand)),
+            2 => Self::Bst(Operand::Combo(operand)),
+            3 => Self::Jnz(Operand::Literal(operand)),
+            4 => Self::Bxc(Operand::Literal(operand)),
+            5 => Self::Out(Operand::Combo(operand)),
+            6 => Self::Bdv(Operand::Combo(operand)),
+            7 => Self::Cdv(Operand::Combo(operand)),
+            n => return Err(ParseError::new("an opcode from 0 to 7", &n.to_string())),
+        })
+    }
+}
+
+impl Instruction {
+    fn mnemonic(&self) -> (&'static str, &Operand) {
+        match self {
+            Instruction::Adv(operand) => ("adv", operand),
+            Instruction::Bxl(operand) => ("bxl", operand),
+            Instruction::Bst(operand) => ("bst", operand),
+            Instruction::Jnz(operand) => ("jnz", operand),
+            Instruction::Bxc(operand) => ("bxc", operand),
+            Instruction::Out(operand) => ("out", operand),
+            Instruction::Bdv(operand) => ("bdv", operand),
+            Instruction::Cdv(operand) => ("cdv", operand),
+        }
+    }
+
+    fn apply<N: Register, W: Write>(
+        &self,
+        pointer: usize,
+        registers: &mut HashMap<char, N>,
+        output: &mut W,
+        arithmetic: Arithmetic,
+    ) -> Result<usize, Overflow> {
+        Ok(match self {
+            Instruction::Adv(operand) => {
+                let quotient = registers[&'a'].halve(&operand.value(registers), arithmetic)?;
+                registers.entry('a').and_modify(|value| *value = quotient);
+                pointer + 1
+            }
+            Instruction::Bxl(operand) => {
+                let operand = operand.value(registers);
+                registers
+                    .entry('b')
+                    .and_modify(|value| *value = value.clone() ^ operand);
+                pointer + 1
+            }
+            Instruction::Bst(operand) => {
+                let operand = operand.value(registers);
+                registers
+                    .entry('b')
+                    .and_modify(|value| *value = operand % N::from(8));
+                pointer + 1
+            }
+            Instruction::Jnz(operand) => {
+                if registers[&'a'] == N::from(0) {
+                    pointer + 1
+                } else {
+                    operand.literal() / 2
+                }
+            }
+            Instruction::Bxc(_operand) => {
+                let reg_b = registers[&'b'].clone();
+                let reg_c = registers[&'c'].clone();
+                registers
+                    .entry('b')
+                    .and_modify(|value| *value = reg_b ^ reg_c);
+                pointer + 1
+            }
+            Instruction::Out(operand) => {
+                let operand = operand.value(registers);
+                write!(output, "{},", operand % N::from(8)).unwrap();
+                pointer + 1
+            }
+            Instruction::Bdv(operand) => {
+                let quotient = registers[&'a'].halve(&operand.value(registers), arithmetic)?;
+                registers.entry('b').and_modify(|value| *value = quotient);
+                pointer + 1
+            }
+            Instruction::Cdv(operand) => {
+                let quotient = registers[&'a'].halve(&operand.value(registers), arithmetic)?;
+                registers.entry('c').and_modify(|value| *value = quotient);
+                pointer + 1
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Computer<N> {
+    registers: HashMap<char, N>,
+    instructions: Vec<Instruction>,
+    instruction_pointer: usize,
+    output: String,
+    arithmetic: Arithmetic,
+}
+
+fn parse_instruction(text: &str, pair: &[&str]) -> Result<Instruction, ParseError> {
+    let opcode = parse_at(text, pair[0], "an opcode")?;
+    let operand = match pair.get(1) {
+        Some(operand) => parse_at(text, operand, "an operand")?,
+        None => return Err(ParseError::at(text, &text[text.len()..], "an operand")),
+    };
+    let instruction =
+        Instruction::try_from((opcode, operand)).map_err(|e| e.within(text, pair[0]))?;
+    match instruction.mnemonic() {
+        (_, Operand::Combo(7..)) => {
+            Err(ParseError::at(text, pair[1], "a combo operand from 0 to 6"))
+        }
+        _ => Ok(instruction),
+    }
+}
+
+impl<N: Register> TryFrom<&str> for Computer<N> {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let [a, b, c, program] = labelled_block(
+            value,
+            value,
+            ["Register A:", "Register B:", "Register C:", "Program:"],
+        )?;
+        let registers = [('a', a), ('b', b), ('c', c)]
+            .into_iter()
+            .map(|(name, register)| Ok((name, parse_at(value, register, "a register value")?)))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        let instructions = list(program, ",")
+            .collect::<Vec<_>>()
+            .chunks(2)
+            .map(|pair| parse_instruction(value, pair))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            registers,
+            instructions,
+            instruction_pointer: 0,
+            output: String::new(),
+            arithmetic: Arithmetic::default(),
+        })
+    }
+}
+
+impl<N: Register> Computer<N> {
+    fn execute_program(&mut self) -> Result<(), Overflow> {
+        self.execute_observed(&mut ())
+    }
+
+    fn execute_observed<O: Observer + ?Sized>(&mut self, observer: &mut O) -> Result<(), Overflow> {
+        observer.observe(&Execution {
+            computer: self,
+            pointer: None,
+        });
+        while let Some(instruction) = self.instructions.get(self.instruction_pointer) {
+            let pointer = self.instruction_pointer;
+            self.instruction_pointer = instruction.apply(
+                pointer,
+                &mut self.registers,
+                &mut self.output,
+                self.arithmetic,
+            )?;
+            observer.observe(&Execution {
+                computer: self,
+                pointer: Some(pointer),
+            });
+        }
+        if self.output.ends_with(',') {
+            self.output = self.output.strip_suffix(',').unwrap().to_string();
+        }
+        Ok(())
+    }
+
+    /// The lowest value of register A that makes the program output itself, if any.
+    fn quine(&self) -> Result<Option<N>, Overflow> {
+        let mut values: BinaryHeap<Reverse<(N, usize)>> = BinaryHeap::new();
+
+        // The program as it is written, which the output must match or end with.
+        let target = self.program();
+        // Each octal digit of A outputs at most one value, so A of the quine has no
+        // more digits than the program has values.
+        let digits = self.instructions.len() * 2;
+
+        for i in 1..8 {
+            values.push(Reverse((N::from_operand(i), 1)));
+        }
+
+        while let Some(Reverse((val, length))) = values.pop() {
+            let mut computer = self.clone();
+            computer
+                .registers
+                .entry('a')
+                .and_modify(|value| *value = val.clone())
+                .or_insert(val.clone());
+            computer.output = String::new();
+            computer.execute_program()?;
+            let output = computer.output;
+
+            if output == target {
+                return Ok(Some(val));
+            }
+
+            if length < digits && !output.is_empty() && target.ends_with(&format!(",{output}")) {
+                let shifted = val.times(&N::from(8), self.arithmetic)?;
+                for i in 0..8 {
+                    let next = shifted.plus(&N::from_operand(i), self.arithmetic)?;
+                    values.push(Reverse((next, length + 1)));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn program(&self) -> String {
+        self.instructions
+            .iter()
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// The registers, program and output of the computer after it ran the instruction at
+/// `pointer`.
+struct Execution<'a, N> {
+    computer: &'a Computer<N>,
+    pointer: Option<usize>,
+}
+
+impl<N: Register> Display for Execution<'_, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for register in ['a', 'b', 'c'] {
+            writeln!(
+                f,
+                "Register {}: {}",
+                register.to_ascii_uppercase(),
+                self.computer.registers[&register]
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(f, "Program: {}", self.computer.program())?;
+        writeln!(f, "Pointer: {}", self.computer.instruction_pointer * 2)?;
+        write!(f, "Output: {}", self.computer.output.trim_end_matches(','))
+    }
+}
+
+impl<N: Register> View for Execution<'_, N> {
+    fn caption(&self) -> Option<String> {
+        let pointer = self.pointer?;
+        let (name, operand) = self.computer.instructions[pointer].mnemonic();
+        Some(format!("{}: {name} {operand}", pointer * 2))
+    }
+}
+
+/// The computer with machine registers, or with big ones for [`Arithmetic::Big`].
+#[derive(Debug, Clone)]
+pub enum Device {
+    Machine(Computer<usize>),
+    Big(Computer<BigUint>),
+}
+
+fn run<N: Register>(computer: &Computer<N>, observer: &mut dyn Observer) -> Answer {
+    let mut computer = computer.clone();
+    computer
+        .execute_observed(observer)
+        .map(|()| computer.output)
+        .into()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Device;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("example", include_str!("../examples/example.txt"))
+            .part1("4,6,3,5,6,3,5,2,1,0"),
+        Example::new("quine", include_str!("../examples/quine.txt")).part2("117440"),
+    ];
+
+    const PARAMS: &'static [Param] = &[ARITHMETIC];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let arithmetic = Arithmetic::from_params(params);
+        Ok(match arithmetic {
+            Arithmetic::Big => Device::Big(Computer {
+                arithmetic,
+                ..Computer::try_from(input)?
+            }),
+            _ => Device::Machine(Computer {
+                arithmetic,
+                ..Computer::try_from(input)?
+            }),
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        Self::simulate(input, 1, &mut ())
+    }
+
+    fn simulate(input: &Self::Input<'_>, part: u8, observer: &mut dyn Observer) -> Answer {
+        match (part, input) {
+            (1, Device::Machine(computer)) => run(computer, observer),
+            (1, Device::Big(computer)) => run(computer, observer),
+            _ => Answer::Unsolved,
+        }
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        match input {
+            Device::Machine(computer) => computer.quine().into(),
+            Device::Big(computer) => computer.quine().into(),
+        }
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        // Programs of the puzzle output 3 bits of register A at a time until it is 0, so
+        // the size is the number of octal digits of A. Only some operands let the
+        // program output itself.
+        let program = loop {
+            let program = format!(
+                "2,4,1,{},7,5,1,{},4,{},0,3,5,5,3,0",
+                rng.below(8),
+                rng.below(8),
+                rng.below(8)
+            );
+            let computer = Computer::<usize>::try_from(
+                format!("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: {program}\n")
+                    .as_str(),
+            )
+            .unwrap();
+            if matches!(computer.quine(), Ok(Some(_))) {
+                break program;
+            }
+        };
+        let a = (1..size).fold(BigUint::from(1_u16 + rng.below(7) as u16), |a, _| {
+            let digit = BigUint::from(rng.below(8) as u16);
+            a * BigUint::from(8_u16) + digit
+        });
+        let generated = Generated::new(format!(
+            "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {program}\n"
+        ));
+        // Register A outgrows the machine integers past 21 octal digits.
+        Some(match size > 20 {
+            true => generated.param(ARITHMETIC.name, 2),
+            false => generated,
+        })
+    }
+}
+
+#[cfg(test)]
+mod day17_tests {
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests, example_input,
+        observe::FrameCollector,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day17, Program);
+
+    #[test]
+    fn test_simulate() {
+        let example = example_input::<Day17>("example");
+        let input = Day17::parse(example).unwrap();
+        let mut frames = FrameCollector::default();
+        assert_eq!(
+            Day17::simulate(&input, 1, &mut frames),
+            Answer::Text("4,6,3,5,6,3,5,2,1,0".to_string())
+        );
+        let frames = frames.into_frames();
+        assert_eq!(
+            frames[0].text,
+            format!("{}\nPointer: 0\nOutput: ", example.trim_end())
+        );
+        assert_eq!(frames[1].caption, Some("0: adv 1".to_string()));
+        assert!(frames[1].text.starts_with("Register A: 364\n"));
+        assert_eq!(frames[3].caption, Some("4: jnz 0".to_string()));
+        assert!(frames
+            .last()
+            .unwrap()
+            .text
+            .ends_with("Pointer: 6\nOutput: 4,6,3,5,6,3,5,2,1,0"));
+    }
+
+    #[test]
+    fn test_1() {
+        let input = r#"Register A: 0
+Register B: 0
+Register C: 9
+
+Program: 2,6"#;
+        let mut computer = Computer::<usize>::try_from(input).unwrap();
+        computer.execute_program().unwrap();
+        assert_eq!(*computer.registers.get(&'b').unwrap(), 1);
+    }
+
+    #[test]
+    fn test_2() {
+        let input = r#"Register A: 10
+Register B: 0
+Register C: 0
+
+Program: 5,0,5,1,5,4"#;
+        let mut computer = Computer::<usize>::try_from(input).unwrap();
+        computer.execute_program().unwrap();
+        assert_eq!(computer.output, "0,1,2".to_string());
+    }
+
+    #[test]
+    fn test_3() {
+        let input = r#"Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0"#;
+        let mut computer = Computer::<usize>::try_from(input).unwrap();
+
+        computer.execute_program().unwrap();
+        assert_eq!(computer.output, "4,2,5,6,7,7,7,7,3,1,0".to_string());
+        assert_eq!(*computer.registers.get(&'a').unwrap(), 0);
+    }
+
+    #[test]
+    fn test_4() {
+        let input = r#"Register A: 0
+Register B: 29
+Register C: 0
+
+Program: 1,7"#;
+        let mut computer = Computer::<usize>::try_from(input).unwrap();
+
+        computer.execute_program().unwrap();
+        assert_eq!(*computer.registers.get(&'b').unwrap(), 26);
+    }
+
+    #[test]
+    fn test_5() {
+        let input = r#"Register A: 0
+Register B: 2024
+Register C: 43690
+
+Program: 4,0"#;
+        let mut computer = Computer::<usize>::try_from(input).unwrap();
+
+        computer.execute_program().unwrap();
+        assert_eq!(*computer.registers.get(&'b').unwrap(), 44354);
+    }
+
+    #[test]
+    fn test_no_quine() {
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4";
+        assert_eq!(
+            Day17::part2(&Day17::parse(input).unwrap()),
+            Answer::Unsolved
+        );
+    }
+
+    #[test]
+    fn test_parse_combo_operand() {
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 1,7,2,7";
+        assert_eq!(
+            Day17::parse(input).unwrap_err().to_string(),
+            "line 5, column 16: expected a combo operand from 0 to 6, found `7`"
+        );
+    }
+
+    #[test]
+    fn test_big_arithmetic() {
+        let input = r#"Register A: 1000000000000000000000000000000
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0"#;
+        assert!(Day17::parse(input).is_err());
+        let params = Params::from_pairs(&[("arithmetic", 2)]);
+        let computer = Day17::parse_with(input, &params).unwrap();
+        assert_eq!(
+            Day17::part1(&computer).to_string(),
+            "0,0,0,0,0,0,0,0,0,1,5,6,3,3,7,4,6,1,3,4,0,4,6,4,3,2,6,2,6,7,4,4,1,0"
+        );
+        let quine = example_input::<Day17>("quine");
+        let computer = Day17::parse_with(quine, &params).unwrap();
+        assert_eq!(Day17::part2(&computer), Answer::Number(117440));
+    }
+
+    const FAR_SHIFT: &str = r#"Register A: 5
+Register B: 70
+Register C: 0
+
+Program: 0,5,5,4"#;
+
+    #[test]
+    fn test_big_shift() {
+        let params = Params::from_pairs(&[("arithmetic", 2)]);
+        let computer = Day17::parse_with(FAR_SHIFT, &params).unwrap();
+        assert_eq!(Day17::part1(&computer), Answer::Text("0".to_string()));
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let params = Params::from_pairs(&[("arithmetic", 1)]);
+        let mut computer = Computer::<usize>::try_from(FAR_SHIFT).unwrap();
+        computer.arithmetic = Arithmetic::Checked;
+        assert_eq!(computer.execute_program(), Err(Overflow("a power of two")));
+        let computer = Day17::parse_with(FAR_SHIFT, &params).unwrap();
+        assert_eq!(Day17::part1(&computer), Answer::Unsolved);
+    }
+
+    /// A program of instructions without jumps, run once or, like those of the puzzle,
+    /// in a loop that shifts register A right by `looped` bits until it is 0. Only part 1
+    /// is checked, finding the quine is a search no naive solver finishes.
+    #[derive(Debug, Clone)]
+    struct Program {
+        registers: [u64; 3],
+        body: Vec<(u64, u64)>,
+        looped: Option<u64>,
+    }
+
+    impl Program {
+        fn instructions(&self) -> Vec<(u64, u64)> {
+            let mut instructions = self.body.clone();
+            if let Some(bits) = self.looped {
+                instructions.extend([(0, bits), (3, 0)]);
+            }
+            instructions
+        }
+
+        fn output(&self) -> Vec<u64> {
+            let instructions = self.instructions();
+            let [mut a, mut b, mut c] = self.registers;
+            let mut output = vec![];
+            let mut pointer = 0;
+            while let Some(&(opcode, operand)) = instructions.get(pointer) {
+                let combo = match operand {
+                    4 => a,
+                    5 => b,
+                    6 => c,
+                    literal => literal,
+                };
+                let quotient = a
+                    .checked_shr(combo.try_into().unwrap_or(u32::MAX))
+                    .unwrap_or(0);
+                pointer += 1;
+                match opcode {
+                    0 => a = quotient,
+                    1 => b ^= operand,
+                    2 => b = combo % 8,
+                    3 if a != 0 => pointer = operand as usize / 2,
+                    4 => b ^= c,
+                    5 => output.push(combo % 8),
+                    6 => b = quotient,
+                    7 => c = quotient,
+                    _ => (),
+                }
+            }
+            output
+        }
+    }
+
+    impl Case for Program {
+        fn generate(rng: &mut Rng) -> Self {
+            let mut body = (0..rng.between(0, 5))
+                .map(|_| {
+                    let opcode = *rng.pick(&[1, 2, 4, 5, 6, 7]);
+                    let operand = match opcode {
+                        1 | 4 => rng.below(8),
+                        _ => rng.below(7),
+                    };
+                    (opcode, operand as u64)
+                })
+                .collect::<Vec<_>>();
+            let out = rng.below(body.len() + 1);
+            body.insert(out, (5, rng.below(7) as u64));
+            Program {
+                registers: [rng.below(4096), rng.below(64), rng.below(64)].map(|r| r as u64),
+                body,
+                looped: rng.chance(0.5).then(|| rng.between(1, 3) as u64),
+            }
+        }
+
+        fn input(&self) -> String {
+            let [a, b, c] = self.registers;
+            let program = self
+                .instructions()
+                .iter()
+                .map(|(opcode, operand)| format!("{opcode},{operand}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            format!("Register A: {a}\nRegister B: {b}\nRegister C: {c}\n\nProgram: {program}\n")
+        }
+
+        /// Random programs divide by powers of two past 63 bits, which only big registers
+        /// do.
+        fn params(&self) -> Params {
+            Params::from_pairs(&[(ARITHMETIC.name, 2)])
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            let unlooped = self.looped.map(|_| Program {
+                looped: None,
+                ..self.clone()
+            });
+            let shorter = shrink_list(&self.body)
+                .into_iter()
+                .filter(|body| body.iter().any(|&(opcode, _)| opcode == 5))
+                .map(|body| Program {
+                    body,
+                    ..self.clone()
+                });
+            unlooped.into_iter().chain(shorter).collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            match part {
+                1 => self
+                    .output()
+                    .iter()
+                    .map(u64::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",")
+                    .into(),
+                _ => Answer::Unsolved,
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day17::Day17;
+
+fn main() {
+    let input = read_input(2024, 17);
+    let input = Day17::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day17::part1(&input));
+    println!("Part 2 = {}", Day17::part2(&input));
+}
@@ -0,0 +1,438 @@
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display, Formatter},
+};
+
+use common::{
+    generate::Generated,
+    grid::Grid,
+    observe::{Observer, View},
+    parse::{integers_n, lines},
+    random::Rng,
+    search, trace, Answer, Example, Param, Params, ParseError, Solution,
+};
+
+#[derive(Debug, Clone)]
+enum MemoryCell {
+    Sane,
+    Corrupted,
+}
+
+impl Display for MemoryCell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MemoryCell::Sane => write!(f, "."),
+            MemoryCell::Corrupted => write!(f, "#"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Memory {
+    start: (usize, usize),
+    target: (usize, usize),
+    cells: Grid<MemoryCell>,
+    corruption: VecDeque<(usize, usize)>,
+}
+
+impl Display for Memory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+/// The memory space after `byte` fell, drawn by [`Memory`].
+struct Fall<'a> {
+    memory: &'a Memory,
+    byte: Option<(usize, usize)>,
+}
+
+impl Display for Fall<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.memory)
+    }
+}
+
+impl View for Fall<'_> {
+    fn caption(&self) -> Option<String> {
+        self.byte
+            .map(|(col, row)| format!("byte fell at {col},{row}"))
+    }
+}
+
+impl Memory {
+    fn new(cols: usize, rows: usize, corruption: Vec<(usize, usize)>) -> Self {
+        Self {
+            start: (0, 0),
+            target: (rows - 1, cols - 1),
+            cells: Grid::new(rows, cols, MemoryCell::Sane),
+            corruption: corruption.into(),
+        }
+    }
+
+    /// Drops the next byte, if any is left to fall.
+    fn drop<O: Observer + ?Sized>(&mut self, observer: &mut O) -> Option<(usize, usize)> {
+        let (col, row) = self.corruption.pop_front()?;
+        self.cells[(row, col)] = MemoryCell::Corrupted;
+        observer.observe(&Fall {
+            memory: self,
+            byte: Some((col, row)),
+        });
+        Some((col, row))
+    }
+
+    fn neighbors(&self, position: (usize, usize)) -> Vec<(usize, usize)> {
+        self.cells
+            .neighbours4(position)
+            .filter(|&neighbor| matches!(self.cells[neighbor], MemoryCell::Sane))
+            .collect()
+    }
+
+    fn escape(&self) -> Option<usize> {
+        search::bfs(
+            self.start,
+            |&position| self.neighbors(position),
+            |&position| position == self.target,
+        )
+        .map(|path| path.cost)
+    }
+
+    /// The first byte that cuts the way to the exit, if any does.
+    fn blocking_byte<O: Observer + ?Sized>(&mut self, observer: &mut O) -> Option<(usize, usize)> {
+        observer.observe(&Fall {
+            memory: self,
+            byte: None,
+        });
+        loop {
+            let drop = self.drop(observer)?;
+            trace!("after {drop:?} fell:\n{self}");
+            if self.escape().is_none() {
+                return Some(drop);
+            }
+        }
+    }
+}
+
+fn parse_input(input: &str, size: usize) -> Result<Vec<(usize, usize)>, ParseError> {
+    lines(input)
+        .map(|l| match integers_n(input, l)? {
+            [col, row] if col < size && row < size => Ok((col, row)),
+            _ => Err(ParseError::at(
+                input,
+                l,
+                format!("a byte within the memory space of size {size}"),
+            )),
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct Corruption {
+    bytes: Vec<(usize, usize)>,
+    size: usize,
+    fallen: usize,
+}
+
+impl Corruption {
+    fn memory(&self) -> Memory {
+        Memory::new(self.size, self.size, self.bytes.clone())
+    }
+
+    fn escape_after_fall<O: Observer + ?Sized>(&self, observer: &mut O) -> Option<usize> {
+        let mut memory = self.memory();
+        observer.observe(&Fall {
+            memory: &memory,
+            byte: None,
+        });
+        (0..self.fallen).for_each(|_| {
+            memory.drop(observer);
+        });
+        memory.escape()
+    }
+}
+
+const SIZE: Param = Param::new("size", 71, "width and height of the memory space").range(1, 10_000);
+const BYTES: Param = Param::new("bytes", 1024, "bytes that fall before escaping in part 1");
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Corruption;
+
+    const EXAMPLES: &'static [Example] =
+        &[
+            Example::new("example", include_str!("../examples/example.txt"))
+                .params(&[("size", 7), ("bytes", 12)])
+                .part1("22")
+                .part2("6,1"),
+        ];
+
+    const PARAMS: &'static [Param] = &[SIZE, BYTES];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let size = params.value(&SIZE) as usize;
+        let fallen = params.value(&BYTES) as usize;
+        let bytes = parse_input(input, size)?;
+        if fallen > bytes.len() {
+            return Err(ParseError::at(
+                input,
+                &input[input.len()..],
+                format!("at least {fallen} bytes"),
+            ));
+        }
+        Ok(Corruption {
+            bytes,
+            size,
+            fallen,
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input.escape_after_fall(&mut ()).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        input.memory().blocking_byte(&mut ()).into()
+    }
+
+    fn simulate(input: &Self::Input<'_>, part: u8, observer: &mut dyn Observer) -> Answer {
+        match part {
+            1 => input.escape_after_fall(observer).into(),
+            2 => input.memory().blocking_byte(observer).into(),
+            _ => Answer::Unsolved,
+        }
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let size = size.max(2);
+        let mut bytes = (0..size * size)
+            .map(|cell| (cell % size, cell / size))
+            .filter(|&byte| byte != (0, 0) && byte != (size - 1, size - 1))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut bytes);
+        // A fifth of the space falls in part 1 as in the puzzle, or less if that leaves
+        // no way out.
+        let escapes = |fallen: usize| {
+            let mut corrupted = vec![vec![false; size]; size];
+            for &(x, y) in &bytes[..fallen] {
+                corrupted[y][x] = true;
+            }
+            corrupted[0][0] = true;
+            let mut stack = vec![(0_usize, 0_usize)];
+            while let Some((x, y)) = stack.pop() {
+                for (nx, ny) in [
+                    (x + 1, y),
+                    (x, y + 1),
+                    (x.wrapping_sub(1), y),
+                    (x, y.wrapping_sub(1)),
+                ] {
+                    if nx < size && ny < size && !corrupted[ny][nx] {
+                        corrupted[ny][nx] = true;
+                        stack.push((nx, ny));
+                    }
+                }
+            }
+            corrupted[size - 1][size - 1]
+        };
+        let mut fallen = bytes.len() / 5;
+        while !escapes(fallen) {
+            fallen /= 2;
+        }
+        let input = bytes.iter().map(|(x, y)| format!("{x},{y}\n")).collect();
+        Some(
+            Generated::new(input)
+                .param(SIZE.name, size as u64)
+                .param(BYTES.name, fallen as u64),
+        )
+    }
+}
+
+#[cfg(test)]
+mod day18_tests {
+    use std::collections::HashSet;
+
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests, example_input,
+        observe::FrameCollector,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day18, Bytes);
+
+    #[test]
+    fn test_drop() {
+        let input = example_input::<Day18>("example");
+        let input = parse_input(input, 7).unwrap();
+        let mut memory = Memory::new(7, 7, input);
+        (0..12).for_each(|_| {
+            memory.drop(&mut ());
+        });
+        assert_eq!(
+            memory.to_string(),
+            r#"...#...
+..#..#.
+....#..
+...#..#
+..#..#.
+.#..#..
+#.#...."#
+        );
+    }
+
+    #[test]
+    fn test_no_way_out() {
+        let params = Params::from_pairs(&[("size", 3), ("bytes", 3)]);
+        let walled = Day18::parse_with("0,1\n1,1\n2,1\n", &params).unwrap();
+        assert_eq!(Day18::part1(&walled), Answer::Unsolved);
+        assert_eq!(Day18::part2(&walled), Answer::Coord(2, 1));
+        let params = Params::from_pairs(&[("size", 3), ("bytes", 1)]);
+        let open = Day18::parse_with("1,1\n", &params).unwrap();
+        assert_eq!(Day18::part1(&open), Answer::Number(4));
+        assert_eq!(Day18::part2(&open), Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_parse_out_of_range() {
+        let input = example_input::<Day18>("example");
+        let error = Day18::parse_with(input, &Params::from_pairs(&[("size", 3)])).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected a byte within the memory space of size 3, found `5,4`"
+        );
+        let params = Params::from_pairs(&[("size", 7), ("bytes", 100)]);
+        let error = Day18::parse_with(input, &params).unwrap_err();
+        assert_eq!(error.expected(), "at least 100 bytes");
+        assert_eq!(error.found(), "end of input");
+    }
+
+    #[test]
+    fn test_simulate() {
+        let example = Day18::EXAMPLES[0];
+        let input = Day18::parse_with(example.input, &example.to_params()).unwrap();
+        let mut frames = FrameCollector::default();
+        assert_eq!(Day18::simulate(&input, 1, &mut frames), Answer::Number(22));
+        let fallen = frames.into_frames();
+        assert_eq!(fallen.len(), 13);
+        assert_eq!(fallen[0].text, ".......\n".repeat(7).trim_end());
+        assert_eq!(fallen[12].caption, Some("byte fell at 5,1".to_string()));
+        let mut frames = FrameCollector::default();
+        assert_eq!(Day18::simulate(&input, 2, &mut frames), Answer::Coord(6, 1));
+        let last = frames.frames().last().unwrap();
+        assert_eq!(last.caption, Some("byte fell at 6,1".to_string()));
+    }
+
+    #[derive(Debug, Clone)]
+    struct Bytes {
+        bytes: Vec<(usize, usize)>,
+        size: usize,
+        fallen: usize,
+    }
+
+    impl Bytes {
+        /// The fewest steps across after the first `fallen` bytes, by breadth first search.
+        fn steps(&self, fallen: usize) -> Option<usize> {
+            let corrupted = self.bytes[..fallen].iter().copied().collect::<HashSet<_>>();
+            let mut seen = HashSet::from([(0, 0)]);
+            let mut frontier = vec![(0, 0)];
+            for steps in 0.. {
+                if frontier.is_empty() {
+                    return None;
+                }
+                if frontier.contains(&(self.size - 1, self.size - 1)) {
+                    return Some(steps);
+                }
+                frontier = frontier
+                    .iter()
+                    .flat_map(|&(x, y)| {
+                        [
+                            (x + 1, y),
+                            (x, y + 1),
+                            (x.wrapping_sub(1), y),
+                            (x, y.wrapping_sub(1)),
+                        ]
+                    })
+                    .filter(|&(x, y)| x < self.size && y < self.size)
+                    .filter(|byte| !corrupted.contains(byte) && seen.insert(*byte))
+                    .collect();
+            }
+            unreachable!()
+        }
+
+        /// Whether the path is open after `fallen` and closed once all bytes fell.
+        fn valid(&self) -> bool {
+            self.steps(self.fallen).is_some() && self.steps(self.bytes.len()).is_none()
+        }
+    }
+
+    impl Case for Bytes {
+        fn generate(rng: &mut Rng) -> Self {
+            let size = rng.between(2, 7) as usize;
+            let mut cells = (0..size)
+                .flat_map(|x| (0..size).map(move |y| (x, y)))
+                .filter(|&cell| cell != (0, 0))
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut cells);
+            let mut bytes = Bytes {
+                bytes: cells,
+                size,
+                fallen: 0,
+            };
+            let blocked = (1..=bytes.bytes.len())
+                .find(|&fallen| bytes.steps(fallen).is_none())
+                .unwrap();
+            bytes.fallen = rng.below(blocked);
+            bytes
+                .bytes
+                .truncate(rng.between(blocked as i64, bytes.bytes.len() as i64) as usize);
+            bytes
+        }
+
+        fn input(&self) -> String {
+            self.bytes
+                .iter()
+                .map(|(x, y)| format!("{x},{y}\n"))
+                .collect()
+        }
+
+        fn params(&self) -> Params {
+            Params::from_pairs(&[("size", self.size as u64), ("bytes", self.fallen as u64)])
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            let mut smaller = shrink_list(&self.bytes)
+                .into_iter()
+                .map(|bytes| Bytes {
+                    bytes,
+                    ..self.clone()
+                })
+                .collect::<Vec<_>>();
+            if self.fallen > 0 {
+                smaller.push(Bytes {
+                    fallen: self.fallen - 1,
+                    ..self.clone()
+                });
+            }
+            smaller.retain(|bytes| bytes.fallen <= bytes.bytes.len() && bytes.valid());
+            smaller
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            match part {
+                1 => self.steps(self.fallen).unwrap().into(),
+                _ => {
+                    let blocked = (1..=self.bytes.len())
+                        .find(|&fallen| self.steps(fallen).is_none())
+                        .unwrap();
+                    self.bytes[blocked - 1].into()
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day18::Day18;
+
+fn main() {
+    let input = read_input(2024, 18);
+    let input = Day18::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day18::part1(&input));
+    println!("Part 2 = {}", Day18::part2(&input));
+}
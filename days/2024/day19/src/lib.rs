@@ -0,0 +1,262 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+};
+
+use common::{
+    generate::Generated,
+    parse::{lines, list, split_sections},
+    random::Rng,
+    trace, Answer, Example, ParseError, Solution,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Towel<'a>(&'a str);
+
+impl Display for Towel<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+const COLORS: &str = "wubrg";
+
+fn check_stripes<'a>(text: &str, stripes: &'a str) -> Result<&'a str, ParseError> {
+    match stripes.char_indices().find(|(_, c)| !COLORS.contains(*c)) {
+        _ if stripes.is_empty() => Err(ParseError::at(text, stripes, "a stripe color")),
+        Some((i, _)) => Err(ParseError::at(text, &stripes[i..], "a stripe color")),
+        None => Ok(stripes),
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Towel<'a> {
+    type Error = ParseError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        check_stripes(value, value.trim()).map(Towel)
+    }
+}
+
+pub type Towels<'a> = Vec<Towel<'a>>;
+
+fn can_make<'a>(
+    pattern: &'a str,
+    towels: &Towels<'a>,
+    current: Towels<'a>,
+    memo: &mut HashMap<&'a str, usize>,
+) -> usize {
+    if pattern.is_empty() {
+        return 1;
+    }
+
+    if let Some(result) = memo.get(pattern) {
+        return *result;
+    }
+
+    towels
+        .iter()
+        .filter(|t| t.to_string().len() <= pattern.len())
+        .map(|t| {
+            let mut clone = current.clone();
+            if pattern.starts_with(&t.to_string()) {
+                clone.push(t.clone());
+                let result = can_make(
+                    pattern.strip_prefix(&t.to_string()).unwrap(),
+                    towels,
+                    clone,
+                    memo,
+                );
+                memo.entry(pattern)
+                    .and_modify(|count| *count += result)
+                    .or_insert(result);
+                result
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+fn count_feasible<'a>(patterns: Vec<&str>, towels: &Towels<'a>) -> usize {
+    let mut memo = HashMap::new();
+    patterns
+        .iter()
+        .inspect(|&pattern| trace!("processing {pattern}"))
+        .map(|pattern| can_make(pattern, towels, vec![], &mut memo))
+        .filter(|&count| count > 0)
+        .count()
+}
+
+fn count_all_options<'a>(patterns: Vec<&str>, towels: &Towels<'a>) -> usize {
+    let mut memo = HashMap::new();
+    patterns
+        .iter()
+        .inspect(|&pattern| trace!("processing {pattern}"))
+        .map(|pattern| can_make(pattern, towels, vec![], &mut memo))
+        .sum()
+}
+
+fn parse_input(input: &str) -> Result<(Towels<'_>, Vec<&str>), ParseError> {
+    let (towels, patterns) = split_sections(input, input)?;
+    let towels = list(towels, ",")
+        .map(|t| Towel::try_from(t).map_err(|e| e.within(input, t)))
+        .collect::<Result<_, _>>()?;
+    let patterns = lines(patterns)
+        .map(|pattern| check_stripes(input, pattern))
+        .collect::<Result<_, _>>()?;
+    Ok((towels, patterns))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = (Towels<'a>, Vec<&'a str>);
+
+    const EXAMPLES: &'static [Example] =
+        &[
+            Example::new("example", include_str!("../examples/example.txt"))
+                .part1("6")
+                .part2("16"),
+        ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (towels, patterns) = input;
+        count_feasible(patterns.clone(), towels).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (towels, patterns) = input;
+        count_all_options(patterns.clone(), towels).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let colors = ['w', 'u', 'b', 'r', 'g'];
+        let stripes = |rng: &mut Rng, length: usize| {
+            (0..length).map(|_| *rng.pick(&colors)).collect::<String>()
+        };
+        // One color has no towel of its own, which makes some designs impossible.
+        let missing = *rng.pick(&colors);
+        let mut towels = (0..400)
+            .map(|_| {
+                let length = rng.between(2, 8) as usize;
+                stripes(rng, length)
+            })
+            .chain(
+                colors
+                    .iter()
+                    .filter(|&&c| c != missing)
+                    .map(|c| c.to_string()),
+            )
+            .collect::<Vec<_>>();
+        towels.sort();
+        towels.dedup();
+        let designs = (0..size)
+            .map(|_| match rng.chance(0.5) {
+                true => {
+                    let mut design = String::new();
+                    while design.len() < 40 {
+                        design.push_str(rng.pick(&towels).as_str());
+                    }
+                    design
+                }
+                false => {
+                    let length = rng.between(40, 60) as usize;
+                    stripes(rng, length)
+                }
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut towels);
+        Some(Generated::new(format!(
+            "{}\n\n{}\n",
+            towels.join(", "),
+            designs.join("\n")
+        )))
+    }
+}
+
+#[cfg(test)]
+mod day19_tests {
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day19, Onsen);
+
+    #[derive(Debug, Clone)]
+    struct Onsen {
+        towels: Vec<String>,
+        designs: Vec<String>,
+    }
+
+    fn arrangements(towels: &[String], design: &str) -> usize {
+        if design.is_empty() {
+            return 1;
+        }
+        towels
+            .iter()
+            .filter_map(|towel| design.strip_prefix(towel.as_str()))
+            .map(|rest| arrangements(towels, rest))
+            .sum()
+    }
+
+    impl Case for Onsen {
+        fn generate(rng: &mut Rng) -> Self {
+            let stripes = |rng: &mut Rng, length: i64| {
+                (0..rng.between(1, length))
+                    .map(|_| *rng.pick(&['w', 'u', 'b', 'r', 'g']))
+                    .collect::<String>()
+            };
+            let mut towels = (0..8).map(|_| stripes(rng, 3)).collect::<Vec<_>>();
+            towels.sort();
+            towels.dedup();
+            towels.truncate(rng.between(1, towels.len() as i64) as usize);
+            let designs = (0..rng.between(1, 5)).map(|_| stripes(rng, 12)).collect();
+            Onsen { towels, designs }
+        }
+
+        fn input(&self) -> String {
+            format!(
+                "{}\n\n{}\n",
+                self.towels.join(", "),
+                self.designs.join("\n")
+            )
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            let fewer_towels = shrink_list(&self.towels)
+                .into_iter()
+                .filter(|towels| !towels.is_empty())
+                .map(|towels| Onsen {
+                    towels,
+                    ..self.clone()
+                });
+            let fewer_designs = shrink_list(&self.designs)
+                .into_iter()
+                .filter(|designs| !designs.is_empty())
+                .map(|designs| Onsen {
+                    designs,
+                    ..self.clone()
+                });
+            fewer_towels.chain(fewer_designs).collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let counts = self
+                .designs
+                .iter()
+                .map(|design| arrangements(&self.towels, design));
+            match part {
+                1 => counts.filter(|&count| count > 0).count().into(),
+                _ => counts.sum::<usize>().into(),
+            }
+        }
+    }
+}
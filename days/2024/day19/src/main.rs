@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day19::Day19;
+
+fn main() {
+    let input = read_input(2024, 19);
+    let input = Day19::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day19::part1(&input));
+    println!("Part 2 = {}", Day19::part2(&input));
+}
@@ -0,0 +1,353 @@
+use std::collections::HashMap;
+
+use common::{
+    differential::render_grid,
+    generate::{self, Generated},
+    geom::Point,
+    grid::Grid,
+    random::Rng,
+    search, Answer, Example, Param, Params, ParseError, Solution,
+};
+
+#[derive(Debug, Ord, Eq, PartialOrd)]
+struct Cheat {
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+impl PartialEq for Cheat {
+    fn eq(&self, other: &Self) -> bool {
+        (self.start == other.start && self.end == other.end)
+            || (self.start == other.end && self.end == other.start)
+    }
+}
+
+impl Cheat {
+    fn new(start: (usize, usize), end: (usize, usize)) -> Self {
+        Self { start, end }
+    }
+}
+
+#[derive(Debug)]
+pub struct Race {
+    walls: Grid<bool>,
+    track: Vec<(usize, usize)>,
+    distances: HashMap<(usize, usize), usize>,
+}
+
+impl TryFrom<&str> for Race {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let map = Grid::try_parse(value, |c| match c {
+            '.' | 'S' | 'E' | '#' => Ok(c),
+            c => Err(ParseError::new("`.`, `#`, `S` or `E`", &c.to_string())),
+        })?;
+        let start = map
+            .find(&'S')
+            .ok_or_else(|| ParseError::at(value, &value[value.len()..], "a start tile `S`"))?;
+        let walls = map.map(|&tile| tile == '#');
+
+        let distances = search::bfs_distances(start, |&tile| {
+            walls
+                .neighbours4(tile)
+                .filter(|&next| !walls[next])
+                .collect::<Vec<_>>()
+        });
+        let mut track = distances.keys().copied().collect::<Vec<_>>();
+        track.sort_by_key(|tile| distances[tile]);
+
+        Ok(Self {
+            walls,
+            track,
+            distances,
+        })
+    }
+}
+
+impl Race {
+    fn cheats(&self, from: (usize, usize), duration: usize) -> Vec<Cheat> {
+        let duration: isize = duration as isize;
+        ((-duration)..=duration)
+            .flat_map(|delta_row| {
+                ((-duration)..=duration)
+                    .map(move |delta_col| (delta_row, delta_col))
+                    .filter(|delta| ![(-1, 0), (1, 0), (0, -1), (0, 1), (0, 0)].contains(delta))
+            })
+            .filter_map(|delta| self.walls.offset(from, delta))
+            .filter(|tile| Point::from(from).manhattan(Point::from(*tile)) <= duration as usize)
+            .filter(|tile| {
+                self.distances
+                    .get(tile)
+                    .is_some_and(|&distance| distance > self.distances[&from])
+            })
+            .map(|tile| Cheat::new(from, tile))
+            .collect()
+    }
+
+    fn find_valid_cheats(&self, duration: usize) -> HashMap<usize, usize> {
+        let mut cheats = self
+            .track
+            .iter()
+            .flat_map(|&tile| self.cheats(tile, duration))
+            .collect::<Vec<_>>();
+        cheats.sort_unstable();
+        cheats.dedup();
+        cheats
+            .iter()
+            .fold(HashMap::new(), |mut cheat_saving, cheat| {
+                let (start, end) = (cheat.start, cheat.end);
+                let idx_start = self.distances[&start];
+                let idx_end = self.distances[&end];
+                let saved_ps = if idx_start > idx_end {
+                    idx_start - idx_end - Point::from(start).manhattan(Point::from(end))
+                } else {
+                    idx_end - idx_start - Point::from(start).manhattan(Point::from(end))
+                };
+                if saved_ps > 0 {
+                    cheat_saving
+                        .entry(saved_ps)
+                        .and_modify(|num_tracks| *num_tracks += 1)
+                        .or_insert(1);
+                }
+                cheat_saving
+            })
+    }
+}
+
+fn count_cheats_saving(race: &Race, duration: usize, threshold: usize) -> usize {
+    race.find_valid_cheats(duration)
+        .iter()
+        .filter(|(&ps, _)| ps >= threshold)
+        .map(|(_, num)| num)
+        .sum()
+}
+
+#[derive(Debug)]
+pub struct Competition {
+    race: Race,
+    threshold: usize,
+    durations: [usize; 2],
+}
+
+const THRESHOLD: Param = Param::new("threshold", 100, "picoseconds a cheat must save");
+const SHORT_CHEAT: Param = Param::new("cheat1", 2, "picoseconds a cheat lasts in part 1");
+const LONG_CHEAT: Param = Param::new("cheat2", 20, "picoseconds a cheat lasts in part 2");
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Competition;
+
+    const EXAMPLES: &'static [Example] =
+        &[
+            Example::new("example", include_str!("../examples/example.txt"))
+                .params(&[("threshold", 50)])
+                .part1("1")
+                .part2("285"),
+        ];
+
+    const PARAMS: &'static [Param] = &[THRESHOLD, SHORT_CHEAT, LONG_CHEAT];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        Ok(Competition {
+            race: Race::try_from(input)?,
+            threshold: params.value(&THRESHOLD) as usize,
+            durations: [SHORT_CHEAT, LONG_CHEAT].map(|param| params.value(&param) as usize),
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        count_cheats_saving(&input.race, input.durations[0], input.threshold).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        count_cheats_saving(&input.race, input.durations[1], input.threshold).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let racetrack = generate::single_track(size, rng);
+        Some(Generated::new(render_grid(&racetrack)))
+    }
+}
+
+#[cfg(test)]
+mod day20_tests {
+    use common::{
+        differential::{render_grid, Case},
+        differential_tests, example_input,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day20, Racetrack);
+
+    #[test]
+    fn test_parse() {
+        let input = example_input::<Day20>("example");
+        let race = Race::try_from(input).unwrap();
+        assert_eq!(race.track.len() - 1, 84);
+    }
+
+    #[test]
+    fn test_find_valid_cheats() {
+        let input = example_input::<Day20>("example");
+        let race = Race::try_from(input).unwrap();
+        assert_eq!(race.find_valid_cheats(2).values().sum::<usize>(), 44);
+    }
+
+    #[test]
+    fn part1() {
+        let input = example_input::<Day20>("example");
+        let race = Race::try_from(input).unwrap();
+        let saved_ps = race.find_valid_cheats(2);
+        assert_eq!(saved_ps.get(&2).unwrap(), &14);
+        assert_eq!(saved_ps.get(&4).unwrap(), &14);
+        assert_eq!(saved_ps.get(&6).unwrap(), &2);
+        assert_eq!(saved_ps.get(&8).unwrap(), &4);
+        assert_eq!(saved_ps.get(&10).unwrap(), &2);
+        assert_eq!(saved_ps.get(&12).unwrap(), &3);
+        assert_eq!(saved_ps.get(&20).unwrap(), &1);
+        assert_eq!(saved_ps.get(&36).unwrap(), &1);
+        assert_eq!(saved_ps.get(&38).unwrap(), &1);
+        assert_eq!(saved_ps.get(&40).unwrap(), &1);
+        assert_eq!(saved_ps.get(&64).unwrap(), &1);
+    }
+
+    #[test]
+    fn part2() {
+        let input = example_input::<Day20>("example");
+        let race = Race::try_from(input).unwrap();
+        let saved_ps = race.find_valid_cheats(20);
+        assert_eq!(saved_ps.get(&50).unwrap(), &32);
+        assert_eq!(saved_ps.get(&52).unwrap(), &31);
+        assert_eq!(saved_ps.get(&54).unwrap(), &29);
+        assert_eq!(saved_ps.get(&56).unwrap(), &39);
+        assert_eq!(saved_ps.get(&58).unwrap(), &25);
+        assert_eq!(saved_ps.get(&60).unwrap(), &23);
+        assert_eq!(saved_ps.get(&62).unwrap(), &20);
+        assert_eq!(saved_ps.get(&64).unwrap(), &19);
+        assert_eq!(saved_ps.get(&66).unwrap(), &12);
+        assert_eq!(saved_ps.get(&68).unwrap(), &14);
+        assert_eq!(saved_ps.get(&70).unwrap(), &12);
+        assert_eq!(saved_ps.get(&72).unwrap(), &22);
+        assert_eq!(saved_ps.get(&74).unwrap(), &4);
+        assert_eq!(saved_ps.get(&76).unwrap(), &3);
+    }
+
+    #[derive(Debug, Clone)]
+    struct Racetrack {
+        size: (usize, usize),
+        track: Vec<(usize, usize)>,
+        threshold: usize,
+        cheats: (usize, usize),
+    }
+
+    impl Case for Racetrack {
+        fn generate(rng: &mut Rng) -> Self {
+            let size = (rng.between(3, 10) as usize, rng.between(3, 10) as usize);
+            let inside = |(row, col): (usize, usize)| {
+                (1..size.0 - 1).contains(&row) && (1..size.1 - 1).contains(&col)
+            };
+            let neighbours = |(row, col): (usize, usize)| {
+                [
+                    (row - 1, col),
+                    (row + 1, col),
+                    (row, col - 1),
+                    (row, col + 1),
+                ]
+            };
+            let mut track = vec![(rng.below(size.0 - 2) + 1, rng.below(size.1 - 2) + 1)];
+            let length = rng.between(2, 40) as usize;
+            // The track never touches itself, so that it stays a single way to the end.
+            while track.len() < length {
+                let last = *track.last().unwrap();
+                let next = neighbours(last)
+                    .into_iter()
+                    .filter(|&next| inside(next) && !track.contains(&next))
+                    .filter(|&next| {
+                        neighbours(next)
+                            .into_iter()
+                            .all(|cell| cell == last || !track.contains(&cell))
+                    })
+                    .collect::<Vec<_>>();
+                if next.is_empty() {
+                    break;
+                }
+                track.push(*rng.pick(&next));
+            }
+            if track.len() < 2 {
+                return Self::generate(rng);
+            }
+            Racetrack {
+                size,
+                track,
+                threshold: rng.between(1, 12) as usize,
+                cheats: (rng.between(1, 3) as usize, rng.between(1, 8) as usize),
+            }
+        }
+
+        fn input(&self) -> String {
+            let mut grid = vec![vec!['#'; self.size.1]; self.size.0];
+            for &(row, col) in &self.track {
+                grid[row][col] = '.';
+            }
+            let (start, end) = (self.track[0], self.track[self.track.len() - 1]);
+            grid[start.0][start.1] = 'S';
+            grid[end.0][end.1] = 'E';
+            render_grid(&grid)
+        }
+
+        fn params(&self) -> Params {
+            Params::from_pairs(&[
+                ("threshold", self.threshold as u64),
+                ("cheat1", self.cheats.0 as u64),
+                ("cheat2", self.cheats.1 as u64),
+            ])
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            let mut smaller = vec![];
+            if self.track.len() > 2 {
+                smaller.push(Racetrack {
+                    track: self.track[..self.track.len() - 1].to_vec(),
+                    ..self.clone()
+                });
+                smaller.push(Racetrack {
+                    track: self.track[1..].to_vec(),
+                    ..self.clone()
+                });
+            }
+            if self.threshold > 1 {
+                smaller.push(Racetrack {
+                    threshold: self.threshold - 1,
+                    ..self.clone()
+                });
+            }
+            smaller
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let cheat = if part == 1 {
+                self.cheats.0
+            } else {
+                self.cheats.1
+            };
+            let mut count = 0;
+            for (from, &(from_row, from_col)) in self.track.iter().enumerate() {
+                for (to, &(to_row, to_col)) in self.track.iter().enumerate().skip(from + 1) {
+                    let distance = from_row.abs_diff(to_row) + from_col.abs_diff(to_col);
+                    if distance <= cheat && to - from >= distance + self.threshold {
+                        count += 1;
+                    }
+                }
+            }
+            count.into()
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day20::Day20;
+
+fn main() {
+    let input = read_input(2024, 20);
+    let input = Day20::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day20::part1(&input));
+    println!("Part 2 = {}", Day20::part2(&input));
+}
@@ -0,0 +1,481 @@
+use std::{collections::HashMap, iter};
+
+use common::{
+    generate::Generated, geom::Direction, parse::parse_at, random::Rng, search, Answer, Example,
+    Param, Params, ParseError, Solution,
+};
+use itertools::Itertools;
+
+#[derive(Debug)]
+struct Edge {
+    from: char,
+    to: char,
+    direction: Direction,
+}
+
+impl Edge {
+    fn new(from: char, to: char, direction: Direction) -> Self {
+        Self {
+            from,
+            to,
+            direction,
+        }
+    }
+}
+
+fn neighbors(key: char, edges: &[Edge]) -> Vec<char> {
+    edges
+        .iter()
+        .filter(|e| e.from == key)
+        .map(|e| e.to)
+        .collect()
+}
+
+fn direction(from: char, to: char, edges: &[Edge]) -> Direction {
+    edges
+        .iter()
+        .find(|e| e.from == from && e.to == to)
+        .map(|e| e.direction)
+        .unwrap()
+}
+
+fn compute_min_paths(edges: &[Edge]) -> HashMap<(char, char), Vec<Vec<Direction>>> {
+    let keys = edges.iter().map(|e| e.from).unique().collect::<Vec<_>>();
+    keys.iter()
+        .cartesian_product(keys.iter())
+        .map(|(&from, &to)| {
+            let paths = search::bfs_all(from, |&key| neighbors(key, edges), |&key| key == to)
+                .unwrap()
+                .paths()
+                .iter()
+                .map(|keys| {
+                    keys.iter()
+                        .tuple_windows()
+                        .map(|(&a, &b)| direction(a, b, edges))
+                        .collect()
+                })
+                .collect();
+            ((from, to), paths)
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+struct Keypad {
+    min_paths: HashMap<(char, char), Vec<Vec<Direction>>>,
+}
+
+impl Keypad {
+    fn new(edges: Vec<Edge>) -> Self {
+        let min_paths = compute_min_paths(&edges);
+        Self { min_paths }
+    }
+
+    fn new_numeric() -> Self {
+        let edges = vec![
+            Edge::new('7', '8', Direction::East),
+            Edge::new('7', '4', Direction::South),
+            Edge::new('8', '7', Direction::West),
+            Edge::new('8', '5', Direction::South),
+            Edge::new('8', '9', Direction::East),
+            Edge::new('9', '8', Direction::West),
+            Edge::new('9', '6', Direction::South),
+            Edge::new('4', '7', Direction::North),
+            Edge::new('4', '5', Direction::East),
+            Edge::new('4', '1', Direction::South),
+            Edge::new('5', '4', Direction::West),
+            Edge::new('5', '8', Direction::North),
+            Edge::new('5', '6', Direction::East),
+            Edge::new('5', '2', Direction::South),
+            Edge::new('6', '5', Direction::West),
+            Edge::new('6', '9', Direction::North),
+            Edge::new('6', '3', Direction::South),
+            Edge::new('1', '4', Direction::North),
+            Edge::new('1', '2', Direction::East),
+            Edge::new('2', '1', Direction::West),
+            Edge::new('2', '5', Direction::North),
+            Edge::new('2', '3', Direction::East),
+            Edge::new('2', '0', Direction::South),
+            Edge::new('3', '2', Direction::West),
+            Edge::new('3', '6', Direction::North),
+            Edge::new('3', 'A', Direction::South),
+            Edge::new('0', '2', Direction::North),
+            Edge::new('0', 'A', Direction::East),
+            Edge::new('A', '0', Direction::West),
+            Edge::new('A', '3', Direction::North),
+        ];
+        Self::new(edges)
+    }
+
+    fn new_directional() -> Self {
+        let edges = vec![
+            Edge::new('^', 'A', Direction::East),
+            Edge::new('^', 'v', Direction::South),
+            Edge::new('A', '^', Direction::West),
+            Edge::new('A', '>', Direction::South),
+            Edge::new('<', 'v', Direction::East),
+            Edge::new('v', '<', Direction::West),
+            Edge::new('v', '^', Direction::North),
+            Edge::new('v', '>', Direction::East),
+            Edge::new('>', 'v', Direction::West),
+            Edge::new('>', 'A', Direction::North),
+        ];
+        Self::new(edges)
+    }
+
+    fn find_shortest_sequence(
+        &self,
+        target: &str,
+        depth: usize,
+        memo: &mut HashMap<(String, usize), usize>,
+    ) -> usize {
+        if let Some(length) = memo.get(&(target.to_string(), depth)) {
+            return *length;
+        }
+        let min_length = format!("A{target}")
+            .chars()
+            .tuple_windows()
+            .map(|(from, to)| {
+                let shortest_paths = self.min_paths.get(&(from, to)).unwrap();
+                let min_length = match depth {
+                    0 => shortest_paths[0].len() + 1,
+                    _ => shortest_paths
+                        .iter()
+                        .map(|path| {
+                            let path = path
+                                .iter()
+                                .map(|d| d.arrow())
+                                .chain(iter::once('A'))
+                                .collect::<String>();
+                            Keypad::new_directional().find_shortest_sequence(&path, depth - 1, memo)
+                        })
+                        .min()
+                        .unwrap(),
+                };
+                min_length
+            })
+            .sum::<usize>();
+        memo.insert((target.to_string(), depth), min_length);
+        min_length
+    }
+
+    fn calculate_complexity(&self, code: &Code, num_robot_stages: usize) -> usize {
+        self.find_shortest_sequence(code.keys, num_robot_stages, &mut HashMap::new()) * code.number
+    }
+
+    fn calculate_total_complexity(&self, codes: &[Code], num_robot_stages: usize) -> usize {
+        codes
+            .iter()
+            .map(|code| self.calculate_complexity(code, num_robot_stages))
+            .sum()
+    }
+}
+
+/// A code typed on the numeric keypad, with the number its digits make.
+#[derive(Debug)]
+struct Code<'a> {
+    keys: &'a str,
+    number: usize,
+}
+
+fn parse_code<'a>(input: &str, code: &'a str) -> Result<Code<'a>, ParseError> {
+    let digits = code
+        .strip_suffix('A')
+        .ok_or_else(|| ParseError::at(input, &code[code.len()..], "`A`"))?;
+    if let Some((i, c)) = digits.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        let key = &digits[i..i + c.len_utf8()];
+        return Err(ParseError::at(input, key, "a digit"));
+    }
+    let number = parse_at(input, digits, "a numeric code")?;
+    Ok(Code { keys: code, number })
+}
+
+#[derive(Debug)]
+pub struct Door<'a> {
+    codes: Vec<Code<'a>>,
+    robots: [usize; 2],
+}
+
+const FEW_ROBOTS: Param = Param::new("robots1", 2, "directional keypads robots use in part 1");
+const MANY_ROBOTS: Param = Param::new("robots2", 25, "directional keypads robots use in part 2");
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = Door<'a>;
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new("example", include_str!("../examples/example.txt")).part1("126384")];
+
+    const PARAMS: &'static [Param] = &[FEW_ROBOTS, MANY_ROBOTS];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let codes = input
+            .trim()
+            .lines()
+            .map(|code| parse_code(input, code))
+            .collect::<Result<_, _>>()?;
+        Ok(Door {
+            codes,
+            robots: [FEW_ROBOTS, MANY_ROBOTS].map(|param| params.value(&param) as usize),
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        Keypad::new_numeric()
+            .calculate_total_complexity(&input.codes, input.robots[0])
+            .into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        Keypad::new_numeric()
+            .calculate_total_complexity(&input.codes, input.robots[1])
+            .into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let codes = (0..size)
+            .map(|_| format!("{:03}A\n", rng.between(1, 999)))
+            .collect();
+        Some(Generated::new(codes))
+    }
+}
+
+#[cfg(test)]
+mod day21_tests {
+    use std::collections::VecDeque;
+
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day21, Codes);
+
+    #[test]
+    fn test_numeric_keypad() {
+        let keypad = Keypad::new_numeric();
+        assert_eq!(
+            keypad.min_paths.get(&('A', '0')),
+            Some(vec![vec![Direction::West]].as_ref())
+        );
+        let paths_0_9 = keypad.min_paths.get(&('0', '9'));
+        assert!(paths_0_9.is_some());
+        let paths_0_9 = paths_0_9.unwrap();
+        assert_eq!(paths_0_9.len(), 4);
+        assert!(paths_0_9.contains(
+            vec![
+                Direction::North,
+                Direction::North,
+                Direction::North,
+                Direction::East,
+            ]
+            .as_ref()
+        ));
+        assert!(paths_0_9.contains(
+            vec![
+                Direction::North,
+                Direction::North,
+                Direction::East,
+                Direction::North,
+            ]
+            .as_ref()
+        ));
+        assert!(paths_0_9.contains(
+            vec![
+                Direction::North,
+                Direction::East,
+                Direction::North,
+                Direction::North,
+            ]
+            .as_ref()
+        ));
+        assert!(paths_0_9.contains(
+            vec![
+                Direction::East,
+                Direction::North,
+                Direction::North,
+                Direction::North,
+            ]
+            .as_ref()
+        ));
+    }
+
+    #[test]
+    fn test_directional_keypad() {
+        let keypad = Keypad::new_directional();
+        assert_eq!(
+            keypad.min_paths.get(&('A', '^')),
+            Some(vec![vec![Direction::West]].as_ref())
+        );
+        let paths_l_a = keypad.min_paths.get(&('<', 'A'));
+        assert!(paths_l_a.is_some());
+        let paths_l_a = paths_l_a.unwrap();
+        assert_eq!(paths_l_a.len(), 2);
+        assert!(
+            paths_l_a.contains(vec![Direction::East, Direction::East, Direction::North,].as_ref())
+        );
+        assert!(
+            paths_l_a.contains(vec![Direction::East, Direction::North, Direction::East,].as_ref())
+        );
+    }
+
+    #[test]
+    fn test_find_seq_lengths() {
+        let input = "029A";
+        let keypad = Keypad::new_numeric();
+        let min_length = keypad.find_shortest_sequence(input, 2, &mut HashMap::new());
+        assert_eq!(min_length, 68);
+    }
+
+    #[test]
+    fn test_calculate_complexity() {
+        let input = "029A";
+        let keypad = Keypad::new_numeric();
+        let code = parse_code(input, input).unwrap();
+        assert_eq!(code.number, 29);
+        assert_eq!(keypad.calculate_complexity(&code, 2), 68 * 29);
+    }
+
+    #[test]
+    fn test_parse_code() {
+        assert_eq!(
+            parse_code("+29A", "+29A").unwrap_err().to_string(),
+            "line 1, column 1: expected a digit, found `+`"
+        );
+        assert_eq!(
+            parse_code("029", "029").unwrap_err().to_string(),
+            "line 1, column 4: expected `A`, found end of input"
+        );
+    }
+
+    #[derive(Debug, Clone)]
+    struct Codes {
+        codes: Vec<String>,
+        robots: [usize; 2],
+    }
+
+    const NUMERIC: [&str; 4] = ["789", "456", "123", " 0A"];
+    const DIRECTIONAL: [&str; 2] = [" ^A", "<v>"];
+
+    /// The key at `(row, col)` of `pad`, if there is one.
+    fn key_at(pad: &[&str], (row, col): (i64, i64)) -> Option<char> {
+        let line = pad.get(usize::try_from(row).ok()?)?;
+        let key = line.chars().nth(usize::try_from(col).ok()?)?;
+        (key != ' ').then_some(key)
+    }
+
+    /// The arms and the count of keys of `code` typed after the user presses `key`, or
+    /// `None` if an arm would leave its keypad or type a wrong key.
+    fn press(
+        mut arms: Vec<(i64, i64)>,
+        typed: usize,
+        code: &[u8],
+        mut key: char,
+    ) -> Option<(Vec<(i64, i64)>, usize)> {
+        for level in (0..arms.len()).rev() {
+            let pad: &[&str] = match level {
+                0 => &NUMERIC,
+                _ => &DIRECTIONAL,
+            };
+            let (row, col) = arms[level];
+            let next = match key {
+                '^' => (row - 1, col),
+                'v' => (row + 1, col),
+                '<' => (row, col - 1),
+                '>' => (row, col + 1),
+                _ => {
+                    key = key_at(pad, arms[level])?;
+                    continue;
+                }
+            };
+            key_at(pad, next)?;
+            arms[level] = next;
+            return Some((arms, typed));
+        }
+        (code.get(typed) == Some(&(key as u8))).then_some((arms, typed + 1))
+    }
+
+    /// The fewest presses of the user to type `code` through `robots` directional
+    /// keypads, searching every position of the arms, the numeric one first.
+    fn presses(code: &str, robots: usize) -> usize {
+        let arms = iter::once((3, 2))
+            .chain(iter::repeat_n((0, 2), robots))
+            .collect::<Vec<_>>();
+        let mut distances = HashMap::from([((arms.clone(), 0), 0)]);
+        let mut queue = VecDeque::from([(arms, 0)]);
+        while let Some(state) = queue.pop_front() {
+            let distance = distances[&state];
+            if state.1 == code.len() {
+                return distance;
+            }
+            for key in ['^', 'v', '<', '>', 'A'] {
+                if let Some(next) = press(state.0.clone(), state.1, code.as_bytes(), key) {
+                    if !distances.contains_key(&next) {
+                        distances.insert(next.clone(), distance + 1);
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        unreachable!("every code can be typed")
+    }
+
+    impl Case for Codes {
+        fn generate(rng: &mut Rng) -> Self {
+            let codes = (0..rng.between(1, 3))
+                .map(|_| {
+                    let digits = (0..rng.between(1, 3))
+                        .map(|_| char::from(b'0' + rng.below(10) as u8))
+                        .collect::<String>();
+                    format!("{digits}A")
+                })
+                .collect();
+            Codes {
+                codes,
+                robots: [rng.below(3), rng.below(3)],
+            }
+        }
+
+        fn input(&self) -> String {
+            self.codes.iter().map(|code| format!("{code}\n")).collect()
+        }
+
+        fn params(&self) -> Params {
+            Params::from_pairs(&[
+                ("robots1", self.robots[0] as u64),
+                ("robots2", self.robots[1] as u64),
+            ])
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_list(&self.codes)
+                .into_iter()
+                .filter(|codes| !codes.is_empty())
+                .map(|codes| Codes {
+                    codes,
+                    ..self.clone()
+                })
+                .collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            let robots = self.robots[usize::from(part) - 1];
+            self.codes
+                .iter()
+                .map(|code| {
+                    let number = code.trim_end_matches('A').parse::<usize>().unwrap();
+                    presses(code, robots) * number
+                })
+                .sum::<usize>()
+                .into()
+        }
+    }
+}
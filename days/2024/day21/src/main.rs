@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day21::Day21;
+
+fn main() {
+    let input = read_input(2024, 21);
+    let input = Day21::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day21::part1(&input));
+    println!("Part 2 = {}", Day21::part2(&input));
+}
@@ -1,6 +1,11 @@
 use std::collections::{HashMap, HashSet};
 
-use common::read_input;
+use common::{
+    generate::Generated,
+    parse::{lines, parse_at},
+    random::Rng,
+    Answer, Example, ParseError, Solution,
+};
 use itertools::Itertools;
 
 fn mix(num: usize, val: usize) -> usize {
@@ -30,11 +35,11 @@ fn step3(secret_num: usize) -> usize {
 }
 
 fn process(secret_num: usize) -> usize {
-    vec![secret_num]
+    [secret_num]
         .iter()
         .map(|&secret_num| step1(secret_num))
-        .map(|secret_num| step2(secret_num))
-        .map(|secret_num| step3(secret_num))
+        .map(step2)
+        .map(step3)
         .next()
         .unwrap()
 }
@@ -94,21 +99,17 @@ fn find_best_sequences_for_bananas(maps: Vec<HashMap<Vec<isize>, usize>>) -> usi
         .unwrap_or_default()
 }
 
-fn process_multiple(input: &str) -> usize {
-    input
-        .trim()
-        .lines()
-        .map(|l| l.parse().unwrap())
-        .map(|secret_num| *peek_next_numbers(secret_num, 2000).last().unwrap())
+fn process_multiple(secret_nums: &[usize]) -> usize {
+    secret_nums
+        .iter()
+        .map(|&secret_num| *peek_next_numbers(secret_num, 2000).last().unwrap())
         .sum()
 }
 
-fn part2(input: &str) -> usize {
-    let secret_nums_vec = input
-        .trim()
-        .lines()
-        .map(|l| l.parse().unwrap())
-        .map(|secret_num| {
+fn most_bananas(secret_nums: &[usize]) -> usize {
+    let secret_nums_vec = secret_nums
+        .iter()
+        .map(|&secret_num| {
             let mut v = vec![secret_num];
             v.extend(peek_next_numbers(secret_num, 1999));
             v
@@ -134,16 +135,50 @@ fn part2(input: &str) -> usize {
     find_best_sequences_for_bananas(bananas_maps)
 }
 
-fn main() {
-    let input = read_input("day22.txt");
-    println!("Part 1 = {}", process_multiple(input.as_str()));
-    println!("Part 2 = {}", part2(input.as_str()));
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = Vec<usize>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("example", include_str!("../examples/example.txt")).part1("37327623"),
+        Example::new("bananas", include_str!("../examples/bananas.txt")).part2("23"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        lines(input)
+            .map(|l| parse_at(input, l, "a secret number"))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        process_multiple(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        most_bananas(input).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let secrets = (0..size)
+            .map(|_| format!("{}\n", rng.between(1, 16777215)))
+            .collect();
+        Some(Generated::new(secrets))
+    }
 }
 
 #[cfg(test)]
 mod day22_tests {
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests, example_input,
+        random::Rng,
+    };
+
     use super::*;
 
+    differential_tests!(Day22, Buyers);
+
     #[test]
     fn test_mix() {
         assert_eq!(mix(42, 15), 37);
@@ -170,15 +205,6 @@ mod day22_tests {
         );
     }
 
-    #[test]
-    fn part1() {
-        let input = r#"1
-10
-100
-2024"#;
-        assert_eq!(process_multiple(input), 37327623);
-    }
-
     #[test]
     fn test_to_price() {
         let mut secret_nums = vec![123];
@@ -217,10 +243,7 @@ mod day22_tests {
 
     #[test]
     fn test_to_bananas_maps() {
-        let input = r#"1
-2
-3
-2024"#;
+        let input = example_input::<Day22>("bananas");
         let secret_nums_vec = input
             .trim()
             .lines()
@@ -250,27 +273,51 @@ mod day22_tests {
             .collect::<Vec<_>>();
         assert_eq!(bananas_maps.len(), 4);
         let seq: Vec<isize> = vec![-2, 1, -1, 3];
-        assert_eq!(
-            bananas_maps.iter().nth(0).unwrap().get(&seq),
-            Some(7).as_ref()
-        );
-        assert_eq!(
-            bananas_maps.iter().nth(1).unwrap().get(&seq),
-            Some(7).as_ref()
-        );
-        assert_eq!(bananas_maps.iter().nth(2).unwrap().get(&seq), None.as_ref());
-        assert_eq!(
-            bananas_maps.iter().nth(3).unwrap().get(&seq),
-            Some(9).as_ref()
-        );
+        assert_eq!(bananas_maps[0].get(&seq), Some(7).as_ref());
+        assert_eq!(bananas_maps[1].get(&seq), Some(7).as_ref());
+        assert_eq!(bananas_maps[2].get(&seq), None.as_ref());
+        assert_eq!(bananas_maps[3].get(&seq), Some(9).as_ref());
     }
 
-    #[test]
-    fn test_part2() {
-        let input = r#"1
-2
-3
-2024"#;
-        assert_eq!(part2(input), 23);
+    #[derive(Debug, Clone)]
+    struct Buyers(Vec<u64>);
+
+    impl Case for Buyers {
+        fn generate(rng: &mut Rng) -> Self {
+            Buyers(
+                (0..rng.between(1, 4))
+                    .map(|_| rng.between(1, 1 << 24) as u64)
+                    .collect(),
+            )
+        }
+
+        fn input(&self) -> String {
+            self.0.iter().map(|secret| format!("{secret}\n")).collect()
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_list(&self.0)
+                .into_iter()
+                .filter(|secrets| !secrets.is_empty())
+                .map(Buyers)
+                .collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            if part == 2 {
+                return Answer::Unsolved;
+            }
+            self.0
+                .iter()
+                .map(|&secret| {
+                    (0..2000).fold(secret, |secret, _| {
+                        let secret = (secret ^ (secret * 64)) % 16777216;
+                        let secret = (secret ^ (secret / 32)) % 16777216;
+                        (secret ^ (secret * 2048)) % 16777216
+                    })
+                })
+                .sum::<u64>()
+                .into()
+        }
     }
 }
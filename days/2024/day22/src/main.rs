@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day22::Day22;
+
+fn main() {
+    let input = read_input(2024, 22);
+    let input = Day22::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day22::part1(&input));
+    println!("Part 2 = {}", Day22::part2(&input));
+}
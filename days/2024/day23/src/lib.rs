@@ -0,0 +1,384 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display, Formatter},
+    hash::Hash,
+    str::FromStr,
+};
+
+use common::{
+    generate::Generated,
+    parse::{lines, split_at},
+    random::Rng,
+    Answer, Example, ParseError, Solution,
+};
+use itertools::Itertools;
+
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord, Hash)]
+struct Computer(String);
+
+impl Display for Computer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Computer(name) = self;
+        write!(f, "{name}")
+    }
+}
+
+impl Computer {
+    fn name_starts_with(&self, letter: &str) -> bool {
+        let Computer(name) = self;
+        name.starts_with(letter)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Connection {
+    from: Computer,
+    to: Vec<Computer>,
+}
+
+fn parse_computer(text: &str, name: &str) -> Result<Computer, ParseError> {
+    match name.is_empty() || !name.chars().all(|c| c.is_ascii_lowercase()) {
+        true => Err(ParseError::at(text, name, "a computer name")),
+        false => Ok(Computer(name.to_string())),
+    }
+}
+
+impl FromStr for Connection {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (from, to) = split_at(s, s, "-")?;
+        Ok(Self {
+            from: parse_computer(s, from)?,
+            to: vec![parse_computer(s, to)?],
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Lan {
+    connections: Vec<Connection>,
+}
+
+impl TryFrom<&str> for Lan {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut connections: Vec<Connection> = vec![];
+        lines(value).try_for_each(|l| {
+            let direct: Connection = l.parse().map_err(|e: ParseError| e.within(value, l))?;
+            let reverse = Connection {
+                from: direct.to[0].clone(),
+                to: vec![direct.from.clone()],
+            };
+            if let Some(connection) = connections.iter_mut().find(|c| c.from == direct.from) {
+                connection.to.extend(direct.to.clone());
+            } else {
+                connections.push(direct.clone());
+            }
+            if let Some(connection) = connections.iter_mut().find(|c| c.from == reverse.from) {
+                connection.to.extend(reverse.to);
+            } else {
+                connections.push(reverse);
+            }
+            Ok::<_, ParseError>(())
+        })?;
+        Ok(Self { connections })
+    }
+}
+
+impl Lan {
+    fn find_three_connections(&self) -> Vec<[Computer; 3]> {
+        let mut three_connections = self
+            .connections
+            .iter()
+            .filter_map(|conn| {
+                if conn.to.len() >= 2 {
+                    Some(
+                        conn.to
+                            .iter()
+                            .cartesian_product(conn.to.clone())
+                            .filter_map(|(first, second)| {
+                                if self
+                                    .connections
+                                    .iter()
+                                    .find(|c| c.from == *first)
+                                    .unwrap()
+                                    .to
+                                    .contains(&second)
+                                {
+                                    Some([conn.from.clone(), first.clone(), second.clone()])
+                                } else {
+                                    None
+                                }
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                } else {
+                    None
+                }
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+        three_connections.iter_mut().for_each(|conns| conns.sort());
+        three_connections.sort();
+        three_connections.dedup();
+        three_connections
+    }
+
+    fn find_triple_connections_with_letter(&self, letter: &str) -> Vec<[Computer; 3]> {
+        self.find_three_connections()
+            .into_iter()
+            .filter(|comps| comps.iter().any(|c| c.name_starts_with(letter)))
+            .collect::<Vec<_>>()
+    }
+
+    fn find_maximal_cliques(&self) -> Vec<HashSet<Computer>> {
+        let mut graph: HashMap<Computer, HashSet<Computer>> = HashMap::new();
+        self.connections.iter().cloned().for_each(|connection| {
+            for to in connection.to {
+                let from = connection.from.clone();
+                graph
+                    .entry(from.clone())
+                    .and_modify(|set| {
+                        set.insert(to.clone());
+                    })
+                    .or_insert(HashSet::from_iter(vec![to.clone()]));
+                graph
+                    .entry(to)
+                    .and_modify(|set| {
+                        set.insert(from.clone());
+                    })
+                    .or_insert(HashSet::from_iter(vec![from]));
+            }
+        });
+        let mut cliques = Vec::new();
+        let nodes = graph.keys().cloned().collect();
+        bron_kerbosch(HashSet::new(), nodes, HashSet::new(), &graph, &mut cliques);
+        cliques
+    }
+
+    fn find_password(&self) -> String {
+        let cliques = self.find_maximal_cliques();
+        let max_clique = cliques.iter().max_by_key(|set| set.len()).unwrap();
+        let mut as_vec = max_clique.iter().collect::<Vec<_>>();
+        as_vec.sort();
+        as_vec.iter().map(|&c| c.to_string()).join(",")
+    }
+}
+
+fn bron_kerbosch<T>(
+    r: HashSet<T>,
+    mut p: HashSet<T>,
+    mut x: HashSet<T>,
+    graph: &HashMap<T, HashSet<T>>,
+    cliques: &mut Vec<HashSet<T>>,
+) where
+    T: Clone + Eq + Hash,
+{
+    if p.is_empty() && x.is_empty() {
+        cliques.push(r);
+        return;
+    }
+    p.clone().iter().for_each(|node| {
+        let mut new_r = r.clone();
+        new_r.insert(node.clone());
+
+        let new_p = p.intersection(&graph[node]).cloned().collect();
+        let new_x = x.intersection(&graph[node]).cloned().collect();
+
+        bron_kerbosch(new_r, new_p, new_x, graph, cliques);
+
+        p.remove(node);
+        x.insert(node.clone());
+    });
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = Lan;
+
+    const EXAMPLES: &'static [Example] =
+        &[
+            Example::new("example", include_str!("../examples/example.txt"))
+                .part1("7")
+                .part2("co,de,ka,ta"),
+        ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Lan::try_from(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input.find_triple_connections_with_letter("t").len().into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        input.find_password().into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        // Computers have names of two letters, so there are at most 26 * 26.
+        let mut names = (0..26 * 26)
+            .map(|n| {
+                format!(
+                    "{}{}",
+                    char::from(b'a' + n as u8 / 26),
+                    char::from(b'a' + n as u8 % 26)
+                )
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut names);
+        names.truncate(size.clamp(2, names.len()));
+        // A party of 13 connected computers, far larger than those the few random
+        // connections make.
+        let party = &names[..names.len().min(13)];
+        let mut connections = party
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| (a.clone(), b.clone()))
+            .collect::<HashSet<_>>();
+        for _ in 0..2 * names.len() {
+            let (a, b) = (rng.pick(&names), rng.pick(&names));
+            if a != b && !connections.contains(&(b.clone(), a.clone())) {
+                connections.insert((a.clone(), b.clone()));
+            }
+        }
+        let mut connections = connections
+            .into_iter()
+            .map(|(a, b)| format!("{a}-{b}\n"))
+            .collect::<Vec<_>>();
+        connections.sort();
+        rng.shuffle(&mut connections);
+        Some(Generated::new(connections.concat()))
+    }
+}
+
+#[cfg(test)]
+mod day23_tests {
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests, example_input,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day23, Network);
+
+    #[test]
+    fn test_find_three_connections() {
+        let input = example_input::<Day23>("example");
+        let lan = Lan::try_from(input).unwrap();
+        assert_eq!(lan.find_three_connections().len(), 12);
+    }
+
+    #[derive(Debug, Clone)]
+    struct Network {
+        computers: Vec<String>,
+        connections: Vec<(usize, usize)>,
+    }
+
+    impl Network {
+        fn connected(&self, a: usize, b: usize) -> bool {
+            self.connections.contains(&(a, b)) || self.connections.contains(&(b, a))
+        }
+
+        /// The largest sets of computers all connected to each other, trying every subset.
+        fn largest_parties(&self) -> Vec<Vec<usize>> {
+            let mut parties = (0..1_u32 << self.computers.len())
+                .map(|set| {
+                    let party = (0..self.computers.len()).filter(|&i| set & (1 << i) != 0);
+                    party.collect::<Vec<_>>()
+                })
+                .filter(|party| {
+                    party
+                        .iter()
+                        .tuple_combinations()
+                        .all(|(&a, &b)| self.connected(a, b))
+                })
+                .collect::<Vec<_>>();
+            let largest = parties.iter().map(Vec::len).max().unwrap();
+            parties.retain(|party| party.len() == largest);
+            parties
+        }
+
+        /// Whether the largest party is a single one, as the password needs.
+        fn valid(&self) -> bool {
+            !self.connections.is_empty() && self.largest_parties().len() == 1
+        }
+    }
+
+    impl Case for Network {
+        fn generate(rng: &mut Rng) -> Self {
+            loop {
+                let mut computers = (0..rng.between(2, 10))
+                    .map(|_| {
+                        let first = *rng.pick(&['t', 'a', 'k', 'q']);
+                        format!("{first}{}", rng.pick(&['a', 'b', 'c', 't']))
+                    })
+                    .collect::<Vec<_>>();
+                computers.sort();
+                computers.dedup();
+                let density = rng.between(2, 9) as f64 / 10.0;
+                let connections = (0..computers.len())
+                    .tuple_combinations()
+                    .filter_map(|(a, b)| match rng.chance(density) {
+                        true if rng.chance(0.5) => Some((a, b)),
+                        true => Some((b, a)),
+                        false => None,
+                    })
+                    .collect();
+                let network = Network {
+                    computers,
+                    connections,
+                };
+                if network.valid() {
+                    return network;
+                }
+            }
+        }
+
+        fn input(&self) -> String {
+            self.connections
+                .iter()
+                .map(|&(a, b)| format!("{}-{}\n", self.computers[a], self.computers[b]))
+                .collect()
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_list(&self.connections)
+                .into_iter()
+                .map(|connections| Network {
+                    connections,
+                    ..self.clone()
+                })
+                .filter(Network::valid)
+                .collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            if part == 2 {
+                let party = &self.largest_parties()[0];
+                return party
+                    .iter()
+                    .map(|&i| self.computers[i].as_str())
+                    .join(",")
+                    .into();
+            }
+            (0..self.computers.len())
+                .tuple_combinations()
+                .filter(|&(a, b, c)| {
+                    self.connected(a, b) && self.connected(b, c) && self.connected(a, c)
+                })
+                .filter(|&(a, b, c)| {
+                    [a, b, c]
+                        .iter()
+                        .any(|&i| self.computers[i].starts_with('t'))
+                })
+                .count()
+                .into()
+        }
+    }
+}
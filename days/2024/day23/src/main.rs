@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day23::Day23;
+
+fn main() {
+    let input = read_input(2024, 23);
+    let input = Day23::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day23::part1(&input));
+    println!("Part 2 = {}", Day23::part2(&input));
+}
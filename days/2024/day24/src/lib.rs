@@ -0,0 +1,688 @@
+use std::{
+    cell::RefCell,
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use common::{
+    bigint::BigUint,
+    generate::Generated,
+    parse::{key_value, lines, parse_lines, split_at, split_sections},
+    random::Rng,
+    Answer, Example, ParseError, Solution,
+};
+use itertools::Itertools;
+
+#[derive(Debug, PartialEq, Clone)]
+struct Wire {
+    name: String,
+    value: Option<bool>,
+}
+
+impl FromStr for Wire {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = key_value(s, s, ":")?;
+        let value = match value {
+            "1" => true,
+            "0" => false,
+            _ => return Err(ParseError::at(s, value, "a wire value `0` or `1`")),
+        };
+        Ok(Self::new(name.to_string()).with_value(value))
+    }
+}
+
+impl Wire {
+    fn new(name: String) -> Self {
+        Self { name, value: None }
+    }
+
+    fn with_value(self, value: bool) -> Self {
+        Self {
+            value: Some(value),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum GateKind {
+    And,
+    Xor,
+    Or,
+}
+
+impl FromStr for GateKind {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "AND" => Ok(Self::And),
+            "XOR" => Ok(Self::Xor),
+            "OR" => Ok(Self::Or),
+            _ => Err(ParseError::new("a gate `AND`, `OR` or `XOR`", s)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Gate {
+    kind: GateKind,
+    input_1: String,
+    input_2: String,
+    output: String,
+}
+
+impl FromStr for Gate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (inputs, output) = split_at(s, s, " -> ")?;
+        let mut inputs = inputs.split(' ');
+        let mut next = |expected| {
+            inputs
+                .next()
+                .filter(|part| !part.is_empty())
+                .ok_or_else(|| ParseError::at(s, output, expected))
+        };
+        let input_1 = next("an input wire")?.to_string();
+        let kind = next("a gate")?;
+        let kind = kind.parse().map_err(|e: ParseError| e.within(s, kind))?;
+        let input_2 = next("an input wire")?.to_string();
+        Ok(Self::new(kind, input_1, input_2, output.to_string()))
+    }
+}
+
+impl Gate {
+    fn new(kind: GateKind, input_1: String, input_2: String, output: String) -> Self {
+        Self {
+            kind,
+            input_1,
+            input_2,
+            output,
+        }
+    }
+
+    fn apply(&self, wires: &[RefCell<Wire>]) -> Option<bool> {
+        let input_1 = wires
+            .iter()
+            .find(|w| w.borrow().name == self.input_1)
+            .unwrap();
+        let input_2 = wires
+            .iter()
+            .find(|w| w.borrow().name == self.input_2)
+            .unwrap();
+        if let Some(input_1) = input_1.borrow().value {
+            if let Some(input_2) = input_2.borrow().value {
+                match self.kind {
+                    GateKind::And => Some(input_1 && input_2),
+                    GateKind::Xor => Some(input_1 != input_2),
+                    GateKind::Or => Some(input_1 || input_2),
+                }
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Binary(String);
+
+impl From<&[bool]> for Binary {
+    fn from(value: &[bool]) -> Self {
+        let binary = value
+            .iter()
+            .map(|v| match v {
+                true => "1",
+                false => "0",
+            })
+            .collect::<String>();
+        Binary(binary)
+    }
+}
+
+impl From<usize> for Binary {
+    fn from(value: usize) -> Self {
+        let binary = format!("{:b}", value);
+        Binary(binary)
+    }
+}
+
+impl AsRef<str> for Binary {
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl Binary {
+    fn as_val(&self) -> BigUint {
+        self.0.bytes().fold(BigUint::zero(), |value, bit| {
+            value << 1 | BigUint::from(u8::from(bit == b'1'))
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    wires: Vec<RefCell<Wire>>,
+    gates: Vec<Gate>,
+}
+
+impl TryFrom<&str> for Circuit {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (wires, gate_lines) = split_sections(value, value)?;
+        let wires: Vec<Wire> = parse_lines(value, wires)?;
+        let gates: Vec<Gate> = parse_lines(value, gate_lines)?;
+        check_gates(value, gate_lines, &wires, &gates)?;
+        let mut wires = wires.into_iter().map(RefCell::new).collect::<Vec<_>>();
+        let mut other_wires: Vec<RefCell<Wire>> = vec![];
+        gates
+            .iter()
+            .flat_map(|gate: &Gate| {
+                vec![
+                    gate.input_1.clone(),
+                    gate.input_2.clone(),
+                    gate.output.clone(),
+                ]
+            })
+            .for_each(|w| {
+                if wires.iter().find(|wire| wire.borrow().name == w).is_none()
+                    && other_wires
+                        .iter()
+                        .find(|wire| wire.borrow().name == w)
+                        .is_none()
+                {
+                    other_wires.push(RefCell::new(Wire::new(w)));
+                }
+            });
+        wires.extend(other_wires);
+        Ok(Self { wires, gates })
+    }
+}
+
+/// Checks that every gate reads wires that have a value at the start or are the output
+/// of another gate, and that no gate depends on its own output, so that all of them settle.
+fn check_gates(
+    text: &str,
+    section: &str,
+    wires: &[Wire],
+    gates: &[Gate],
+) -> Result<(), ParseError> {
+    let lines = lines(section).collect::<Vec<_>>();
+    let producers = gates
+        .iter()
+        .enumerate()
+        .map(|(index, gate)| (gate.output.as_str(), index))
+        .collect::<HashMap<_, _>>();
+    let mut settled = wires
+        .iter()
+        .map(|wire| wire.name.as_str())
+        .collect::<HashSet<_>>();
+    for (gate, line) in gates.iter().zip(&lines) {
+        // The inputs are the first and third words of the line.
+        let fragments = line.split(' ').step_by(2);
+        for (input, fragment) in [&gate.input_1, &gate.input_2].into_iter().zip(fragments) {
+            if !settled.contains(input.as_str()) && !producers.contains_key(input.as_str()) {
+                return Err(ParseError::at(
+                    text,
+                    fragment,
+                    "an initial wire or the output of a gate",
+                ));
+            }
+        }
+    }
+
+    let mut pending = (0..gates.len()).collect::<Vec<_>>();
+    loop {
+        let (ready, waiting): (Vec<usize>, Vec<usize>) =
+            pending.iter().copied().partition(|&index| {
+                let gate = &gates[index];
+                settled.contains(gate.input_1.as_str()) && settled.contains(gate.input_2.as_str())
+            });
+        if ready.is_empty() {
+            break;
+        }
+        settled.extend(ready.iter().map(|&index| gates[index].output.as_str()));
+        pending = waiting;
+    }
+    let Some(&stuck) = pending.first() else {
+        return Ok(());
+    };
+    // Going back from a gate that never settles through the gates of its unsettled inputs
+    // goes round a cycle, and reports the first gate seen twice.
+    let mut seen = HashSet::new();
+    let mut index = stuck;
+    while seen.insert(index) {
+        let gate = &gates[index];
+        let input = match settled.contains(gate.input_1.as_str()) {
+            true => &gate.input_2,
+            false => &gate.input_1,
+        };
+        index = producers[input.as_str()];
+    }
+    Err(ParseError::at(
+        text,
+        lines[index],
+        "a gate that does not depend on its own output",
+    ))
+}
+
+impl Circuit {
+    fn apply_gate(&self, gate: &Gate) {
+        if let Some(wire_ref) = self.wires.iter().find(|w| w.borrow().name == gate.input_1) {
+            let wire = wire_ref.borrow();
+            if wire.value.is_none() {
+                if let Some(next_gate) = self.gates.iter().find(|g| g.output == wire.name) {
+                    drop(wire);
+                    self.apply_gate(next_gate);
+                }
+            }
+        }
+        if let Some(wire_ref) = self.wires.iter().find(|w| w.borrow().name == gate.input_2) {
+            let wire = wire_ref.borrow();
+            if wire.value.is_none() {
+                if let Some(next_gate) = self.gates.iter().find(|g| g.output == wire.name) {
+                    drop(wire);
+                    self.apply_gate(next_gate);
+                }
+            }
+        }
+        let val = gate.apply(&self.wires).unwrap();
+        if let Some(wire_ref) = self.wires.iter().find(|w| w.borrow().name == gate.output) {
+            wire_ref.borrow_mut().value = Some(val);
+        }
+    }
+
+    fn apply(&self) {
+        self.gates.iter().for_each(|gate| {
+            self.apply_gate(gate);
+        });
+    }
+
+    fn get_value(&self, id: char) -> BigUint {
+        let mut wires = self
+            .wires
+            .iter()
+            .filter(|w| w.borrow().name.starts_with(id))
+            .map(|w| w.borrow())
+            .collect::<Vec<_>>();
+        wires.sort_by_key(|w| Reverse(w.name.clone()));
+        let binary = wires.iter().map(|w| w.value.unwrap()).collect::<Vec<_>>();
+        let binary: Binary = binary.as_slice().into();
+        binary.as_val()
+    }
+
+    fn get_z_value(&self) -> BigUint {
+        self.get_value('z')
+    }
+
+    fn find_fix_2(&self) -> String {
+        // The adder takes its lowest bit without a carry, and its highest output is the
+        // carry of the last bit.
+        let names = self.wires.iter().map(|w| w.borrow().name.clone());
+        let first_x = names.clone().filter(|n| n.starts_with('x')).min();
+        let last_z = names.filter(|n| n.starts_with('z')).max();
+        let (first_x, last_z) = (first_x.as_ref(), last_z.as_ref());
+        let output_gates = self
+            .gates
+            .iter()
+            .flat_map(|g| vec![(&g.input_1, &g.kind), (&g.input_2, &g.kind)])
+            .collect::<Vec<_>>();
+        let mut to_swap = vec![];
+        self.gates.iter().for_each(|g| match g.kind {
+            GateKind::And => {
+                if Some(&g.input_1) != first_x
+                    && Some(&g.input_2) != first_x
+                    && !output_gates.contains(&(&g.output, &GateKind::Or))
+                {
+                    to_swap.push(&g.output);
+                }
+            }
+            GateKind::Or => {
+                if g.output.starts_with("z") && Some(&g.output) != last_z {
+                    to_swap.push(&g.output);
+                }
+                if output_gates.contains(&(&g.output, &GateKind::Or)) {
+                    to_swap.push(&g.output);
+                }
+            }
+            GateKind::Xor => {
+                if g.input_1.starts_with("x") || g.input_2.starts_with("x") {
+                    if Some(&g.input_1) != first_x
+                        && Some(&g.input_2) != first_x
+                        && !output_gates.contains(&(&g.output, &GateKind::Xor))
+                    {
+                        to_swap.push(&g.output);
+                    }
+                } else {
+                    if !g.output.starts_with("z") {
+                        to_swap.push(&g.output);
+                    }
+                }
+            }
+        });
+        to_swap.sort_unstable();
+        to_swap.into_iter().join(",")
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = Circuit;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example::new("small", include_str!("../examples/small.txt")).part1("4"),
+        Example::new("larger", include_str!("../examples/larger.txt")).part1("2024"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Circuit::try_from(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let circuit = input.clone();
+        circuit.apply();
+        circuit.get_z_value().into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        input.find_fix_2().into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        // A ripple-carry adder of `size` bits, like the puzzle's one of 45, with the
+        // outputs of 4 pairs of gates swapped within the adders of single bits.
+        let bits = size.max(2);
+        let width = bits.to_string().len().max(2);
+        let wire = |prefix: &str, bit: usize| format!("{prefix}{bit:0width$}");
+        let length = (3..)
+            .find(|&length| 23_usize.pow(length) > 10 * bits)
+            .unwrap();
+        let mut names = HashSet::new();
+        let mut name = |rng: &mut Rng| loop {
+            let name = (0..length)
+                .map(|_| char::from(b'a' + rng.below(23) as u8))
+                .collect::<String>();
+            if names.insert(name.clone()) {
+                break name;
+            }
+        };
+        let mut carry = name(rng);
+        let mut gates = vec![
+            (wire("x", 0), "XOR", wire("y", 0), wire("z", 0)),
+            (wire("x", 0), "AND", wire("y", 0), carry.clone()),
+        ];
+        let mut adders = vec![];
+        for bit in 1..bits {
+            let (sum, both, through) = (name(rng), name(rng), name(rng));
+            let next = match bit + 1 == bits {
+                true => wire("z", bits),
+                false => name(rng),
+            };
+            let (x, y, z) = (wire("x", bit), wire("y", bit), wire("z", bit));
+            gates.push((x.clone(), "XOR", y.clone(), sum.clone()));
+            gates.push((x, "AND", y, both.clone()));
+            gates.push((sum.clone(), "XOR", carry.clone(), z.clone()));
+            gates.push((sum.clone(), "AND", carry, through.clone()));
+            gates.push((both.clone(), "OR", through.clone(), next.clone()));
+            if bit + 1 < bits {
+                adders.push([z, sum, both, through, next.clone()]);
+            }
+            carry = next;
+        }
+        rng.shuffle(&mut adders);
+        for adder in adders.iter().take(4) {
+            let (a, b) = *rng.pick(&[(0, 2), (0, 3), (0, 4), (1, 2)]);
+            for gate in &mut gates {
+                if gate.3 == adder[a] {
+                    gate.3 = adder[b].clone();
+                } else if gate.3 == adder[b] {
+                    gate.3 = adder[a].clone();
+                }
+            }
+        }
+        rng.shuffle(&mut gates);
+        let inputs = ["x", "y"]
+            .iter()
+            .flat_map(|prefix| (0..bits).map(move |bit| wire(prefix, bit)))
+            .map(|input| format!("{input}: {}\n", rng.below(2)))
+            .collect::<String>();
+        let gates = gates
+            .into_iter()
+            .map(|(a, kind, b, output)| match rng.chance(0.5) {
+                true => format!("{a} {kind} {b} -> {output}\n"),
+                false => format!("{b} {kind} {a} -> {output}\n"),
+            })
+            .collect::<String>();
+        Some(Generated::new(format!("{inputs}\n{gates}")))
+    }
+}
+
+#[cfg(test)]
+mod day24_tests {
+    use common::{differential::Case, differential_tests, example_input, random::Rng};
+
+    use super::*;
+
+    differential_tests!(Day24, Device);
+
+    #[test]
+    fn test_parse() {
+        let input = example_input::<Day24>("small");
+        let circuit = Circuit::try_from(input).unwrap();
+        assert_eq!(
+            circuit.wires,
+            vec![
+                RefCell::new(Wire::new(String::from("x00")).with_value(true)),
+                RefCell::new(Wire::new(String::from("x01")).with_value(true)),
+                RefCell::new(Wire::new(String::from("x02")).with_value(true)),
+                RefCell::new(Wire::new(String::from("y00")).with_value(false)),
+                RefCell::new(Wire::new(String::from("y01")).with_value(true)),
+                RefCell::new(Wire::new(String::from("y02")).with_value(false)),
+                RefCell::new(Wire::new(String::from("z00"))),
+                RefCell::new(Wire::new(String::from("z01"))),
+                RefCell::new(Wire::new(String::from("z02"))),
+            ]
+        );
+        assert_eq!(
+            circuit.gates,
+            vec![
+                Gate::new(
+                    GateKind::And,
+                    String::from("x00"),
+                    String::from("y00"),
+                    String::from("z00")
+                ),
+                Gate::new(
+                    GateKind::Xor,
+                    String::from("x01"),
+                    String::from("y01"),
+                    String::from("z01")
+                ),
+                Gate::new(
+                    GateKind::Or,
+                    String::from("x02"),
+                    String::from("y02"),
+                    String::from("z02")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_undefined_wire() {
+        assert_eq!(
+            Circuit::try_from("x00: 1\n\nx00 XOR y00 -> z00")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 9: expected an initial wire or the output of a gate, found `y00`"
+        );
+    }
+
+    #[test]
+    fn test_parse_cycle() {
+        let input = "x00: 1\n\nb XOR x00 -> z00\nx00 AND a -> b\nb OR x00 -> a";
+        assert_eq!(
+            Circuit::try_from(input).unwrap_err().to_string(),
+            "line 4, column 1: expected a gate that does not depend on its own output, found `x00 AND a -> b`"
+        );
+    }
+
+    #[test]
+    fn test_apply() {
+        let input = example_input::<Day24>("larger");
+        let circuit = Circuit::try_from(input).unwrap();
+        circuit.apply();
+        let mut wires = circuit.wires;
+        wires.sort_by_key(|w| w.borrow().name.clone());
+        assert_eq!(
+            wires,
+            vec![
+                RefCell::new(Wire::new(String::from("bfw")).with_value(true)),
+                RefCell::new(Wire::new(String::from("bqk")).with_value(true)),
+                RefCell::new(Wire::new(String::from("djm")).with_value(true)),
+                RefCell::new(Wire::new(String::from("ffh")).with_value(false)),
+                RefCell::new(Wire::new(String::from("fgs")).with_value(true)),
+                RefCell::new(Wire::new(String::from("frj")).with_value(true)),
+                RefCell::new(Wire::new(String::from("fst")).with_value(true)),
+                RefCell::new(Wire::new(String::from("gnj")).with_value(true)),
+                RefCell::new(Wire::new(String::from("hwm")).with_value(true)),
+                RefCell::new(Wire::new(String::from("kjc")).with_value(false)),
+                RefCell::new(Wire::new(String::from("kpj")).with_value(true)),
+                RefCell::new(Wire::new(String::from("kwq")).with_value(false)),
+                RefCell::new(Wire::new(String::from("mjb")).with_value(true)),
+                RefCell::new(Wire::new(String::from("nrd")).with_value(true)),
+                RefCell::new(Wire::new(String::from("ntg")).with_value(false)),
+                RefCell::new(Wire::new(String::from("pbm")).with_value(true)),
+                RefCell::new(Wire::new(String::from("psh")).with_value(true)),
+                RefCell::new(Wire::new(String::from("qhw")).with_value(true)),
+                RefCell::new(Wire::new(String::from("rvg")).with_value(false)),
+                RefCell::new(Wire::new(String::from("tgd")).with_value(false)),
+                RefCell::new(Wire::new(String::from("tnw")).with_value(true)),
+                RefCell::new(Wire::new(String::from("vdt")).with_value(true)),
+                RefCell::new(Wire::new(String::from("wpb")).with_value(false)),
+                RefCell::new(Wire::new(String::from("x00")).with_value(true)),
+                RefCell::new(Wire::new(String::from("x01")).with_value(false)),
+                RefCell::new(Wire::new(String::from("x02")).with_value(true)),
+                RefCell::new(Wire::new(String::from("x03")).with_value(true)),
+                RefCell::new(Wire::new(String::from("x04")).with_value(false)),
+                RefCell::new(Wire::new(String::from("y00")).with_value(true)),
+                RefCell::new(Wire::new(String::from("y01")).with_value(true)),
+                RefCell::new(Wire::new(String::from("y02")).with_value(true)),
+                RefCell::new(Wire::new(String::from("y03")).with_value(true)),
+                RefCell::new(Wire::new(String::from("y04")).with_value(true)),
+                RefCell::new(Wire::new(String::from("z00")).with_value(false)),
+                RefCell::new(Wire::new(String::from("z01")).with_value(false)),
+                RefCell::new(Wire::new(String::from("z02")).with_value(false)),
+                RefCell::new(Wire::new(String::from("z03")).with_value(true)),
+                RefCell::new(Wire::new(String::from("z04")).with_value(false)),
+                RefCell::new(Wire::new(String::from("z05")).with_value(true)),
+                RefCell::new(Wire::new(String::from("z06")).with_value(true)),
+                RefCell::new(Wire::new(String::from("z07")).with_value(true)),
+                RefCell::new(Wire::new(String::from("z08")).with_value(true)),
+                RefCell::new(Wire::new(String::from("z09")).with_value(true)),
+                RefCell::new(Wire::new(String::from("z10")).with_value(true)),
+                RefCell::new(Wire::new(String::from("z11")).with_value(false)),
+                RefCell::new(Wire::new(String::from("z12")).with_value(false))
+            ]
+        )
+    }
+
+    #[derive(Debug, Clone)]
+    struct Device {
+        inputs: Vec<(String, u8)>,
+        gates: Vec<(String, &'static str, String, String)>,
+    }
+
+    impl Device {
+        fn value(&self, wire: &str) -> u8 {
+            if let Some((_, value)) = self.inputs.iter().find(|(name, _)| name == wire) {
+                return *value;
+            }
+            let (a, operation, b, _) = self.gates.iter().find(|gate| gate.3 == wire).unwrap();
+            let (a, b) = (self.value(a), self.value(b));
+            match *operation {
+                "AND" => a & b,
+                "OR" => a | b,
+                _ => a ^ b,
+            }
+        }
+    }
+
+    impl Case for Device {
+        fn generate(rng: &mut Rng) -> Self {
+            let bits = rng.between(1, 4);
+            let inputs = ["x", "y"]
+                .iter()
+                .flat_map(|prefix| (0..bits).map(move |bit| format!("{prefix}{bit:02}")))
+                .map(|name| (name, rng.below(2) as u8))
+                .collect::<Vec<_>>();
+            let mut wires = inputs
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect::<Vec<_>>();
+            let outputs = rng.between(1, 5);
+            let count = rng.between(outputs, 10);
+            let mut gates = vec![];
+            for index in 0..count {
+                let (a, b) = loop {
+                    let (a, b) = (rng.pick(&wires).clone(), rng.pick(&wires).clone());
+                    if a != b {
+                        break (a, b);
+                    }
+                };
+                // The last gates feed the output wires, named in order from `z00`.
+                let output = match index - (count - outputs) {
+                    bit if bit >= 0 => format!("z{bit:02}"),
+                    _ => format!("g{index:02}"),
+                };
+                wires.push(output.clone());
+                gates.push((a, *rng.pick(&["AND", "OR", "XOR"]), b, output));
+            }
+            rng.shuffle(&mut gates);
+            Device { inputs, gates }
+        }
+
+        fn input(&self) -> String {
+            let inputs = self
+                .inputs
+                .iter()
+                .map(|(name, value)| format!("{name}: {value}\n"))
+                .collect::<String>();
+            let gates = self
+                .gates
+                .iter()
+                .map(|(a, operation, b, output)| format!("{a} {operation} {b} -> {output}\n"))
+                .collect::<String>();
+            format!("{inputs}\n{gates}")
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            if part == 2 {
+                return Answer::Unsolved;
+            }
+            let mut outputs = self
+                .gates
+                .iter()
+                .map(|(_, _, _, output)| output)
+                .filter(|output| output.starts_with('z'))
+                .collect::<Vec<_>>();
+            outputs.sort();
+            outputs
+                .iter()
+                .rev()
+                .fold(0_u64, |number, output| {
+                    number << 1 | u64::from(self.value(output))
+                })
+                .into()
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use common::{read_input, Solution};
+use y2024_day24::Day24;
+
+fn main() {
+    let input = read_input(2024, 24);
+    let input = Day24::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day24::part1(&input));
+    println!("Part 2 = {}", Day24::part2(&input));
+}
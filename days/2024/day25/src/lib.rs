@@ -0,0 +1,337 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use common::{
+    generate::Generated, parse::sections, random::Rng, Answer, Example, ParseError, Solution,
+};
+use itertools::Itertools;
+
+fn to_heights<'a>(
+    text: &str,
+    lines: impl Iterator<Item = &'a str>,
+) -> Result<[usize; 5], ParseError> {
+    lines.map(str::trim).try_fold([0; 5], |mut heights, l| {
+        for (idx, c) in l.char_indices() {
+            match (heights.get_mut(idx), c) {
+                (Some(height), '#') => *height += 1,
+                (Some(_), '.') => (),
+                (None, _) => return Err(ParseError::at(text, &l[idx..], "end of line")),
+                (_, _) => return Err(ParseError::at(text, &l[idx..], "`#` or `.`")),
+            }
+        }
+        Ok(heights)
+    })
+}
+
+#[derive(Debug)]
+pub struct Lock {
+    pins: [usize; 5],
+}
+
+impl FromStr for Lock {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.trim().lines();
+        match lines.next().map(str::trim) {
+            Some("#####") => Ok(Self {
+                pins: to_heights(s, lines)?,
+            }),
+            top => Err(ParseError::at(
+                s,
+                top.unwrap_or_default(),
+                "a lock top row `#####`",
+            )),
+        }
+    }
+}
+
+impl Display for Lock {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.pins.iter().join(","))
+    }
+}
+
+#[derive(Debug)]
+pub struct Key {
+    heights: [usize; 5],
+}
+
+impl FromStr for Key {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.trim().lines().rev();
+        match lines.next().map(str::trim) {
+            Some("#####") => Ok(Self {
+                heights: to_heights(s, lines)?,
+            }),
+            bottom => Err(ParseError::at(
+                s,
+                bottom.unwrap_or_default(),
+                "a key bottom row `#####`",
+            )),
+        }
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.heights.iter().join(","))
+    }
+}
+
+impl Key {
+    fn fit(&self, lock: &Lock) -> bool {
+        self.heights
+            .iter()
+            .enumerate()
+            .all(|(pos, h)| h + lock.pins[pos] <= 5)
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Key>, Vec<Lock>), ParseError> {
+    sections(input).try_fold((vec![], vec![]), |(mut keys, mut locks), key_or_lock| {
+        let rebase = |e: ParseError| e.within(input, key_or_lock);
+        match key_or_lock.starts_with("#####") {
+            true => locks.push(key_or_lock.parse().map_err(rebase)?),
+            false => keys.push(key_or_lock.parse().map_err(rebase)?),
+        }
+        Ok((keys, locks))
+    })
+}
+
+fn count_fits(keys: &[Key], locks: &[Lock]) -> usize {
+    keys.iter()
+        .map(|k| locks.iter().filter(|l| k.fit(l)).count())
+        .sum()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = (Vec<Key>, Vec<Lock>);
+
+    const EXAMPLES: &'static [Example] =
+        &[Example::new("example", include_str!("../examples/example.txt")).part1("3")];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (keys, locks) = input;
+        count_fits(keys, locks).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<Generated> {
+        let schematics = (0..size)
+            .map(|_| {
+                let lock = rng.chance(0.5);
+                let heights = [(); 5].map(|_| rng.below(6));
+                (0..7)
+                    .map(|row| {
+                        // Locks hang from the top row, keys stand on the bottom one.
+                        let line = heights.iter().map(|&height| match lock {
+                            true if row <= height => '#',
+                            false if 6 - row <= height => '#',
+                            _ => '.',
+                        });
+                        format!("{}\n", line.collect::<String>())
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        Some(Generated::new(schematics.join("\n")))
+    }
+}
+
+#[cfg(test)]
+mod day25_tests {
+    use common::{
+        differential::{shrink_list, Case},
+        differential_tests, example_input,
+        random::Rng,
+    };
+
+    use super::*;
+
+    differential_tests!(Day25, Schematics);
+
+    #[test]
+    fn test_parse_key_ok() {
+        let input = r#".....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####"#;
+        let key: Result<Key, ParseError> = input.parse();
+        assert!(key.is_ok());
+        let key = key.unwrap();
+        assert_eq!(key.to_string(), "5,0,2,1,3");
+    }
+
+    #[test]
+    fn test_parse_key_ko() {
+        let input = r#"#####
+.####
+.####
+.####
+.#.#.
+.#...
+....."#;
+        let key: Result<Key, ParseError> = input.parse();
+        assert!(key.is_err());
+    }
+
+    #[test]
+    fn test_parse_lock_ok() {
+        let input = r#"#####
+.####
+.####
+.####
+.#.#.
+.#...
+....."#;
+        let lock: Result<Lock, ParseError> = input.parse();
+        assert!(lock.is_ok());
+        let lock = lock.unwrap();
+        assert_eq!(lock.to_string(), "0,5,3,4,3");
+    }
+
+    #[test]
+    fn test_parse_lock_ko() {
+        let input = r#".....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####"#;
+        let lock: Result<Lock, ParseError> = input.parse();
+        assert!(lock.is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = example_input::<Day25>("example");
+        let (keys, locks) = parse_input(input).unwrap();
+        assert_eq!(keys.len(), 3);
+        assert_eq!(
+            keys.iter().map(|k| k.to_string()).collect::<Vec<_>>(),
+            vec!["5,0,2,1,3", "4,3,4,0,2", "3,0,2,0,1"]
+        );
+        assert_eq!(locks.len(), 2);
+        assert_eq!(
+            locks.iter().map(|l| l.to_string()).collect::<Vec<_>>(),
+            vec!["0,5,3,4,3", "1,2,0,5,3"]
+        );
+    }
+
+    #[test]
+    fn test_key_fit_ok() {
+        let input = r#"#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+.....
+#....
+#....
+#....
+#.#.#
+#.###
+#####"#;
+        let (keys, locks) = parse_input(input).unwrap();
+        assert!(keys[0].fit(&locks[0]));
+    }
+
+    #[test]
+    fn test_key_fit_ko() {
+        let input = r#"#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####"#;
+        let (keys, locks) = parse_input(input).unwrap();
+        assert!(!keys[0].fit(&locks[0]));
+    }
+
+    #[derive(Debug, Clone)]
+    struct Schematics(Vec<(bool, [usize; 5])>);
+
+    impl Case for Schematics {
+        fn generate(rng: &mut Rng) -> Self {
+            let schematics = (0..rng.between(1, 8))
+                .map(|_| (rng.chance(0.5), [(); 5].map(|_| rng.below(6))))
+                .collect();
+            Schematics(schematics)
+        }
+
+        fn input(&self) -> String {
+            let schematics = self
+                .0
+                .iter()
+                .map(|(lock, heights)| {
+                    (0..7)
+                        .map(|row| {
+                            let line = heights.iter().map(|&height| {
+                                // Locks hang from the top row, keys stand on the bottom one.
+                                let filled = match lock {
+                                    true => row <= height,
+                                    false => 6 - row <= height,
+                                };
+                                if filled {
+                                    '#'
+                                } else {
+                                    '.'
+                                }
+                            });
+                            format!("{}\n", line.collect::<String>())
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>();
+            schematics.join("\n")
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_list(&self.0)
+                .into_iter()
+                .filter(|schematics| !schematics.is_empty())
+                .map(Schematics)
+                .collect()
+        }
+
+        fn reference(&self, part: u8) -> Answer {
+            if part == 2 {
+                return Answer::Unsolved;
+            }
+            let (locks, keys): (Vec<&(bool, [usize; 5])>, Vec<_>) =
+                self.0.iter().partition(|(lock, _)| *lock);
+            locks
+                .iter()
+                .flat_map(|lock| keys.iter().map(move |key| (lock.1, key.1)))
+                .filter(|(lock, key)| (0..5).all(|pin| lock[pin] + key[pin] <= 5))
+                .count()
+                .into()
+        }
+    }
+}
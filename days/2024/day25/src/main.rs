@@ -0,0 +1,8 @@
+use common::{read_input, Solution};
+use y2024_day25::Day25;
+
+fn main() {
+    let input = read_input(2024, 25);
+    let input = Day25::parse(&input).unwrap_or_else(|e| panic!("{e}"));
+    println!("Part 1 = {}", Day25::part1(&input));
+}